@@ -0,0 +1,70 @@
+//! Measures how many commands & queries the database handles per second when
+//! they're issued concurrently - compares a single shared connection (which is
+//! how Janet used to work) against the connection pool.
+//!
+//! ```shell
+//! $ cargo bench -p lib_database
+//! ```
+
+use lib_database::{CreateUser, Database, DatabaseConfig, FindUsers};
+use lib_gitlab as gl;
+use std::time::Instant;
+use uuid::Uuid;
+
+const TASKS: usize = 32;
+const OPS_PER_TASK: usize = 100;
+
+#[tokio::main]
+async fn main() {
+    run("single connection", Some(1)).await;
+    run("pool", None).await;
+}
+
+async fn run(name: &str, max_connections: Option<u32>) {
+    let path = std::env::temp_dir().join(format!("janet-bench-{}.db", Uuid::new_v4()));
+
+    let db = Database::new(DatabaseConfig {
+        max_connections,
+        ..DatabaseConfig::sqlite(path.display().to_string())
+    })
+    .await
+    .unwrap();
+
+    let started_at = Instant::now();
+
+    let tasks: Vec<_> = (0..TASKS)
+        .map(|task| {
+            let db = db.clone();
+
+            tokio::spawn(async move {
+                for op in 0..OPS_PER_TASK {
+                    let ext_id = gl::UserId::new(task * OPS_PER_TASK + op);
+
+                    db.execute(CreateUser { ext_id }).await.unwrap();
+                    db.get_one(FindUsers::ext_id(ext_id)).await.unwrap();
+                }
+            })
+        })
+        .collect();
+
+    for task in tasks {
+        task.await.unwrap();
+    }
+
+    let elapsed = started_at.elapsed();
+    let ops = TASKS * OPS_PER_TASK * 2;
+
+    println!(
+        "{}: {} ops in {:.2?} ({:.0} ops/s)",
+        name,
+        ops,
+        elapsed,
+        ops as f64 / elapsed.as_secs_f64()
+    );
+
+    drop(db);
+
+    for suffix in &["", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+    }
+}
@@ -0,0 +1,8 @@
+/// Database engine Janet talks to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    Sqlite,
+
+    #[cfg(feature = "postgres")]
+    Postgres,
+}
@@ -0,0 +1,32 @@
+use sqlx::any::{Any, AnyConnection};
+use sqlx::pool::PoolConnection;
+use sqlx::Transaction;
+use std::ops::{Deref, DerefMut};
+use tokio::sync::MutexGuard;
+
+/// Connection commands & queries are executed on - either taken straight from
+/// the pool or, when there's a transaction in progress, the transaction's one
+crate enum Connection<'a> {
+    Pooled(PoolConnection<Any>),
+    Transaction(MutexGuard<'a, Transaction<'static, Any>>),
+}
+
+impl Deref for Connection<'_> {
+    type Target = AnyConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Pooled(conn) => conn,
+            Self::Transaction(tx) => tx,
+        }
+    }
+}
+
+impl DerefMut for Connection<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Pooled(conn) => conn,
+            Self::Transaction(tx) => tx,
+        }
+    }
+}
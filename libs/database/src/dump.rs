@@ -0,0 +1,467 @@
+use crate::*;
+use async_compat::Compat;
+use serde::{Deserialize, Serialize};
+use std::ops::DerefMut;
+
+/// All the tables that are covered by dumps
+const TABLES: &[&str] = &[
+    "users",
+    "notification_preferences",
+    "projects",
+    "merge_requests",
+    "merge_request_dependencies",
+    "reminders",
+    "reminder_recipients",
+    "nudges",
+    "digest_subscriptions",
+    "logs",
+];
+
+/// Contents of the entire database, used for backups & migrating Janet
+/// between machines
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Dump {
+    /// Database's version at the time of the export; dumps can be imported
+    /// only into databases with the same version
+    pub version: usize,
+
+    pub users: Vec<User>,
+    pub notification_preferences: Vec<NotificationPreference>,
+    pub projects: Vec<Project>,
+    pub merge_requests: Vec<MergeRequest>,
+    pub merge_request_dependencies: Vec<MergeRequestDependency>,
+    pub reminders: Vec<Reminder>,
+    pub reminder_recipients: Vec<ReminderRecipient>,
+    pub nudges: Vec<Nudge>,
+    pub digest_subscriptions: Vec<DigestSubscription>,
+    pub logs: Vec<LogEntry>,
+}
+
+impl Dump {
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+            && self.notification_preferences.is_empty()
+            && self.projects.is_empty()
+            && self.merge_requests.is_empty()
+            && self.merge_request_dependencies.is_empty()
+            && self.reminders.is_empty()
+            && self.reminder_recipients.is_empty()
+            && self.nudges.is_empty()
+            && self.digest_subscriptions.is_empty()
+            && self.logs.is_empty()
+    }
+}
+
+impl Database {
+    /// Exports the entire database
+    #[tracing::instrument(skip(self))]
+    pub async fn export(&self) -> Result<Dump> {
+        tracing::debug!("Exporting database");
+
+        Ok(Dump {
+            version: self.version().await?,
+            users: self.get_all(FindUsers::default()).await?,
+            notification_preferences: self.get_all(FindNotificationPreferences::default()).await?,
+            projects: self.get_all(FindProjects::default()).await?,
+            merge_requests: self.get_all(FindMergeRequests::default()).await?,
+            merge_request_dependencies: self
+                .get_all(FindMergeRequestDependencies::default())
+                .await?,
+            reminders: self.get_all(FindReminders::default()).await?,
+            reminder_recipients: self.get_all(FindReminderRecipients::default()).await?,
+            nudges: self.get_all(FindNudges::default()).await?,
+            digest_subscriptions: self.get_all(FindDigestSubscriptions::default()).await?,
+            logs: self.get_all(FindLogEntries::default()).await?,
+        })
+    }
+
+    /// Imports given dump, preserving all ids and timestamps.
+    ///
+    /// Since merging dumps would be error-prone, this works only on empty
+    /// databases.
+    #[tracing::instrument(skip(self, dump))]
+    pub async fn import(&self, dump: Dump) -> Result<()> {
+        tracing::debug!("Importing database");
+
+        let version = self.version().await?;
+
+        if dump.version != version {
+            bail!(
+                "Dump comes from database at version {}, but this one is at version {}",
+                dump.version,
+                version
+            );
+        }
+
+        if !self.is_empty().await? {
+            bail!("Dumps can be imported only into empty databases");
+        }
+
+        Compat::new(self.import_rows(dump)).await
+    }
+
+    /// Checks whether all the tables are empty, without loading their rows
+    async fn is_empty(&self) -> Result<bool> {
+        Compat::new(async {
+            for table in TABLES {
+                let query = format!(
+                    "SELECT COUNT(*) FROM (SELECT 1 FROM {} LIMIT 1) AS t",
+                    table
+                );
+
+                let rows: i64 = sqlx::query_scalar(&query)
+                    .fetch_one(self.reader().await?.deref_mut())
+                    .await
+                    .with_context(|| format!("Couldn't check whether `{}` is empty", table))?;
+
+                if rows > 0 {
+                    return Ok(false);
+                }
+            }
+
+            Ok(true)
+        })
+        .await
+    }
+
+    async fn import_rows(&self, dump: Dump) -> Result<()> {
+        let mut tx = self.writer.begin().await?;
+
+        for user in dump.users {
+            sqlx::query("INSERT INTO users (id, ext_id, created_at) VALUES ($1, $2, $3)")
+                .bind(user.id)
+                .bind(user.ext_id)
+                .bind(user.created_at)
+                .execute(&mut tx)
+                .await
+                .with_context(|| format!("Couldn't import user: {:?}", user.id))?;
+        }
+
+        for preference in dump.notification_preferences {
+            sqlx::query(
+                "INSERT INTO notification_preferences (user_id, channel, created_at) VALUES ($1, $2, $3)",
+            )
+            .bind(preference.user_id)
+            .bind(&preference.channel)
+            .bind(preference.created_at)
+            .execute(&mut tx)
+            .await
+            .with_context(|| {
+                format!(
+                    "Couldn't import notification preference: {:?}",
+                    preference.user_id
+                )
+            })?;
+        }
+
+        for project in dump.projects {
+            sqlx::query("INSERT INTO projects (id, ext_id, created_at) VALUES ($1, $2, $3)")
+                .bind(project.id)
+                .bind(project.ext_id)
+                .bind(project.created_at)
+                .execute(&mut tx)
+                .await
+                .with_context(|| format!("Couldn't import project: {:?}", project.id))?;
+        }
+
+        for merge_request in dump.merge_requests {
+            sqlx::query(
+                "
+                INSERT INTO merge_requests (
+                    id,
+                    project_id,
+                    ext_id,
+                    ext_iid,
+                    ext_state,
+                    checked_at,
+                    created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ",
+            )
+            .bind(merge_request.id)
+            .bind(merge_request.project_id)
+            .bind(merge_request.ext_id)
+            .bind(merge_request.ext_iid)
+            .bind(&merge_request.ext_state)
+            .bind(merge_request.checked_at)
+            .bind(merge_request.created_at)
+            .execute(&mut tx)
+            .await
+            .with_context(|| format!("Couldn't import merge request: {:?}", merge_request.id))?;
+        }
+
+        for dependency in dump.merge_request_dependencies {
+            sqlx::query(
+                "
+                INSERT INTO merge_request_dependencies (
+                    id,
+                    user_id,
+                    ext_discussion_id,
+                    src_merge_request_id,
+                    dst_merge_request_id,
+                    created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ",
+            )
+            .bind(dependency.id)
+            .bind(dependency.user_id)
+            .bind(&dependency.ext_discussion_id)
+            .bind(dependency.src_merge_request_id)
+            .bind(dependency.dst_merge_request_id)
+            .bind(dependency.created_at)
+            .execute(&mut tx)
+            .await
+            .with_context(|| {
+                format!(
+                    "Couldn't import merge request dependency: {:?}",
+                    dependency.id
+                )
+            })?;
+        }
+
+        for reminder in dump.reminders {
+            sqlx::query(
+                "
+                INSERT INTO reminders (
+                    id,
+                    user_id,
+                    merge_request_id,
+                    ext_discussion_id,
+                    message,
+                    remind_at,
+                    trigger_event,
+                    trigger_merge_request_id,
+                    on_activity,
+                    inactivity_secs,
+                    created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                ",
+            )
+            .bind(reminder.id)
+            .bind(reminder.user_id)
+            .bind(reminder.merge_request_id)
+            .bind(&reminder.ext_discussion_id)
+            .bind(&reminder.message)
+            .bind(reminder.remind_at)
+            .bind(&reminder.trigger_event)
+            .bind(reminder.trigger_merge_request_id)
+            .bind(&reminder.on_activity)
+            .bind(reminder.inactivity_secs)
+            .bind(reminder.created_at)
+            .execute(&mut tx)
+            .await
+            .with_context(|| format!("Couldn't import reminder: {:?}", reminder.id))?;
+        }
+
+        for recipient in dump.reminder_recipients {
+            sqlx::query("INSERT INTO reminder_recipients (reminder_id, user_id) VALUES ($1, $2)")
+                .bind(recipient.reminder_id)
+                .bind(recipient.user_id)
+                .execute(&mut tx)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Couldn't import reminder's recipient: {:?}",
+                        recipient.reminder_id
+                    )
+                })?;
+        }
+
+        for nudge in dump.nudges {
+            sqlx::query(
+                "INSERT INTO nudges (merge_request_id, level, nudged_at) VALUES ($1, $2, $3)",
+            )
+            .bind(nudge.merge_request_id)
+            .bind(nudge.level)
+            .bind(nudge.nudged_at)
+            .execute(&mut tx)
+            .await
+            .with_context(|| format!("Couldn't import nudge: {:?}", nudge.merge_request_id))?;
+        }
+
+        for subscription in dump.digest_subscriptions {
+            sqlx::query(
+                "
+                INSERT INTO digest_subscriptions (
+                    user_id,
+                    day_of_week,
+                    send_at,
+                    timezone,
+                    next_digest_at,
+                    last_digest_at,
+                    ext_issue_project_id,
+                    ext_issue_iid
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ",
+            )
+            .bind(subscription.user_id)
+            .bind(subscription.day_of_week)
+            .bind(&subscription.send_at)
+            .bind(&subscription.timezone)
+            .bind(subscription.next_digest_at)
+            .bind(subscription.last_digest_at)
+            .bind(subscription.ext_issue_project_id)
+            .bind(subscription.ext_issue_iid)
+            .execute(&mut tx)
+            .await
+            .with_context(|| {
+                format!(
+                    "Couldn't import digest subscription: {:?}",
+                    subscription.user_id
+                )
+            })?;
+        }
+
+        for log in dump.logs {
+            sqlx::query(
+                "
+                INSERT INTO logs (
+                    id,
+                    event,
+                    payload,
+                    correlation_id,
+                    outcome,
+                    error,
+                    duration_ms,
+                    gitlab_calls,
+                    created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                ",
+            )
+            .bind(log.id)
+            .bind(&log.event)
+            .bind(&log.payload)
+            .bind(&log.correlation_id)
+            .bind(&log.outcome)
+            .bind(&log.error)
+            .bind(log.duration_ms)
+            .bind(&log.gitlab_calls)
+            .bind(log.created_at)
+            .execute(&mut tx)
+            .await
+            .with_context(|| format!("Couldn't import log entry: {:?}", log.id))?;
+        }
+
+        // Since we've provided ids explicitly, Postgres' sequence doesn't know
+        // about them - so let's bump it manually, lest it generates ids that
+        // already exist
+        #[cfg(feature = "postgres")]
+        if self.backend == Backend::Postgres {
+            sqlx::query(
+                "SELECT setval(pg_get_serial_sequence('logs', 'id'), (SELECT coalesce(max(id), 0) + 1 FROM logs), false)",
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_merge_request, create_project, create_user};
+    use chrono::Utc;
+    use lib_gitlab as gl;
+
+    async fn populated_database() -> Database {
+        let db = Database::mock().await;
+        let user_id = create_user(&db, 250).await;
+        let project_id = create_project(&db, 10).await;
+        let src_merge_request_id = create_merge_request(&db, project_id, 100, 1).await;
+        let dst_merge_request_id = create_merge_request(&db, project_id, 101, 2).await;
+
+        db.execute(SetNotificationPreference {
+            user_id,
+            channel: "slack".into(),
+        })
+        .await
+        .unwrap();
+
+        db.execute(CreateMergeRequestDependency {
+            user_id,
+            ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+            src_merge_request_id,
+            dst_merge_request_id,
+        })
+        .await
+        .unwrap();
+
+        db.execute(CreateReminder {
+            user_id,
+            recipients: vec![user_id],
+            merge_request_id: src_merge_request_id,
+            ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+            message: Some("oh hi, mark!".into()),
+            remind_at: Some(Utc::now()),
+            trigger_event: None,
+            trigger_merge_request_id: None,
+            on_activity: None,
+            inactivity_secs: None,
+        })
+        .await
+        .unwrap();
+
+        db.execute(CreateLogEntry {
+            event: "command".into(),
+            payload: "{}".into(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        db
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn exported_database_can_be_imported() {
+        let source = populated_database().await;
+        let target = Database::mock().await;
+
+        let expected = source.export().await.unwrap();
+
+        target.import(expected.clone()).await.unwrap();
+
+        let actual = target.export().await.unwrap();
+
+        // Models don't implement `PartialEq`, so let's compare them through
+        // JSON
+        assert_eq!(
+            serde_json::to_value(&expected).unwrap(),
+            serde_json::to_value(&actual).unwrap(),
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn dump_cannot_be_imported_into_non_empty_database() {
+        let source = populated_database().await;
+        let dump = source.export().await.unwrap();
+
+        assert!(source.import(dump).await.is_err());
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn dump_cannot_be_imported_into_database_with_just_logs() {
+        let dump = Database::mock().await.export().await.unwrap();
+        let target = Database::mock().await;
+
+        target
+            .execute(CreateLogEntry {
+                event: "command".into(),
+                payload: "{}".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert!(target.import(dump).await.is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use sqlx::any::{Any, AnyArguments};
+use sqlx::{Arguments, Encode, Type};
+
+/// Query built out of optional filters.
+///
+/// Takes care of numbering the placeholders, since - as opposed to SQLite -
+/// Postgres doesn't understand the anonymous `?` ones.
+crate struct DynamicQuery<'q> {
+    sql: String,
+    args: AnyArguments<'q>,
+    args_count: usize,
+}
+
+impl<'q> DynamicQuery<'q> {
+    crate fn new(sql: impl Into<String>) -> Self {
+        Self {
+            sql: sql.into(),
+            args: Default::default(),
+            args_count: 0,
+        }
+    }
+
+    /// Appends given SQL to the query, replacing its `?` with a placeholder
+    /// for `arg`
+    crate fn push<T>(&mut self, sql: &str, arg: T)
+    where
+        T: 'q + Send + Encode<'q, Any> + Type<Any>,
+    {
+        self.args_count += 1;
+        self.sql += &sql.replace('?', &format!("${}", self.args_count));
+        self.args.add(arg);
+    }
+
+    /// Appends given SQL to the query as-is
+    crate fn push_sql(&mut self, sql: &str) {
+        self.sql += sql;
+    }
+
+    crate fn build(self) -> (String, AnyArguments<'q>) {
+        (self.sql, self.args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholders_are_numbered() {
+        let mut query = DynamicQuery::new("SELECT * FROM users WHERE 1 = 1");
+
+        query.push(" AND id = ?", "foo");
+        query.push(" AND ext_id = ?", 123);
+
+        let (sql, _) = query.build();
+
+        assert_eq!(
+            "SELECT * FROM users WHERE 1 = 1 AND id = $1 AND ext_id = $2",
+            sql
+        );
+    }
+}
@@ -1,26 +1,33 @@
 pub use self::{
+    digest_subscriptions::*,
     logs::*,
     merge_request_dependencies::*,
     merge_requests::*,
+    notification_preferences::*,
+    nudges::*,
     projects::*,
     reminders::*,
     users::*,
 };
 
+mod digest_subscriptions;
 mod logs;
 mod merge_request_dependencies;
 mod merge_requests;
+mod notification_preferences;
+mod nudges;
 mod projects;
 mod reminders;
 mod users;
 
 crate mod prelude {
+    pub use crate::dynamic_query::DynamicQuery;
     pub use crate::{Command, Database, Id, Query};
     pub use anyhow::*;
     pub use async_trait::async_trait;
     pub use chrono::{DateTime, Utc};
     pub use lib_gitlab as gl;
-    pub use sqlx::sqlite::SqliteArguments;
-    pub use sqlx::{Arguments, FromRow, Sqlite};
+    pub use serde::{Deserialize, Serialize};
+    pub use sqlx::FromRow;
     pub use std::ops::DerefMut;
 }
@@ -0,0 +1,8 @@
+pub use self::{delete::*, digest_subscription::*, find::*, mark_sent::*, reschedule::*, set::*};
+
+mod delete;
+mod digest_subscription;
+mod find;
+mod mark_sent;
+mod reschedule;
+mod set;
@@ -0,0 +1,68 @@
+use crate::features::prelude::*;
+use crate::User;
+
+/// Unsubscribes given user from digests
+#[derive(Clone, Debug)]
+pub struct DeleteDigestSubscription {
+    /// Internal id of the user who doesn't want to get digests anymore
+    pub user_id: Id<User>,
+}
+
+#[async_trait]
+impl Command for DeleteDigestSubscription {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Deleting digest subscription");
+
+        sqlx::query("DELETE FROM digest_subscriptions WHERE user_id = $1")
+            .bind(self.user_id)
+            .execute(db.writer().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't delete digest subscription: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::{FindDigestSubscriptions, SetDigestSubscription};
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let users = [create_user(&db, 250).await, create_user(&db, 251).await];
+
+        for &user_id in &users {
+            db.execute(SetDigestSubscription {
+                user_id,
+                day_of_week: None,
+                send_at: "09:00".to_string(),
+                timezone: None,
+                next_digest_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+        }
+
+        db.execute(DeleteDigestSubscription { user_id: users[0] })
+            .await
+            .unwrap();
+
+        assert!(db
+            .get_opt(FindDigestSubscriptions::user_id(users[0]))
+            .await
+            .unwrap()
+            .is_none());
+
+        assert!(db
+            .get_opt(FindDigestSubscriptions::user_id(users[1]))
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
@@ -0,0 +1,47 @@
+use crate::features::prelude::*;
+use crate::User;
+
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
+pub struct DigestSubscription {
+    /// Internal id of the user who wants to get digests
+    pub user_id: Id<User>,
+
+    /// ISO 8601 weekday number (`1` for Monday) the digest should be sent on;
+    /// `None` for daily digests
+    pub day_of_week: Option<i64>,
+
+    /// Time of day the digest should be sent at, e.g. `09:00`
+    pub send_at: String,
+
+    /// Timezone `send_at` refers to, e.g. `Europe/Warsaw`; `None` means
+    /// Janet's local timezone
+    pub timezone: Option<String>,
+
+    /// When the next digest should be sent
+    pub next_digest_at: DateTime<Utc>,
+
+    /// When the previous digest was sent; `None` if it hasn't been sent yet
+    pub last_digest_at: Option<DateTime<Utc>>,
+
+    /// GitLab's project id of the issue the previous digest was delivered
+    /// through (if any)
+    pub ext_issue_project_id: Option<i64>,
+
+    /// GitLab's iid of the issue the previous digest was delivered through
+    /// (if any), so that it can be closed once a newer digest arrives
+    pub ext_issue_iid: Option<i64>,
+}
+
+impl DigestSubscription {
+    /// Returns the issue the previous digest was delivered through (if any)
+    pub fn ext_issue(&self) -> Option<(gl::ProjectId, gl::IssueIid)> {
+        match (self.ext_issue_project_id, self.ext_issue_iid) {
+            (Some(project_id), Some(iid)) => Some((
+                gl::ProjectId::new(project_id as _),
+                gl::IssueIid::new(iid as _),
+            )),
+
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::features::prelude::*;
+use crate::{DigestSubscription, User};
+
+#[derive(Clone, Debug, Default)]
+pub struct FindDigestSubscriptions {
+    /// Internal id of the user who wants to get digests
+    pub user_id: Option<Id<User>>,
+
+    /// When set, returns subscriptions whose digest is due by given date (i.e.
+    /// the ones where `next_digest_at <= $due_by`)
+    pub due_by: Option<DateTime<Utc>>,
+}
+
+impl FindDigestSubscriptions {
+    pub fn user_id(user_id: Id<User>) -> Self {
+        Self {
+            user_id: Some(user_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn due_by(due_by: DateTime<Utc>) -> Self {
+        Self {
+            due_by: Some(due_by),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl Query for FindDigestSubscriptions {
+    type Model = DigestSubscription;
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
+        tracing::debug!("Finding digest subscriptions");
+
+        let mut query = DynamicQuery::new("SELECT * FROM digest_subscriptions WHERE 1 = 1");
+
+        if let Some(user_id) = self.user_id {
+            query.push(" AND user_id = ?", user_id);
+        }
+
+        if let Some(due_by) = self.due_by {
+            query.push(" AND next_digest_at <= ?", due_by);
+        }
+
+        let (query, args) = query.build();
+
+        sqlx::query_as_with(&query, args)
+            .fetch_all(db.reader().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't find digest subscriptions for query: {:?}", self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::SetDigestSubscription;
+    use chrono::TimeZone;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let users = [create_user(&db, 250).await, create_user(&db, 251).await];
+
+        for (&user_id, at) in users.iter().zip(vec![100, 200]) {
+            db.execute(SetDigestSubscription {
+                user_id,
+                day_of_week: None,
+                send_at: "09:00".to_string(),
+                timezone: None,
+                next_digest_at: Utc.timestamp(at, 0),
+            })
+            .await
+            .unwrap();
+        }
+
+        let cases = vec![
+            (FindDigestSubscriptions::default(), vec![users[0], users[1]]),
+            (FindDigestSubscriptions::user_id(users[1]), vec![users[1]]),
+            (
+                FindDigestSubscriptions::due_by(Utc.timestamp(50, 0)),
+                vec![],
+            ),
+            (
+                FindDigestSubscriptions::due_by(Utc.timestamp(150, 0)),
+                vec![users[0]],
+            ),
+        ];
+
+        for (case_idx, (query, expected)) in cases.into_iter().enumerate() {
+            let mut actual: Vec<_> = db
+                .get_all(query)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|subscription| subscription.user_id)
+                .collect();
+
+            let mut expected = expected;
+
+            actual.sort();
+            expected.sort();
+
+            assert_eq!(expected, actual, "Test case #{} failed", case_idx);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::features::prelude::*;
+use crate::User;
+
+/// Records that user's digest has been just sent, so that the next one
+/// doesn't repeat what's been already reported
+#[derive(Clone, Debug)]
+pub struct MarkDigestSent {
+    /// Internal id of the user who got the digest
+    pub user_id: Id<User>,
+
+    /// When the digest was sent
+    pub sent_at: DateTime<Utc>,
+
+    /// GitLab's issue the digest was delivered through; `None` if it was
+    /// delivered some other way (e.g. through Slack)
+    pub ext_issue: Option<(gl::ProjectId, gl::IssueIid)>,
+}
+
+#[async_trait]
+impl Command for MarkDigestSent {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Marking digest as sent");
+
+        let (ext_issue_project_id, ext_issue_iid) = match self.ext_issue {
+            Some((project_id, iid)) => (Some(project_id.inner() as i64), Some(iid.inner() as i64)),
+
+            None => (None, None),
+        };
+
+        sqlx::query(
+            "
+            UPDATE digest_subscriptions
+            SET last_digest_at = $1,
+                ext_issue_project_id = $2,
+                ext_issue_iid = $3
+            WHERE user_id = $4
+            ",
+        )
+        .bind(self.sent_at)
+        .bind(ext_issue_project_id)
+        .bind(ext_issue_iid)
+        .bind(self.user_id)
+        .execute(db.writer().await?.deref_mut())
+        .await
+        .with_context(|| format!("Couldn't mark digest as sent: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::{FindDigestSubscriptions, SetDigestSubscription};
+    use chrono::TimeZone;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let user_id = create_user(&db, 250).await;
+
+        db.execute(SetDigestSubscription {
+            user_id,
+            day_of_week: None,
+            send_at: "09:00".to_string(),
+            timezone: None,
+            next_digest_at: Utc.timestamp(100, 0),
+        })
+        .await
+        .unwrap();
+
+        let cases = vec![
+            (
+                Utc.timestamp(100, 0),
+                Some((gl::ProjectId::new(10), gl::IssueIid::new(7))),
+            ),
+            (Utc.timestamp(200, 0), None),
+        ];
+
+        for (sent_at, ext_issue) in cases {
+            db.execute(MarkDigestSent {
+                user_id,
+                sent_at,
+                ext_issue,
+            })
+            .await
+            .unwrap();
+
+            let subscription = db
+                .get_one(FindDigestSubscriptions::user_id(user_id))
+                .await
+                .unwrap();
+
+            assert_eq!(Some(sent_at), subscription.last_digest_at);
+            assert_eq!(ext_issue, subscription.ext_issue());
+        }
+    }
+}
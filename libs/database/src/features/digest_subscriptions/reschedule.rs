@@ -0,0 +1,70 @@
+use crate::features::prelude::*;
+use crate::User;
+
+/// Moves user's next digest to given time (e.g. because the current one has
+/// just been sent)
+#[derive(Clone, Debug)]
+pub struct RescheduleDigestSubscription {
+    /// Internal id of the user who wants to get digests
+    pub user_id: Id<User>,
+
+    /// When the next digest should be sent
+    pub next_digest_at: DateTime<Utc>,
+}
+
+#[async_trait]
+impl Command for RescheduleDigestSubscription {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Rescheduling digest subscription");
+
+        sqlx::query("UPDATE digest_subscriptions SET next_digest_at = $1 WHERE user_id = $2")
+            .bind(self.next_digest_at)
+            .bind(self.user_id)
+            .execute(db.writer().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't reschedule digest subscription: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::{FindDigestSubscriptions, SetDigestSubscription};
+    use chrono::TimeZone;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let user_id = create_user(&db, 250).await;
+
+        db.execute(SetDigestSubscription {
+            user_id,
+            day_of_week: None,
+            send_at: "09:00".to_string(),
+            timezone: None,
+            next_digest_at: Utc.timestamp(100, 0),
+        })
+        .await
+        .unwrap();
+
+        db.execute(RescheduleDigestSubscription {
+            user_id,
+            next_digest_at: Utc.timestamp(200, 0),
+        })
+        .await
+        .unwrap();
+
+        let subscription = db
+            .get_one(FindDigestSubscriptions::user_id(user_id))
+            .await
+            .unwrap();
+
+        assert_eq!(Utc.timestamp(200, 0), subscription.next_digest_at);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::features::prelude::*;
+use crate::User;
+
+/// Subscribes given user to digests, replacing their previous schedule (if
+/// any)
+#[derive(Clone, Debug)]
+pub struct SetDigestSubscription {
+    /// Internal id of the user who wants to get digests
+    pub user_id: Id<User>,
+
+    /// ISO 8601 weekday number (`1` for Monday) the digest should be sent on;
+    /// `None` for daily digests
+    pub day_of_week: Option<i64>,
+
+    /// Time of day the digest should be sent at, e.g. `09:00`
+    pub send_at: String,
+
+    /// Timezone `send_at` refers to, e.g. `Europe/Warsaw`; `None` means
+    /// Janet's local timezone
+    pub timezone: Option<String>,
+
+    /// When the first digest should be sent
+    pub next_digest_at: DateTime<Utc>,
+}
+
+#[async_trait]
+impl Command for SetDigestSubscription {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Setting digest subscription");
+
+        sqlx::query(
+            "
+            INSERT INTO digest_subscriptions (
+                user_id,
+                day_of_week,
+                send_at,
+                timezone,
+                next_digest_at
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id)
+            DO UPDATE SET
+                day_of_week = excluded.day_of_week,
+                send_at = excluded.send_at,
+                timezone = excluded.timezone,
+                next_digest_at = excluded.next_digest_at
+            ",
+        )
+        .bind(self.user_id)
+        .bind(self.day_of_week)
+        .bind(&self.send_at)
+        .bind(&self.timezone)
+        .bind(self.next_digest_at)
+        .execute(db.writer().await?.deref_mut())
+        .await
+        .with_context(|| format!("Couldn't set digest subscription: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::FindDigestSubscriptions;
+    use chrono::TimeZone;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let user_id = create_user(&db, 250).await;
+
+        let cases = vec![
+            (None, "09:00", None),
+            (Some(1), "08:30", Some("Europe/Warsaw".to_string())),
+        ];
+
+        for (day_of_week, send_at, timezone) in cases {
+            db.execute(SetDigestSubscription {
+                user_id,
+                day_of_week,
+                send_at: send_at.to_string(),
+                timezone: timezone.clone(),
+                next_digest_at: Utc.timestamp(100, 0),
+            })
+            .await
+            .unwrap();
+
+            let subscription = db
+                .get_one(FindDigestSubscriptions::user_id(user_id))
+                .await
+                .unwrap();
+
+            assert_eq!(day_of_week, subscription.day_of_week);
+            assert_eq!(send_at, subscription.send_at);
+            assert_eq!(timezone, subscription.timezone);
+            assert_eq!(Utc.timestamp(100, 0), subscription.next_digest_at);
+        }
+    }
+}
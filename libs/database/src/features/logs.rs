@@ -1,5 +1,7 @@
-pub use self::{create::*, find::*, log_entry::*};
+pub use self::{create::*, delete::*, find::*, log_entry::*, update::*};
 
 mod create;
+mod delete;
 mod find;
 mod log_entry;
+mod update;
@@ -1,30 +1,61 @@
 use crate::features::prelude::*;
+use crate::Backend;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct CreateLogEntry {
-    /// Entry's origin; currently it's either "command" or "event"
+    /// Entry's origin; currently it's "command", "event", "reminder", "nudge"
+    /// or "digest" (only the first two can be replayed - `replay` skips the
+    /// rest)
     pub event: String,
 
     /// Entry's content; currently it's always a JSON object
     pub payload: String,
+
+    /// Identifier shared by all entries related to the same request
+    pub correlation_id: Option<String>,
 }
 
 #[async_trait]
 impl Command for CreateLogEntry {
-    type Output = ();
+    /// Id of the created entry
+    type Output = i64;
 
     #[tracing::instrument(skip(db))]
     async fn execute(self, db: &Database) -> Result<Self::Output> {
         tracing::debug!("Creating log entry");
 
-        sqlx::query("INSERT INTO logs (event, payload) VALUES (?, ?)")
-            .bind(&self.event)
-            .bind(&self.payload)
-            .execute(db.lock().await.deref_mut())
-            .await
-            .with_context(|| format!("Couldn't create log entry: {:?}", self))?;
+        // `created_at` is provided explicitly (instead of relying on column's
+        // default value, which on SQLite is in local time), so that it can be
+        // compared with dates passed to `FindLogEntries` & `DeleteLogEntries`
+        let query = "
+            INSERT INTO logs (event, payload, correlation_id, created_at)
+            VALUES ($1, $2, $3, $4)
+        ";
+
+        let id = match db.backend() {
+            Backend::Sqlite => sqlx::query(query)
+                .bind(&self.event)
+                .bind(&self.payload)
+                .bind(&self.correlation_id)
+                .bind(Utc::now())
+                .execute(db.writer().await?.deref_mut())
+                .await
+                .map(|result| result.last_insert_id()),
 
-        Ok(())
+            // Postgres doesn't report ids of inserted rows on its own
+            #[cfg(feature = "postgres")]
+            Backend::Postgres => sqlx::query_as::<_, (i64,)>(&format!("{} RETURNING id", query))
+                .bind(&self.event)
+                .bind(&self.payload)
+                .bind(&self.correlation_id)
+                .bind(Utc::now())
+                .fetch_one(db.writer().await?.deref_mut())
+                .await
+                .map(|(id,)| Some(id)),
+        };
+
+        id.with_context(|| format!("Couldn't create log entry: {:?}", self))?
+            .context("Database didn't return entry's id")
     }
 }
 
@@ -37,26 +68,38 @@ mod tests {
     async fn test() {
         let db = Database::mock().await;
 
-        db.execute(CreateLogEntry {
-            event: "some-event-1".to_string(),
-            payload: "some-payload-1".to_string(),
-        })
-        .await
-        .unwrap();
+        let id_1 = db
+            .execute(CreateLogEntry {
+                event: "some-event-1".to_string(),
+                payload: "some-payload-1".to_string(),
+                correlation_id: Some("some-correlation-id".to_string()),
+            })
+            .await
+            .unwrap();
 
-        db.execute(CreateLogEntry {
-            event: "some-event-2".to_string(),
-            payload: "some-payload-2".to_string(),
-        })
-        .await
-        .unwrap();
+        let id_2 = db
+            .execute(CreateLogEntry {
+                event: "some-event-2".to_string(),
+                payload: "some-payload-2".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
 
-        let logs = db.get_all(FindLogEntries).await.unwrap();
+        let logs = db.get_all(FindLogEntries::default()).await.unwrap();
 
         assert_eq!(2, logs.len());
+        assert_eq!(id_1, logs[0].id);
         assert_eq!("some-event-1", logs[0].event);
         assert_eq!("some-payload-1", logs[0].payload);
+        assert_eq!(
+            Some("some-correlation-id"),
+            logs[0].correlation_id.as_deref()
+        );
+        assert_eq!(None, logs[0].outcome);
+        assert_eq!(id_2, logs[1].id);
         assert_eq!("some-event-2", logs[1].event);
         assert_eq!("some-payload-2", logs[1].payload);
+        assert_eq!(None, logs[1].correlation_id);
     }
 }
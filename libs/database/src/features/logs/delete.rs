@@ -0,0 +1,139 @@
+use crate::features::prelude::*;
+
+/// Deletes log entries; used to enforce the retention policy
+#[derive(Clone, Debug, Default)]
+pub struct DeleteLogEntries {
+    /// When set, deletes entries created before given date
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// When set, deletes all but given number of the newest entries
+    pub keep_newest: Option<usize>,
+}
+
+#[async_trait]
+impl Command for DeleteLogEntries {
+    /// Number of deleted entries
+    type Output = u64;
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Deleting log entries");
+
+        let mut deleted = 0;
+
+        if let Some(created_before) = self.created_before {
+            deleted += sqlx::query("DELETE FROM logs WHERE created_at < $1")
+                .bind(created_before)
+                .execute(db.writer().await?.deref_mut())
+                .await
+                .with_context(|| format!("Couldn't delete log entries: {:?}", self))?
+                .rows_affected();
+        }
+
+        if let Some(keep_newest) = self.keep_newest {
+            deleted += sqlx::query(
+                "
+                DELETE FROM logs
+                WHERE id <= (
+                    SELECT id
+                    FROM logs
+                    ORDER BY id DESC
+                    LIMIT 1 OFFSET $1
+                )
+                ",
+            )
+            .bind(keep_newest as i64)
+            .execute(db.writer().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't delete log entries: {:?}", self))?
+            .rows_affected();
+        }
+
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CreateLogEntry, FindLogEntries};
+
+    async fn database(n: usize) -> Database {
+        let db = Database::mock().await;
+
+        for i in 0..n {
+            db.execute(CreateLogEntry {
+                event: "event".into(),
+                payload: format!("{}", i),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        }
+
+        db
+    }
+
+    async fn payloads(db: &Database) -> Vec<String> {
+        db.get_all(FindLogEntries::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|log| log.payload)
+            .collect()
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn deletes_entries_older_than_given_date() {
+        let db = database(3).await;
+
+        let deleted = db
+            .execute(DeleteLogEntries {
+                created_before: Some(Utc::now() - chrono::Duration::hours(1)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(0, deleted);
+        assert_eq!(3, payloads(&db).await.len());
+
+        let deleted = db
+            .execute(DeleteLogEntries {
+                created_before: Some(Utc::now() + chrono::Duration::hours(1)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(3, deleted);
+        assert!(payloads(&db).await.is_empty());
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn keeps_given_number_of_newest_entries() {
+        let db = database(5).await;
+
+        let deleted = db
+            .execute(DeleteLogEntries {
+                keep_newest: Some(2),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(3, deleted);
+        assert_eq!(vec!["3", "4"], payloads(&db).await);
+
+        let deleted = db
+            .execute(DeleteLogEntries {
+                keep_newest: Some(10),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(0, deleted);
+        assert_eq!(vec!["3", "4"], payloads(&db).await);
+    }
+}
@@ -1,8 +1,62 @@
 use crate::features::prelude::*;
 use crate::features::LogEntry;
+use crate::Backend;
 
 #[derive(Clone, Debug, Default)]
-pub struct FindLogEntries;
+pub struct FindLogEntries {
+    /// When set, returns entry with given id
+    pub id: Option<i64>,
+
+    /// When set, returns entries with given origin (e.g. "command")
+    pub event: Option<String>,
+
+    /// When set, returns entries with given correlation id (e.g. the ones
+    /// related to a specific webhook delivery)
+    pub correlation_id: Option<String>,
+
+    /// When set, returns entries with ids greater than given one; comes handy
+    /// for following the logs
+    pub after_id: Option<i64>,
+
+    /// When set, returns entries created at or after given date
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// When set, returns entries created before given date
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// When set, returns entries whose payload contains given field with given
+    /// value - e.g. `("user.username", "someone")`; fields are compared as
+    /// text, so numbers have to be provided as strings too
+    pub payload: Option<(String, String)>,
+
+    /// When set, returns the newest entries first (by default the oldest ones
+    /// come first)
+    pub newest_first: bool,
+
+    /// When set, returns at most given number of entries
+    pub limit: Option<usize>,
+
+    /// When set, skips given number of entries
+    pub offset: Option<usize>,
+}
+
+impl FindLogEntries {
+    pub fn event(event: impl Into<String>) -> Self {
+        Self {
+            event: Some(event.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns `n` most recent entries, newest first
+    pub fn newest(n: usize) -> Self {
+        Self {
+            newest_first: true,
+            limit: Some(n),
+            ..Default::default()
+        }
+    }
+}
 
 #[async_trait]
 impl Query for FindLogEntries {
@@ -12,9 +66,211 @@ impl Query for FindLogEntries {
     async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
         tracing::debug!("Finding log entries");
 
-        sqlx::query_as("SELECT * FROM logs")
-            .fetch_all(db.lock().await.deref_mut())
+        let mut query = DynamicQuery::new("SELECT * FROM logs WHERE 1 = 1");
+
+        if let Some(id) = self.id {
+            query.push(" AND id = ?", id);
+        }
+
+        if let Some(event) = &self.event {
+            query.push(" AND event = ?", event.as_str());
+        }
+
+        if let Some(correlation_id) = &self.correlation_id {
+            query.push(" AND correlation_id = ?", correlation_id.as_str());
+        }
+
+        if let Some(after_id) = self.after_id {
+            query.push(" AND id > ?", after_id);
+        }
+
+        if let Some(created_after) = self.created_after {
+            query.push(" AND created_at >= ?", created_after);
+        }
+
+        if let Some(created_before) = self.created_before {
+            query.push(" AND created_at < ?", created_before);
+        }
+
+        if let Some((path, value)) = &self.payload {
+            match db.backend() {
+                Backend::Sqlite => {
+                    query.push(
+                        " AND CAST(json_extract(payload, ?) AS TEXT)",
+                        format!("$.{}", path),
+                    );
+                }
+
+                #[cfg(feature = "postgres")]
+                Backend::Postgres => {
+                    query.push(
+                        " AND (payload::jsonb #>> string_to_array(?, '.'))",
+                        path.as_str(),
+                    );
+                }
+            }
+
+            query.push(" = ?", value.as_str());
+        }
+
+        if self.newest_first {
+            query.push_sql(" ORDER BY id DESC");
+        } else {
+            query.push_sql(" ORDER BY id");
+        }
+
+        // SQLite doesn't support `OFFSET` without `LIMIT`, so when only the
+        // offset is set, we have to provide some (large enough) limit too
+        if self.limit.is_some() || self.offset.is_some() {
+            query.push(
+                " LIMIT ?",
+                self.limit.map(|limit| limit as i64).unwrap_or(i64::MAX),
+            );
+        }
+
+        if let Some(offset) = self.offset {
+            query.push(" OFFSET ?", offset as i64);
+        }
+
+        let (sql, args) = query.build();
+
+        sqlx::query_as_with(&sql, args)
+            .fetch_all(db.reader().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't find log entries for query: {:?}", self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CreateLogEntry;
+
+    async fn database() -> Database {
+        let db = Database::mock().await;
+
+        for (event, payload) in &[
+            ("command", r#"{ "user": { "id": 1 }, "cmd": "hi" }"#),
+            ("event", r#"{ "user": { "id": 2 } }"#),
+            (
+                "command",
+                r#"{ "user": { "id": 2 }, "cmd": "depends on !1" }"#,
+            ),
+        ] {
+            db.execute(CreateLogEntry {
+                event: event.to_string(),
+                payload: payload.to_string(),
+                ..Default::default()
+            })
             .await
-            .context("Couldn't find log entries")
+            .unwrap();
+        }
+
+        db
+    }
+
+    async fn find(db: &Database, query: FindLogEntries) -> Vec<i64> {
+        db.get_all(query)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|log| log.id)
+            .collect()
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn by_default_returns_all_entries_oldest_first() {
+        let db = database().await;
+
+        assert_eq!(vec![1, 2, 3], find(&db, Default::default()).await);
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn filters_by_event() {
+        let db = database().await;
+
+        assert_eq!(
+            vec![1, 3],
+            find(&db, FindLogEntries::event("command")).await
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn filters_by_id() {
+        let db = database().await;
+
+        let query = FindLogEntries {
+            id: Some(2),
+            ..Default::default()
+        };
+
+        assert_eq!(vec![2], find(&db, query).await);
+
+        let query = FindLogEntries {
+            after_id: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(vec![2, 3], find(&db, query).await);
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn filters_by_date() {
+        let db = database().await;
+
+        let query = FindLogEntries {
+            created_after: Some(Utc::now() + chrono::Duration::hours(1)),
+            ..Default::default()
+        };
+
+        assert!(find(&db, query).await.is_empty());
+
+        let query = FindLogEntries {
+            created_before: Some(Utc::now() + chrono::Duration::hours(1)),
+            ..Default::default()
+        };
+
+        assert_eq!(vec![1, 2, 3], find(&db, query).await);
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn filters_by_payload() {
+        let db = database().await;
+
+        let query = FindLogEntries {
+            payload: Some(("user.id".into(), "2".into())),
+            ..Default::default()
+        };
+
+        assert_eq!(vec![2, 3], find(&db, query).await);
+
+        let query = FindLogEntries {
+            payload: Some(("cmd".into(), "hi".into())),
+            ..Default::default()
+        };
+
+        assert_eq!(vec![1], find(&db, query).await);
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn supports_pagination() {
+        let db = database().await;
+
+        let query = FindLogEntries {
+            limit: Some(1),
+            offset: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(vec![2], find(&db, query).await);
+
+        let query = FindLogEntries {
+            offset: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(vec![2, 3], find(&db, query).await);
+
+        assert_eq!(vec![3, 2], find(&db, FindLogEntries::newest(2)).await);
     }
 }
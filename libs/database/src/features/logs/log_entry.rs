@@ -1,13 +1,36 @@
 use crate::features::prelude::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
 pub struct LogEntry {
-    /// Entry's origin; currently it's either "command" or "event"
+    /// Entry's id; ids grow monotonically, so they can be used to sort the
+    /// entries
+    pub id: i64,
+
+    /// Entry's origin; currently it's "command", "event", "reminder", "nudge"
+    /// or "digest" (only the first two can be replayed - `replay` skips the
+    /// rest)
     pub event: String,
 
     /// Entry's content; currently it's always a JSON object
     pub payload: String,
 
+    /// Identifier shared by all entries related to the same request - e.g.
+    /// GitLab's `X-Gitlab-Event-UUID` for entries created due to a webhook
+    pub correlation_id: Option<String>,
+
+    /// How handling the entry ended: `ok`, `err` or - when the entry is still
+    /// being handled (or Janet crashed in the meantime) - `None`
+    pub outcome: Option<String>,
+
+    /// Error that occurred while handling the entry, if any
+    pub error: Option<String>,
+
+    /// How long handling the entry took
+    pub duration_ms: Option<i64>,
+
+    /// Requests sent to GitLab while handling the entry; a JSON array
+    pub gitlab_calls: Option<String>,
+
     /// When the entry was created
     pub created_at: DateTime<Utc>,
 }
@@ -0,0 +1,88 @@
+use crate::features::prelude::*;
+
+/// Records how handling given log entry ended
+#[derive(Clone, Debug)]
+pub struct UpdateLogEntry {
+    /// Entry's id
+    pub id: i64,
+
+    /// How handling the entry ended: `ok` or `err`
+    pub outcome: String,
+
+    /// Error that occurred while handling the entry, if any
+    pub error: Option<String>,
+
+    /// How long handling the entry took
+    pub duration_ms: i64,
+
+    /// Requests sent to GitLab while handling the entry; a JSON array
+    pub gitlab_calls: String,
+}
+
+#[async_trait]
+impl Command for UpdateLogEntry {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Updating log entry");
+
+        sqlx::query(
+            "
+            UPDATE logs
+            SET outcome = $1,
+                error = $2,
+                duration_ms = $3,
+                gitlab_calls = $4
+            WHERE id = $5
+            ",
+        )
+        .bind(&self.outcome)
+        .bind(&self.error)
+        .bind(self.duration_ms)
+        .bind(&self.gitlab_calls)
+        .bind(self.id)
+        .execute(db.writer().await?.deref_mut())
+        .await
+        .with_context(|| format!("Couldn't update log entry: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CreateLogEntry, FindLogEntries};
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+
+        let id = db
+            .execute(CreateLogEntry {
+                event: "command".into(),
+                payload: "{}".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        db.execute(UpdateLogEntry {
+            id,
+            outcome: "err".into(),
+            error: Some("whoops".into()),
+            duration_ms: 123,
+            gitlab_calls: "[]".into(),
+        })
+        .await
+        .unwrap();
+
+        let log = db.get_one(FindLogEntries::default()).await.unwrap();
+
+        assert_eq!(Some("err"), log.outcome.as_deref());
+        assert_eq!(Some("whoops"), log.error.as_deref());
+        assert_eq!(Some(123), log.duration_ms);
+        assert_eq!(Some("[]"), log.gitlab_calls.as_deref());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::features::prelude::*;
-use crate::{MergeRequest, MergeRequestDependency, User};
+use crate::{FindMergeRequestDependencies, MergeRequest, MergeRequestDependency, User};
 
 #[derive(Clone, Debug)]
 pub struct CreateMergeRequestDependency {
@@ -28,16 +28,28 @@ impl Command for CreateMergeRequestDependency {
 
         let id = Id::default();
 
-        sqlx::query(
+        // Creating dependency is idempotent - i.e. creating the same dependency
+        // for the second time is a no-op; we're relying on the unique index
+        // here (instead of checking whether the dependency exists beforehand),
+        // so that two concurrent requests can't both get through
+        let result = sqlx::query(
             "
             INSERT INTO merge_request_dependencies (
                 id,
+                user_id,
+                ext_discussion_id,
+                src_merge_request_id,
+                dst_merge_request_id,
+                created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (
                 user_id,
                 ext_discussion_id,
                 src_merge_request_id,
                 dst_merge_request_id
             )
-            VALUES (?, ?, ?, ?, ?)
+            DO NOTHING
             ",
         )
         .bind(id)
@@ -45,11 +57,26 @@ impl Command for CreateMergeRequestDependency {
         .bind(self.ext_discussion_id.as_ref())
         .bind(self.src_merge_request_id)
         .bind(self.dst_merge_request_id)
-        .execute(db.lock().await.deref_mut())
+        .bind(Utc::now())
+        .execute(db.writer().await?.deref_mut())
         .await
         .with_context(|| format!("Couldn't create merge request dependency: {:?}", self))?;
 
-        Ok(id)
+        if result.rows_affected() > 0 {
+            return Ok(id);
+        }
+
+        let dependency = db
+            .get_one(FindMergeRequestDependencies {
+                user_id: Some(self.user_id),
+                ext_discussion_id: Some(&self.ext_discussion_id),
+                src_merge_request_id: Some(self.src_merge_request_id),
+                dst_merge_request_id: Some(self.dst_merge_request_id),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(dependency.id)
     }
 }
 
@@ -57,7 +84,6 @@ impl Command for CreateMergeRequestDependency {
 mod tests {
     use super::*;
     use crate::test_utils::*;
-    use crate::FindMergeRequestDependencies;
 
     #[tokio::test(threaded_scheduler)]
     async fn test() {
@@ -91,4 +117,36 @@ mod tests {
         assert_eq!(src_merge_request_id, dep.src_merge_request_id);
         assert_eq!(dst_merge_request_id, dep.dst_merge_request_id);
     }
+
+    mod when_creating_the_same_dependency_for_the_second_time {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_already_existing_id() {
+            let db = Database::mock().await;
+            let user_id = create_user(&db, 250).await;
+            let project_id = create_project(&db, 10).await;
+            let src_merge_request_id = create_merge_request(&db, project_id, 100, 1).await;
+            let dst_merge_request_id = create_merge_request(&db, project_id, 101, 2).await;
+
+            let command = CreateMergeRequestDependency {
+                user_id,
+                ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                src_merge_request_id,
+                dst_merge_request_id,
+            };
+
+            let id_1 = db.execute(command.clone()).await.unwrap();
+            let id_2 = db.execute(command.clone()).await.unwrap();
+
+            assert_eq!(id_1, id_2);
+
+            let deps = db
+                .get_all(FindMergeRequestDependencies::default())
+                .await
+                .unwrap();
+
+            assert_eq!(1, deps.len());
+        }
+    }
 }
@@ -14,9 +14,9 @@ impl Command for DeleteMergeRequestDependency {
     async fn execute(self, db: &Database) -> Result<Self::Output> {
         tracing::debug!("Deleting merge request dependency");
 
-        sqlx::query("DELETE FROM merge_request_dependencies WHERE id = ?")
+        sqlx::query("DELETE FROM merge_request_dependencies WHERE id = $1")
             .bind(self.id)
-            .execute(db.lock().await.deref_mut())
+            .execute(db.writer().await?.deref_mut())
             .await
             .with_context(|| format!("Couldn't delete merge request dependency: {:?}", self))?;
 
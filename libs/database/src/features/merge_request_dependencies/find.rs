@@ -29,36 +29,32 @@ impl Query for FindMergeRequestDependencies<'_> {
     async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
         tracing::debug!("Finding merge request dependencies");
 
-        let mut query = String::from("SELECT * FROM merge_request_dependencies WHERE 1 = 1");
-        let mut args = SqliteArguments::default();
+        let mut query = DynamicQuery::new("SELECT * FROM merge_request_dependencies WHERE 1 = 1");
 
         if let Some(id) = self.id {
-            query += " AND id = ?";
-            args.add(id);
+            query.push(" AND id = ?", id);
         }
 
         if let Some(user_id) = self.user_id {
-            query += " AND user_id = ?";
-            args.add(user_id);
+            query.push(" AND user_id = ?", user_id);
         }
 
         if let Some(ext_discussion_id) = self.ext_discussion_id {
-            query += " AND ext_discussion_id = ?";
-            args.add(ext_discussion_id.as_ref());
+            query.push(" AND ext_discussion_id = ?", ext_discussion_id.as_ref());
         }
 
         if let Some(src_merge_request_id) = self.src_merge_request_id {
-            query += " AND src_merge_request_id = ?";
-            args.add(src_merge_request_id);
+            query.push(" AND src_merge_request_id = ?", src_merge_request_id);
         }
 
         if let Some(dst_merge_request_id) = self.dst_merge_request_id {
-            query += " AND dst_merge_request_id = ?";
-            args.add(dst_merge_request_id);
+            query.push(" AND dst_merge_request_id = ?", dst_merge_request_id);
         }
 
+        let (query, args) = query.build();
+
         sqlx::query_as_with(&query, args)
-            .fetch_all(db.lock().await.deref_mut())
+            .fetch_all(db.reader().await?.deref_mut())
             .await
             .with_context(|| {
                 format!(
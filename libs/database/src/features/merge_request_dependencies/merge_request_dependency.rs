@@ -1,7 +1,7 @@
 use crate::features::prelude::*;
 use crate::{MergeRequest, User};
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
 pub struct MergeRequestDependency {
     /// Internal dependency id
     pub id: Id<Self>,
@@ -31,6 +31,7 @@ impl Command for CreateMergeRequest {
         tracing::debug!("Creating merge request");
 
         let id = Id::default();
+        let now = Utc::now();
 
         sqlx::query(
             "
@@ -39,9 +40,11 @@ impl Command for CreateMergeRequest {
                 project_id,
                 ext_id,
                 ext_iid,
-                ext_state
+                ext_state,
+                checked_at,
+                created_at
             )
-            VALUES (?, ?, ?, ?, ?)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             ",
         )
         .bind(id)
@@ -49,7 +52,9 @@ impl Command for CreateMergeRequest {
         .bind(self.ext_id.inner() as i64)
         .bind(self.ext_iid.inner() as i64)
         .bind(&self.ext_state)
-        .execute(db.lock().await.deref_mut())
+        .bind(now)
+        .bind(now)
+        .execute(db.writer().await?.deref_mut())
         .await
         .with_context(|| format!("Couldn't create merge request: {:?}", self))?;
 
@@ -40,7 +40,7 @@ impl Query for FindMergeRequests {
     async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
         tracing::debug!("Finding merge requests");
 
-        let mut query = String::from(
+        let mut query = DynamicQuery::new(
             "
              SELECT
                 mr.*
@@ -56,30 +56,26 @@ impl Query for FindMergeRequests {
             ",
         );
 
-        let mut args = SqliteArguments::default();
-
         if let Some(id) = self.id {
-            query += " AND mr.id = ?";
-            args.add(id);
+            query.push(" AND mr.id = ?", id);
         }
 
         if let Some(ext_id) = self.ext_id {
-            query += " AND mr.ext_id = ?";
-            args.add(ext_id.inner() as i64);
+            query.push(" AND mr.ext_id = ?", ext_id.inner() as i64);
         }
 
         if let Some(ext_iid) = self.ext_iid {
-            query += " AND mr.ext_iid = ?";
-            args.add(ext_iid.inner() as i64);
+            query.push(" AND mr.ext_iid = ?", ext_iid.inner() as i64);
         }
 
         if let Some(ext_project_id) = self.ext_project_id {
-            query += " AND p.ext_id = ?";
-            args.add(ext_project_id.inner() as i64);
+            query.push(" AND p.ext_id = ?", ext_project_id.inner() as i64);
         }
 
+        let (query, args) = query.build();
+
         sqlx::query_as_with(&query, args)
-            .fetch_all(db.lock().await.deref_mut())
+            .fetch_all(db.reader().await?.deref_mut())
             .await
             .with_context(|| format!("Couldn't find merge requests for query: {:?}", self))
     }
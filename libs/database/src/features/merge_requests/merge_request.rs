@@ -1,7 +1,7 @@
 use crate::features::prelude::*;
 use crate::Project;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
 pub struct MergeRequest {
     /// Internal merge request id
     pub id: Id<Self>,
@@ -0,0 +1,5 @@
+pub use self::{find::*, notification_preference::*, set::*};
+
+mod find;
+mod notification_preference;
+mod set;
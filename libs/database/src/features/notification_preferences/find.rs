@@ -0,0 +1,44 @@
+use crate::features::prelude::*;
+use crate::{NotificationPreference, User};
+
+#[derive(Clone, Debug, Default)]
+pub struct FindNotificationPreferences {
+    /// Internal user id
+    pub user_id: Option<Id<User>>,
+}
+
+impl FindNotificationPreferences {
+    pub fn user_id(user_id: Id<User>) -> Self {
+        Self {
+            user_id: Some(user_id),
+        }
+    }
+}
+
+#[async_trait]
+impl Query for FindNotificationPreferences {
+    type Model = NotificationPreference;
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
+        tracing::debug!("Finding notification preferences");
+
+        let mut query = DynamicQuery::new("SELECT * FROM notification_preferences WHERE 1 = 1");
+
+        if let Some(user_id) = self.user_id {
+            query.push(" AND user_id = ?", user_id);
+        }
+
+        let (query, args) = query.build();
+
+        sqlx::query_as_with(&query, args)
+            .fetch_all(db.reader().await?.deref_mut())
+            .await
+            .with_context(|| {
+                format!(
+                    "Couldn't find notification preferences for query: {:?}",
+                    self
+                )
+            })
+    }
+}
@@ -0,0 +1,14 @@
+use crate::features::prelude::*;
+use crate::User;
+
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
+pub struct NotificationPreference {
+    /// Internal id of the user this preference belongs to
+    pub user_id: Id<User>,
+
+    /// Channel through which the user wants to get notified (e.g. `slack`)
+    pub channel: String,
+
+    /// When the preference was created
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,76 @@
+use crate::features::prelude::*;
+use crate::User;
+
+/// Sets channel through which given user wants to get notified, replacing the
+/// previous preference (if any)
+#[derive(Clone, Debug)]
+pub struct SetNotificationPreference {
+    /// Internal user id
+    pub user_id: Id<User>,
+
+    /// Channel through which the user wants to get notified (e.g. `slack`)
+    pub channel: String,
+}
+
+#[async_trait]
+impl Command for SetNotificationPreference {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Setting notification preference");
+
+        sqlx::query(
+            "
+            INSERT INTO notification_preferences (user_id, channel, created_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id)
+            DO UPDATE SET channel = excluded.channel
+            ",
+        )
+        .bind(self.user_id)
+        .bind(&self.channel)
+        .bind(Utc::now())
+        .execute(db.writer().await?.deref_mut())
+        .await
+        .with_context(|| format!("Couldn't set notification preference: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::FindNotificationPreferences;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let user_id = create_user(&db, 250).await;
+
+        assert!(db
+            .get_opt(FindNotificationPreferences::user_id(user_id))
+            .await
+            .unwrap()
+            .is_none());
+
+        for channel in &["slack", "matrix"] {
+            db.execute(SetNotificationPreference {
+                user_id,
+                channel: channel.to_string(),
+            })
+            .await
+            .unwrap();
+
+            let preference = db
+                .get_one(FindNotificationPreferences::user_id(user_id))
+                .await
+                .unwrap();
+
+            assert_eq!(user_id, preference.user_id);
+            assert_eq!(*channel, preference.channel);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub use self::{create::*, delete::*, find::*, nudge::*};
+
+mod create;
+mod delete;
+mod find;
+mod nudge;
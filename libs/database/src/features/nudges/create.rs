@@ -0,0 +1,79 @@
+use crate::features::prelude::*;
+use crate::MergeRequest;
+
+/// Records that given merge request has been nudged, so that we don't nudge
+/// it again
+#[derive(Clone, Debug)]
+pub struct CreateNudge {
+    /// Internal id of the merge request that got nudged
+    pub merge_request_id: Id<MergeRequest>,
+
+    /// `1` for the first nudge, `2` for the escalation
+    pub level: i64,
+
+    /// When the nudge was sent
+    pub nudged_at: DateTime<Utc>,
+}
+
+#[async_trait]
+impl Command for CreateNudge {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Creating nudge");
+
+        // Creating nudge is idempotent - i.e. creating the same nudge for the
+        // second time is a no-op
+        sqlx::query(
+            "
+            INSERT INTO nudges (merge_request_id, level, nudged_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (merge_request_id, level)
+            DO NOTHING
+            ",
+        )
+        .bind(self.merge_request_id)
+        .bind(self.level)
+        .bind(self.nudged_at)
+        .execute(db.writer().await?.deref_mut())
+        .await
+        .with_context(|| format!("Couldn't create nudge: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::FindNudges;
+    use chrono::TimeZone;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let project_id = create_project(&db, 10).await;
+        let merge_request_id = create_merge_request(&db, project_id, 100, 1).await;
+
+        for at in vec![100, 200] {
+            db.execute(CreateNudge {
+                merge_request_id,
+                level: 1,
+                nudged_at: Utc.timestamp(at, 0),
+            })
+            .await
+            .unwrap();
+        }
+
+        let nudges = db
+            .get_all(FindNudges::merge_request_id(merge_request_id))
+            .await
+            .unwrap();
+
+        assert_eq!(1, nudges.len());
+        assert_eq!(1, nudges[0].level);
+        assert_eq!(Utc.timestamp(100, 0), nudges[0].nudged_at);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::features::prelude::*;
+use crate::MergeRequest;
+
+/// Forgets all the nudges sent for given merge request (e.g. because someone
+/// has picked it up again)
+#[derive(Clone, Debug)]
+pub struct DeleteNudges {
+    /// Internal id of the merge request
+    pub merge_request_id: Id<MergeRequest>,
+}
+
+#[async_trait]
+impl Command for DeleteNudges {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Deleting nudges");
+
+        sqlx::query("DELETE FROM nudges WHERE merge_request_id = $1")
+            .bind(self.merge_request_id)
+            .execute(db.writer().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't delete nudges: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::{CreateNudge, FindNudges};
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let project_id = create_project(&db, 10).await;
+
+        let merge_requests = [
+            create_merge_request(&db, project_id, 100, 1).await,
+            create_merge_request(&db, project_id, 101, 2).await,
+        ];
+
+        for &merge_request_id in &merge_requests {
+            for level in 1..=2 {
+                db.execute(CreateNudge {
+                    merge_request_id,
+                    level,
+                    nudged_at: Utc::now(),
+                })
+                .await
+                .unwrap();
+            }
+        }
+
+        db.execute(DeleteNudges {
+            merge_request_id: merge_requests[0],
+        })
+        .await
+        .unwrap();
+
+        let count = |merge_request_id| {
+            let db = &db;
+
+            async move {
+                db.get_all(FindNudges::merge_request_id(merge_request_id))
+                    .await
+                    .unwrap()
+                    .len()
+            }
+        };
+
+        assert_eq!(0, count(merge_requests[0]).await);
+        assert_eq!(2, count(merge_requests[1]).await);
+    }
+}
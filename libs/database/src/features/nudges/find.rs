@@ -0,0 +1,41 @@
+use crate::features::prelude::*;
+use crate::{MergeRequest, Nudge};
+
+#[derive(Clone, Debug, Default)]
+pub struct FindNudges {
+    /// Internal id of the merge request that got nudged
+    pub merge_request_id: Option<Id<MergeRequest>>,
+}
+
+impl FindNudges {
+    pub fn merge_request_id(merge_request_id: Id<MergeRequest>) -> Self {
+        Self {
+            merge_request_id: Some(merge_request_id),
+        }
+    }
+}
+
+#[async_trait]
+impl Query for FindNudges {
+    type Model = Nudge;
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
+        tracing::debug!("Finding nudges");
+
+        let mut query = DynamicQuery::new("SELECT * FROM nudges WHERE 1 = 1");
+
+        if let Some(merge_request_id) = self.merge_request_id {
+            query.push(" AND merge_request_id = ?", merge_request_id);
+        }
+
+        query.push_sql(" ORDER BY level");
+
+        let (query, args) = query.build();
+
+        sqlx::query_as_with(&query, args)
+            .fetch_all(db.reader().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't find nudges for query: {:?}", self))
+    }
+}
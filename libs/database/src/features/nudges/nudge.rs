@@ -0,0 +1,14 @@
+use crate::features::prelude::*;
+use crate::MergeRequest;
+
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
+pub struct Nudge {
+    /// Internal id of the merge request that got nudged
+    pub merge_request_id: Id<MergeRequest>,
+
+    /// `1` for the first nudge, `2` for the escalation
+    pub level: i64,
+
+    /// When the nudge was sent
+    pub nudged_at: DateTime<Utc>,
+}
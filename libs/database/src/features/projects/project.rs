@@ -1,6 +1,6 @@
 use crate::features::prelude::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
 pub struct Project {
     /// Internal id
     pub id: Id<Self>,
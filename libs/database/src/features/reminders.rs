@@ -1,6 +1,21 @@
-pub use self::{create::*, delete::*, find::*, reminder::*};
+pub use self::{
+    count::*,
+    create::*,
+    delete::*,
+    delete_recipient::*,
+    find::*,
+    find_recipients::*,
+    postpone::*,
+    reminder::*,
+    reminder_recipient::*,
+};
 
+mod count;
 mod create;
 mod delete;
+mod delete_recipient;
 mod find;
+mod find_recipients;
+mod postpone;
 mod reminder;
+mod reminder_recipient;
@@ -0,0 +1,75 @@
+use crate::features::prelude::*;
+
+/// Counts reminders without loading them; returns exactly one row
+#[derive(Clone, Debug, Default)]
+pub struct CountReminders {
+    /// When set, counts reminders overdue by given date (i.e. the ones where
+    /// `reminders.remind_at <= $remind_at`)
+    pub overdue_by: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+impl Query for CountReminders {
+    type Model = i64;
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
+        tracing::debug!("Counting reminders");
+
+        let mut query = DynamicQuery::new("SELECT COUNT(*) FROM reminders WHERE 1 = 1");
+
+        if let Some(overdue_by) = self.overdue_by {
+            query.push(" AND remind_at <= ?", overdue_by);
+        }
+
+        let (query, args) = query.build();
+
+        sqlx::query_scalar_with(&query, args)
+            .fetch_all(db.reader().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't count reminders for query: {:?}", self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_merge_request, create_project, create_user};
+    use crate::CreateReminder;
+    use chrono::TimeZone;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let user_id = create_user(&db, 250).await;
+        let project_id = create_project(&db, 10).await;
+        let merge_request_id = create_merge_request(&db, project_id, 100, 1).await;
+
+        assert_eq!(0, db.get_one(CountReminders::default()).await.unwrap());
+
+        for remind_at in &[100, 200] {
+            db.execute(CreateReminder {
+                user_id,
+                recipients: vec![user_id],
+                merge_request_id,
+                ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                message: None,
+                remind_at: Some(Utc.timestamp(*remind_at, 0)),
+                trigger_event: None,
+                trigger_merge_request_id: None,
+                on_activity: None,
+                inactivity_secs: None,
+            })
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(2, db.get_one(CountReminders::default()).await.unwrap());
+
+        let overdue = CountReminders {
+            overdue_by: Some(Utc.timestamp(150, 0)),
+        };
+
+        assert_eq!(1, db.get_one(overdue).await.unwrap());
+    }
+}
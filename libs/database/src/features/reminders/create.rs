@@ -3,9 +3,12 @@ use crate::{MergeRequest, Reminder, User};
 
 #[derive(Clone, Debug)]
 pub struct CreateReminder {
-    /// Internal id of the user who should get reminded
+    /// Internal id of the user who created the reminder
     pub user_id: Id<User>,
 
+    /// Internal ids of the users who should get reminded
+    pub recipients: Vec<Id<User>>,
+
     /// Internal id of the merge request where we should create comment
     pub merge_request_id: Id<MergeRequest>,
 
@@ -15,8 +18,23 @@ pub struct CreateReminder {
     /// Message to remind; optional
     pub message: Option<String>,
 
-    /// When we should remind
-    pub remind_at: DateTime<Utc>,
+    /// When we should remind; optional if `trigger_event` is present
+    pub remind_at: Option<DateTime<Utc>>,
+
+    /// Event we should remind on (e.g. `pipeline.succeeded`); optional
+    pub trigger_event: Option<String>,
+
+    /// Internal id of the merge request `trigger_event` refers to; must be
+    /// present together with `trigger_event`
+    pub trigger_merge_request_id: Option<Id<MergeRequest>>,
+
+    /// What should happen when someone writes a note on the merge request:
+    /// `postpone` or `cancel`; optional
+    pub on_activity: Option<String>,
+
+    /// For `on_activity = postpone`: how long the merge request must remain
+    /// quiet for the reminder to fire
+    pub inactivity_secs: Option<i64>,
 }
 
 #[async_trait]
@@ -29,30 +47,54 @@ impl Command for CreateReminder {
 
         let id = Id::default();
 
-        sqlx::query(
-            "
-            INSERT INTO reminders (
-                id,
-                user_id,
-                merge_request_id,
-                ext_discussion_id,
-                message,
-                remind_at
+        db.transaction(|db| async move {
+            sqlx::query(
+                "
+                INSERT INTO reminders (
+                    id,
+                    user_id,
+                    merge_request_id,
+                    ext_discussion_id,
+                    message,
+                    remind_at,
+                    trigger_event,
+                    trigger_merge_request_id,
+                    on_activity,
+                    inactivity_secs,
+                    created_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                ",
             )
-            VALUES (?, ?, ?, ?, ?, ?)
-            ",
-        )
-        .bind(id)
-        .bind(self.user_id)
-        .bind(self.merge_request_id)
-        .bind(self.ext_discussion_id.as_ref())
-        .bind(self.message.as_ref())
-        .bind(self.remind_at)
-        .execute(db.lock().await.deref_mut())
+            .bind(id)
+            .bind(self.user_id)
+            .bind(self.merge_request_id)
+            .bind(self.ext_discussion_id.as_ref())
+            .bind(self.message.as_ref())
+            .bind(self.remind_at)
+            .bind(self.trigger_event.as_ref())
+            .bind(self.trigger_merge_request_id)
+            .bind(self.on_activity.as_ref())
+            .bind(self.inactivity_secs)
+            .bind(Utc::now())
+            .execute(db.writer().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't create reminder: {:?}", self))?;
+
+            for user_id in &self.recipients {
+                sqlx::query(
+                    "INSERT INTO reminder_recipients (reminder_id, user_id) VALUES ($1, $2)",
+                )
+                .bind(id)
+                .bind(*user_id)
+                .execute(db.writer().await?.deref_mut())
+                .await
+                .with_context(|| format!("Couldn't create reminder's recipient: {:?}", user_id))?;
+            }
+
+            Ok(id)
+        })
         .await
-        .with_context(|| format!("Couldn't create reminder: {:?}", self))?;
-
-        Ok(id)
     }
 }
 
@@ -60,7 +102,7 @@ impl Command for CreateReminder {
 mod tests {
     use super::*;
     use crate::test_utils::{create_merge_request, create_project, create_user};
-    use crate::FindReminders;
+    use crate::{FindReminderRecipients, FindReminders};
 
     async fn context() -> (Database, Id<User>, Id<MergeRequest>) {
         let db = Database::mock().await;
@@ -84,6 +126,23 @@ mod tests {
         );
         assert_eq!(command.message, reminder.message);
         assert_eq!(command.remind_at, reminder.remind_at);
+        assert_eq!(command.trigger_event, reminder.trigger_event);
+        assert_eq!(
+            command.trigger_merge_request_id,
+            reminder.trigger_merge_request_id
+        );
+        assert_eq!(command.on_activity, reminder.on_activity);
+        assert_eq!(command.inactivity_secs, reminder.inactivity_secs);
+
+        let recipients: Vec<_> = db
+            .get_all(FindReminderRecipients::reminder(id))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|recipient| recipient.user_id)
+            .collect();
+
+        assert_eq!(command.recipients, recipients);
     }
 
     #[tokio::test(threaded_scheduler)]
@@ -94,10 +153,15 @@ mod tests {
             &db,
             CreateReminder {
                 user_id,
+                recipients: vec![user_id],
                 merge_request_id,
                 ext_discussion_id: gl::DiscussionId::new("cafebabe"),
                 message: Some("Hello, World!".to_string()),
-                remind_at: Utc::now(),
+                remind_at: Some(Utc::now()),
+                trigger_event: None,
+                trigger_merge_request_id: None,
+                on_activity: None,
+                inactivity_secs: None,
             },
         )
         .await;
@@ -111,10 +175,58 @@ mod tests {
             &db,
             CreateReminder {
                 user_id,
+                recipients: vec![user_id],
+                merge_request_id,
+                ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                message: None,
+                remind_at: Some(Utc::now()),
+                trigger_event: None,
+                trigger_merge_request_id: None,
+                on_activity: None,
+                inactivity_secs: None,
+            },
+        )
+        .await;
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn with_trigger() {
+        let (db, user_id, merge_request_id) = context().await;
+
+        test(
+            &db,
+            CreateReminder {
+                user_id,
+                recipients: vec![user_id],
+                merge_request_id,
+                ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                message: None,
+                remind_at: None,
+                trigger_event: Some("pipeline.succeeded".to_string()),
+                trigger_merge_request_id: Some(merge_request_id),
+                on_activity: None,
+                inactivity_secs: None,
+            },
+        )
+        .await;
+    }
+    #[tokio::test(threaded_scheduler)]
+    async fn with_inactivity() {
+        let (db, user_id, merge_request_id) = context().await;
+
+        test(
+            &db,
+            CreateReminder {
+                user_id,
+                recipients: vec![user_id],
                 merge_request_id,
                 ext_discussion_id: gl::DiscussionId::new("cafebabe"),
                 message: None,
-                remind_at: Utc::now(),
+                remind_at: Some(Utc::now()),
+                trigger_event: None,
+                trigger_merge_request_id: None,
+                on_activity: Some("postpone".to_string()),
+                inactivity_secs: Some(2 * 24 * 3600),
             },
         )
         .await;
@@ -14,13 +14,22 @@ impl Command for DeleteReminder {
     async fn execute(self, db: &Database) -> Result<Self::Output> {
         tracing::debug!("Deleting reminder");
 
-        sqlx::query("DELETE FROM reminders WHERE id = ?")
-            .bind(self.id)
-            .execute(db.lock().await.deref_mut())
-            .await
-            .with_context(|| format!("Couldn't delete reminder: {:?}", self))?;
+        db.transaction(|db| async move {
+            sqlx::query("DELETE FROM reminder_recipients WHERE reminder_id = $1")
+                .bind(self.id)
+                .execute(db.writer().await?.deref_mut())
+                .await
+                .with_context(|| format!("Couldn't delete reminder's recipients: {:?}", self))?;
+
+            sqlx::query("DELETE FROM reminders WHERE id = $1")
+                .bind(self.id)
+                .execute(db.writer().await?.deref_mut())
+                .await
+                .with_context(|| format!("Couldn't delete reminder: {:?}", self))?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 }
 
@@ -52,10 +61,15 @@ mod tests {
             let id = db
                 .execute(CreateReminder {
                     user_id,
+                    recipients: vec![user_id],
                     merge_request_id,
                     ext_discussion_id: gl::DiscussionId::new(format!("cafebabe-{}", i)),
                     message: None,
-                    remind_at: Utc::now(),
+                    remind_at: Some(Utc::now()),
+                    trigger_event: None,
+                    trigger_merge_request_id: None,
+                    on_activity: None,
+                    inactivity_secs: None,
                 })
                 .await
                 .unwrap();
@@ -0,0 +1,85 @@
+use crate::features::prelude::*;
+use crate::{Reminder, User};
+
+/// Removes a single recipient from given reminder (e.g. after they've been
+/// notified), leaving the reminder itself intact
+#[derive(Clone, Debug)]
+pub struct DeleteReminderRecipient {
+    pub reminder_id: Id<Reminder>,
+    pub user_id: Id<User>,
+}
+
+#[async_trait]
+impl Command for DeleteReminderRecipient {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Deleting reminder's recipient");
+
+        sqlx::query("DELETE FROM reminder_recipients WHERE reminder_id = $1 AND user_id = $2")
+            .bind(self.reminder_id)
+            .bind(self.user_id)
+            .execute(db.writer().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't delete reminder's recipient: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_merge_request, create_project, create_user};
+    use crate::{CreateReminder, FindReminderRecipients, FindReminders};
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let users = [create_user(&db, 250).await, create_user(&db, 251).await];
+        let project_id = create_project(&db, 10).await;
+        let merge_request_id = create_merge_request(&db, project_id, 100, 1).await;
+
+        let reminder_id = db
+            .execute(CreateReminder {
+                user_id: users[0],
+                recipients: users.to_vec(),
+                merge_request_id,
+                ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                message: None,
+                remind_at: Some(Utc::now()),
+                trigger_event: None,
+                trigger_merge_request_id: None,
+                on_activity: None,
+                inactivity_secs: None,
+            })
+            .await
+            .unwrap();
+
+        db.execute(DeleteReminderRecipient {
+            reminder_id,
+            user_id: users[0],
+        })
+        .await
+        .unwrap();
+
+        let recipients: Vec<_> = db
+            .get_all(FindReminderRecipients::reminder(reminder_id))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|recipient| recipient.user_id)
+            .collect();
+
+        assert_eq!(vec![users[1]], recipients);
+
+        assert!(db
+            .get_one(FindReminders {
+                id: Some(reminder_id),
+                ..Default::default()
+            })
+            .await
+            .is_ok());
+    }
+}
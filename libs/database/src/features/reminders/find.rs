@@ -1,14 +1,29 @@
 use crate::features::prelude::*;
-use crate::Reminder;
+use crate::{MergeRequest, Reminder, User};
 
 #[derive(Clone, Debug, Default)]
 pub struct FindReminders {
     /// When set, returns reminder with specified id
     pub id: Option<Id<Reminder>>,
 
+    /// When set, returns reminders created on given merge request
+    pub merge_request_id: Option<Id<MergeRequest>>,
+
+    /// When set, returns reminders addressed to given user (see:
+    /// `FindReminderRecipients`)
+    pub recipient_id: Option<Id<User>>,
+
     /// When set, returns reminders overdue by given date (i.e. the ones where
     /// `reminders.remind_at <= $remind_at`)
     pub overdue_by: Option<DateTime<Utc>>,
+
+    /// When set, returns reminders waiting for given event (e.g.
+    /// `pipeline.succeeded`)
+    pub trigger_event: Option<String>,
+
+    /// When set, returns reminders waiting for an event on given merge
+    /// request
+    pub trigger_merge_request_id: Option<Id<MergeRequest>>,
 }
 
 impl FindReminders {
@@ -19,12 +34,34 @@ impl FindReminders {
         }
     }
 
+    pub fn merge_request_id(merge_request_id: Id<MergeRequest>) -> Self {
+        Self {
+            merge_request_id: Some(merge_request_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn recipient_id(recipient_id: Id<User>) -> Self {
+        Self {
+            recipient_id: Some(recipient_id),
+            ..Default::default()
+        }
+    }
+
     pub fn overdue_by(overdue_by: DateTime<Utc>) -> Self {
         Self {
             overdue_by: Some(overdue_by),
             ..Default::default()
         }
     }
+
+    pub fn triggered_by(event: impl Into<String>, merge_request_id: Id<MergeRequest>) -> Self {
+        Self {
+            trigger_event: Some(event.into()),
+            trigger_merge_request_id: Some(merge_request_id),
+            ..Default::default()
+        }
+    }
 }
 
 #[async_trait]
@@ -35,21 +72,42 @@ impl Query for FindReminders {
     async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
         tracing::debug!("Finding reminders");
 
-        let mut query = String::from("SELECT * FROM reminders WHERE 1 = 1");
-        let mut args = SqliteArguments::default();
+        let mut query = DynamicQuery::new("SELECT * FROM reminders WHERE 1 = 1");
 
         if let Some(id) = self.id {
-            query += " AND id = ?";
-            args.add(id);
+            query.push(" AND id = ?", id);
+        }
+
+        if let Some(merge_request_id) = self.merge_request_id {
+            query.push(" AND merge_request_id = ?", merge_request_id);
+        }
+
+        if let Some(recipient_id) = self.recipient_id {
+            query.push(
+                " AND id IN (SELECT reminder_id FROM reminder_recipients WHERE user_id = ?)",
+                recipient_id,
+            );
         }
 
         if let Some(overdue_by) = self.overdue_by {
-            query += " AND remind_at <= ?";
-            args.add(overdue_by);
+            query.push(" AND remind_at <= ?", overdue_by);
         }
 
+        if let Some(trigger_event) = &self.trigger_event {
+            query.push(" AND trigger_event = ?", trigger_event.clone());
+        }
+
+        if let Some(trigger_merge_request_id) = self.trigger_merge_request_id {
+            query.push(
+                " AND trigger_merge_request_id = ?",
+                trigger_merge_request_id,
+            );
+        }
+
+        let (query, args) = query.build();
+
         sqlx::query_as_with(&query, args)
-            .fetch_all(db.lock().await.deref_mut())
+            .fetch_all(db.reader().await?.deref_mut())
             .await
             .with_context(|| format!("Couldn't find reminders for query: {:?}", self))
     }
@@ -65,6 +123,8 @@ mod tests {
 
     struct TestContext {
         db: Database,
+        merge_requests: [Id<MergeRequest>; 2],
+        users: [Id<User>; 2],
         reminders: [Id<Reminder>; 2],
     }
 
@@ -84,10 +144,15 @@ mod tests {
             let reminder_1 = db
                 .execute(CreateReminder {
                     user_id: users[0],
+                    recipients: vec![users[0]],
                     merge_request_id: merge_requests[0],
                     ext_discussion_id: gl::DiscussionId::new("cafebabe"),
                     message: None,
-                    remind_at: Utc.timestamp(100, 0),
+                    remind_at: Some(Utc.timestamp(100, 0)),
+                    trigger_event: None,
+                    trigger_merge_request_id: None,
+                    on_activity: None,
+                    inactivity_secs: None,
                 })
                 .await
                 .unwrap();
@@ -95,16 +160,23 @@ mod tests {
             let reminder_2 = db
                 .execute(CreateReminder {
                     user_id: users[1],
+                    recipients: vec![users[1]],
                     merge_request_id: merge_requests[1],
                     ext_discussion_id: gl::DiscussionId::new("cafebabe"),
                     message: Some("oh hi, mark!".to_string()),
-                    remind_at: Utc.timestamp(200, 0),
+                    remind_at: Some(Utc.timestamp(200, 0)),
+                    trigger_event: None,
+                    trigger_merge_request_id: None,
+                    on_activity: None,
+                    inactivity_secs: None,
                 })
                 .await
                 .unwrap();
 
             Self {
                 db,
+                merge_requests,
+                users,
                 reminders: [reminder_1, reminder_2],
             }
         }
@@ -173,6 +245,52 @@ mod tests {
         }
     }
 
+    mod given_filter_with_merge_request_id {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_reminders_created_on_given_merge_request() {
+            let ctxt = TestContext::new().await;
+
+            let cases = vec![
+                (ctxt.merge_requests[0], vec![ctxt.reminders[0]]),
+                (ctxt.merge_requests[1], vec![ctxt.reminders[1]]),
+            ];
+
+            for (case_idx, (merge_request_id, expected)) in cases.into_iter().enumerate() {
+                let query = FindReminders::merge_request_id(merge_request_id);
+
+                ctxt.assert_query_returns(query, &expected)
+                    .await
+                    .with_context(|| format!("Test case #{} failed", case_idx))
+                    .unwrap();
+            }
+        }
+    }
+
+    mod given_filter_with_recipient_id {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_reminders_addressed_to_given_user() {
+            let ctxt = TestContext::new().await;
+
+            let cases = vec![
+                (ctxt.users[0], vec![ctxt.reminders[0]]),
+                (ctxt.users[1], vec![ctxt.reminders[1]]),
+            ];
+
+            for (case_idx, (recipient_id, expected)) in cases.into_iter().enumerate() {
+                let query = FindReminders::recipient_id(recipient_id);
+
+                ctxt.assert_query_returns(query, &expected)
+                    .await
+                    .with_context(|| format!("Test case #{} failed", case_idx))
+                    .unwrap();
+            }
+        }
+    }
+
     mod given_filter_with_overdue_by {
         use super::*;
 
@@ -206,4 +324,79 @@ mod tests {
             }
         }
     }
+    mod given_filter_with_trigger {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_reminders_waiting_for_given_event() {
+            let db = Database::mock().await;
+            let user_id = create_user(&db, 1).await;
+            let project_id = create_project(&db, 1).await;
+
+            let merge_requests = [
+                create_merge_request(&db, project_id, 1, 1).await,
+                create_merge_request(&db, project_id, 2, 2).await,
+            ];
+
+            let mut reminders = Vec::new();
+
+            for (event, merge_request_id) in vec![
+                ("pipeline.succeeded", merge_requests[0]),
+                ("pipeline.failed", merge_requests[0]),
+                ("merge_request.merged", merge_requests[1]),
+            ] {
+                let id = db
+                    .execute(CreateReminder {
+                        user_id,
+                        recipients: vec![user_id],
+                        merge_request_id: merge_requests[0],
+                        ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                        message: None,
+                        remind_at: None,
+                        trigger_event: Some(event.to_string()),
+                        trigger_merge_request_id: Some(merge_request_id),
+                        on_activity: None,
+                        inactivity_secs: None,
+                    })
+                    .await
+                    .unwrap();
+
+                reminders.push(id);
+            }
+
+            let cases = vec![
+                (
+                    ("pipeline.succeeded", merge_requests[0]),
+                    vec![reminders[0]],
+                ),
+                (("pipeline.failed", merge_requests[0]), vec![reminders[1]]),
+                (("pipeline.failed", merge_requests[1]), vec![]),
+                (
+                    ("merge_request.merged", merge_requests[1]),
+                    vec![reminders[2]],
+                ),
+            ];
+
+            for (case_idx, ((event, merge_request_id), expected)) in cases.into_iter().enumerate() {
+                let actual: BTreeSet<_> = db
+                    .get_all(FindReminders::triggered_by(event, merge_request_id))
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|reminder| reminder.id)
+                    .collect();
+
+                let expected: BTreeSet<_> = expected.into_iter().collect();
+
+                assert_eq!(expected, actual, "Test case #{} failed", case_idx);
+            }
+
+            // Reminders waiting just for an event are never overdue
+            assert!(db
+                .get_all(FindReminders::overdue_by(Utc::now()))
+                .await
+                .unwrap()
+                .is_empty());
+        }
+    }
 }
@@ -0,0 +1,125 @@
+use crate::features::prelude::*;
+use crate::{Reminder, ReminderRecipient, User};
+
+#[derive(Clone, Debug, Default)]
+pub struct FindReminderRecipients {
+    /// When set, returns recipients of specified reminder
+    pub reminder_id: Option<Id<Reminder>>,
+
+    /// When set, returns reminders addressed to specified user
+    pub user_id: Option<Id<User>>,
+}
+
+impl FindReminderRecipients {
+    pub fn reminder(reminder_id: Id<Reminder>) -> Self {
+        Self {
+            reminder_id: Some(reminder_id),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl Query for FindReminderRecipients {
+    type Model = ReminderRecipient;
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
+        tracing::debug!("Finding reminder recipients");
+
+        let mut query = DynamicQuery::new("SELECT * FROM reminder_recipients WHERE 1 = 1");
+
+        if let Some(reminder_id) = self.reminder_id {
+            query.push(" AND reminder_id = ?", reminder_id);
+        }
+
+        if let Some(user_id) = self.user_id {
+            query.push(" AND user_id = ?", user_id);
+        }
+
+        let (query, args) = query.build();
+
+        sqlx::query_as_with(&query, args)
+            .fetch_all(db.reader().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't find reminder recipients for query: {:?}", self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_merge_request, create_project, create_user};
+    use crate::CreateReminder;
+    use std::collections::BTreeSet;
+
+    async fn find(
+        db: &Database,
+        query: FindReminderRecipients,
+    ) -> BTreeSet<(Id<Reminder>, Id<User>)> {
+        db.get_all(query)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|recipient| (recipient.reminder_id, recipient.user_id))
+            .collect()
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let users = [create_user(&db, 250).await, create_user(&db, 251).await];
+        let project_id = create_project(&db, 10).await;
+        let merge_request_id = create_merge_request(&db, project_id, 100, 1).await;
+
+        let mut reminders = Vec::new();
+
+        for recipients in vec![vec![users[0]], vec![users[0], users[1]]] {
+            let id = db
+                .execute(CreateReminder {
+                    user_id: users[0],
+                    recipients,
+                    merge_request_id,
+                    ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                    message: None,
+                    remind_at: Some(Utc::now()),
+                    trigger_event: None,
+                    trigger_merge_request_id: None,
+                    on_activity: None,
+                    inactivity_secs: None,
+                })
+                .await
+                .unwrap();
+
+            reminders.push(id);
+        }
+
+        assert_eq!(
+            vec![(reminders[0], users[0])]
+                .into_iter()
+                .collect::<BTreeSet<_>>(),
+            find(&db, FindReminderRecipients::reminder(reminders[0])).await,
+        );
+
+        assert_eq!(
+            vec![(reminders[1], users[0]), (reminders[1], users[1])]
+                .into_iter()
+                .collect::<BTreeSet<_>>(),
+            find(&db, FindReminderRecipients::reminder(reminders[1])).await,
+        );
+
+        assert_eq!(
+            vec![(reminders[1], users[1])]
+                .into_iter()
+                .collect::<BTreeSet<_>>(),
+            find(
+                &db,
+                FindReminderRecipients {
+                    user_id: Some(users[1]),
+                    ..Default::default()
+                }
+            )
+            .await,
+        );
+    }
+}
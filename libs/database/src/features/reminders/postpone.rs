@@ -0,0 +1,72 @@
+use crate::features::prelude::*;
+use crate::Reminder;
+
+/// Moves reminder to given time (e.g. because someone has just written a note
+/// on a merge request that was supposed to remain quiet)
+#[derive(Clone, Debug)]
+pub struct PostponeReminder {
+    pub id: Id<Reminder>,
+    pub remind_at: DateTime<Utc>,
+}
+
+#[async_trait]
+impl Command for PostponeReminder {
+    type Output = ();
+
+    #[tracing::instrument(skip(db))]
+    async fn execute(self, db: &Database) -> Result<Self::Output> {
+        tracing::debug!("Postponing reminder");
+
+        sqlx::query("UPDATE reminders SET remind_at = $1 WHERE id = $2")
+            .bind(self.remind_at)
+            .bind(self.id)
+            .execute(db.writer().await?.deref_mut())
+            .await
+            .with_context(|| format!("Couldn't postpone reminder: {:?}", self))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_merge_request, create_project, create_user};
+    use crate::{CreateReminder, FindReminders};
+    use chrono::TimeZone;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn test() {
+        let db = Database::mock().await;
+        let user_id = create_user(&db, 250).await;
+        let project_id = create_project(&db, 10).await;
+        let merge_request_id = create_merge_request(&db, project_id, 100, 1).await;
+
+        let id = db
+            .execute(CreateReminder {
+                user_id,
+                recipients: vec![user_id],
+                merge_request_id,
+                ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                message: None,
+                remind_at: Some(Utc.timestamp(100, 0)),
+                trigger_event: None,
+                trigger_merge_request_id: None,
+                on_activity: Some("postpone".to_string()),
+                inactivity_secs: Some(100),
+            })
+            .await
+            .unwrap();
+
+        db.execute(PostponeReminder {
+            id,
+            remind_at: Utc.timestamp(200, 0),
+        })
+        .await
+        .unwrap();
+
+        let reminder = db.get_one(FindReminders::id(id)).await.unwrap();
+
+        assert_eq!(Some(Utc.timestamp(200, 0)), reminder.remind_at);
+    }
+}
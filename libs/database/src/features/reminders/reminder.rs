@@ -1,12 +1,13 @@
 use crate::features::prelude::*;
 use crate::{MergeRequest, User};
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
 pub struct Reminder {
     /// Internal reminder id
     pub id: Id<Self>,
 
-    /// Internal id of the user who should get reminded
+    /// Internal id of the user who created the reminder (see
+    /// `FindReminderRecipients` for the ones who should get reminded)
     pub user_id: Id<User>,
 
     /// Internal id of the merge request where we should create comment
@@ -18,8 +19,25 @@ pub struct Reminder {
     /// Message to remind; optional
     pub message: Option<String>,
 
-    /// When we should remind
-    pub remind_at: DateTime<Utc>,
+    /// When we should remind; `None` for reminders that wait just for an
+    /// event (see: `trigger_event`)
+    pub remind_at: Option<DateTime<Utc>>,
+
+    /// Event we should remind on (e.g. `pipeline.succeeded`); optional
+    pub trigger_event: Option<String>,
+
+    /// Internal id of the merge request `trigger_event` refers to; present
+    /// together with `trigger_event`
+    pub trigger_merge_request_id: Option<Id<MergeRequest>>,
+
+    /// What should happen when someone writes a note on the reminder's merge
+    /// request: `postpone` (by `inactivity_secs`) or `cancel` (only for notes
+    /// in the same discussion); optional
+    pub on_activity: Option<String>,
+
+    /// For `on_activity = postpone`: how long the merge request must remain
+    /// quiet for the reminder to fire
+    pub inactivity_secs: Option<i64>,
 
     /// When the reminder was created
     pub created_at: DateTime<Utc>,
@@ -0,0 +1,11 @@
+use crate::features::prelude::*;
+use crate::{Reminder, User};
+
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
+pub struct ReminderRecipient {
+    /// Internal id of the reminder
+    pub reminder_id: Id<Reminder>,
+
+    /// Internal id of the user who should get reminded
+    pub user_id: Id<User>,
+}
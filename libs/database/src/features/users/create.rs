@@ -23,10 +23,11 @@ impl Command for CreateUser {
 
         let id = Id::default();
 
-        sqlx::query("INSERT INTO users (id, ext_id) VALUES (?, ?)")
+        sqlx::query("INSERT INTO users (id, ext_id, created_at) VALUES ($1, $2, $3)")
             .bind(id)
             .bind(self.ext_id.inner() as i64)
-            .execute(db.lock().await.deref_mut())
+            .bind(Utc::now())
+            .execute(db.writer().await?.deref_mut())
             .await
             .with_context(|| format!("Couldn't create user: {:?}", self))?;
 
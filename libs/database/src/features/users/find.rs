@@ -34,21 +34,20 @@ impl Query for FindUsers {
     async fn execute(self, db: &Database) -> Result<Vec<Self::Model>> {
         tracing::debug!("Finding users");
 
-        let mut query = String::from("SELECT * FROM users WHERE 1 = 1");
-        let mut args = SqliteArguments::default();
+        let mut query = DynamicQuery::new("SELECT * FROM users WHERE 1 = 1");
 
         if let Some(id) = self.id {
-            query += " AND id = ?";
-            args.add(id);
+            query.push(" AND id = ?", id);
         }
 
         if let Some(ext_id) = self.ext_id {
-            query += " AND ext_id = ?";
-            args.add(ext_id.inner() as i64);
+            query.push(" AND ext_id = ?", ext_id.inner() as i64);
         }
 
+        let (query, args) = query.build();
+
         sqlx::query_as_with(&query, args)
-            .fetch_all(db.lock().await.deref_mut())
+            .fetch_all(db.reader().await?.deref_mut())
             .await
             .with_context(|| format!("Couldn't find users for query: {:?}", self))
     }
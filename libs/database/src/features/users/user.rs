@@ -1,6 +1,6 @@
 use crate::features::prelude::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, Deserialize, FromRow, Serialize)]
 pub struct User {
     /// Internal user id
     pub id: Id<Self>,
@@ -1,7 +1,8 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sqlx::any::{Any, AnyRow, AnyTypeInfo};
 use sqlx::database::{HasArguments, HasValueRef};
 use sqlx::encode::IsNull;
 use sqlx::error::BoxDynError;
-use sqlx::sqlite::{Sqlite, SqliteRow};
 use sqlx::{Database, Error, Row};
 use std::cmp::Ordering;
 use std::fmt;
@@ -70,32 +71,79 @@ impl<T> fmt::Display for Id<T> {
     }
 }
 
-impl<T> sqlx::Type<Sqlite> for Id<T> {
-    fn type_info() -> <Sqlite as Database>::TypeInfo {
-        String::type_info()
+impl<T> FromStr for Id<T> {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            id: Uuid::from_str(s)?,
+            _model: Default::default(),
+        })
     }
 }
 
-impl<'q, T> sqlx::Encode<'q, Sqlite> for Id<T> {
-    fn encode_by_ref(&self, buf: &mut <Sqlite as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
-        self.id.to_string().encode_by_ref(buf)
+impl<T> Serialize for Id<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.id.serialize(serializer)
     }
 }
 
-impl<'r, T> sqlx::Decode<'r, Sqlite> for Id<T> {
-    fn decode(value: <Sqlite as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
-        let id = String::decode(value)?;
-        let id = Uuid::from_str(&id)?;
-
+impl<'de, T> Deserialize<'de> for Id<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         Ok(Self {
-            id,
+            id: Uuid::deserialize(deserializer)?,
             _model: Default::default(),
         })
     }
 }
 
-impl<'r, T> sqlx::FromRow<'r, SqliteRow> for Id<T> {
-    fn from_row(row: &'r SqliteRow) -> Result<Self, Error> {
+impl<T> sqlx::Type<Any> for Id<T> {
+    fn type_info() -> <Any as Database>::TypeInfo {
+        <String as sqlx::Type<Any>>::type_info()
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        <String as sqlx::Type<Any>>::compatible(ty)
+    }
+}
+
+impl<'q, T> sqlx::Encode<'q, Any> for Id<T> {
+    fn encode_by_ref(&self, buf: &mut <Any as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        <String as sqlx::Encode<Any>>::encode_by_ref(&self.id.to_string(), buf)
+    }
+}
+
+impl<'r, T> sqlx::Decode<'r, Any> for Id<T> {
+    fn decode(value: <Any as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        Ok(<String as sqlx::Decode<Any>>::decode(value)?.parse()?)
+    }
+}
+
+impl<'r, T> sqlx::FromRow<'r, AnyRow> for Id<T> {
+    fn from_row(row: &'r AnyRow) -> Result<Self, Error> {
         Ok(row.try_get(0)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Model;
+
+    #[test]
+    fn can_be_parsed_back_from_string() {
+        let id = Id::<Model>::default();
+
+        assert_eq!(id, id.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn can_be_serialized_and_deserialized() {
+        let id = Id::<Model>::default();
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(format!("\"{}\"", id), json);
+        assert_eq!(id, serde_json::from_str(&json).unwrap());
+    }
+}
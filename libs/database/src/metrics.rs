@@ -0,0 +1,41 @@
+use once_cell::sync::Lazy;
+use prometheus::{register_histogram_vec, HistogramTimer, HistogramVec};
+use std::any::type_name;
+
+static STATEMENT_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "janet_database_statement_duration_seconds",
+        "Latency of database commands & queries, by kind and name",
+        &["kind", "name"],
+        vec![0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0]
+    )
+    .unwrap()
+});
+
+/// Starts measuring a command / query; the measurement is recorded when the
+/// returned timer gets dropped.
+crate fn start_timer<T>(kind: &str) -> HistogramTimer {
+    STATEMENT_DURATION
+        .with_label_values(&[kind, name_of::<T>()])
+        .start_timer()
+}
+
+/// Returns the name of given command / query, without its module path - e.g.
+/// `lib_database::features::users::FindUsers` becomes `FindUsers`.
+fn name_of<T>() -> &'static str {
+    let name = type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FindUsers;
+
+    #[test]
+    fn name_of_strips_module_path() {
+        assert_eq!("FindUsers", name_of::<FindUsers>());
+    }
+}
@@ -1,66 +1,403 @@
+use crate::Backend;
 use anyhow::*;
-use sqlx::SqliteConnection;
+use sqlx::any::AnyConnection;
+use sqlx::{Connection, Executor};
 
-const MIGRATIONS: &[&str] = &[
-    include_str!("migrations/01.sql"),
-    include_str!("migrations/02.sql"),
-    include_str!("migrations/03.sql"),
+struct Migration {
+    up: &'static str,
+    down: &'static str,
+}
+
+macro_rules! migration {
+    ($backend:literal, $id:literal) => {
+        Migration {
+            up: include_str!(concat!("migrations/", $backend, "/", $id, ".up.sql")),
+            down: include_str!(concat!("migrations/", $backend, "/", $id, ".down.sql")),
+        }
+    };
+}
+
+const SQLITE_MIGRATIONS: &[Migration] = &[
+    migration!("sqlite", "01"),
+    migration!("sqlite", "02"),
+    migration!("sqlite", "03"),
+    migration!("sqlite", "04"),
+    migration!("sqlite", "05"),
+    migration!("sqlite", "06"),
+    migration!("sqlite", "07"),
+    migration!("sqlite", "08"),
+    migration!("sqlite", "09"),
+    migration!("sqlite", "10"),
+    migration!("sqlite", "11"),
+    migration!("sqlite", "12"),
+    migration!("sqlite", "13"),
+    migration!("sqlite", "14"),
 ];
 
-pub async fn run(conn: &mut SqliteConnection) -> Result<()> {
-    boot(conn).await?;
+#[cfg(feature = "postgres")]
+const POSTGRES_MIGRATIONS: &[Migration] = &[
+    migration!("postgres", "01"),
+    migration!("postgres", "02"),
+    migration!("postgres", "03"),
+    migration!("postgres", "04"),
+    migration!("postgres", "05"),
+    migration!("postgres", "06"),
+    migration!("postgres", "07"),
+    migration!("postgres", "08"),
+    migration!("postgres", "09"),
+    migration!("postgres", "10"),
+    migration!("postgres", "11"),
+    migration!("postgres", "12"),
+    migration!("postgres", "13"),
+    migration!("postgres", "14"),
+];
+
+/// Version the database ends up at after all the migrations are applied
+pub const LATEST_VERSION: usize = SQLITE_MIGRATIONS.len();
+
+fn migrations(backend: Backend) -> &'static [Migration] {
+    match backend {
+        Backend::Sqlite => SQLITE_MIGRATIONS,
+
+        #[cfg(feature = "postgres")]
+        Backend::Postgres => POSTGRES_MIGRATIONS,
+    }
+}
+
+/// Migrates database up to given version
+pub async fn run(backend: Backend, conn: &mut AnyConnection, target_version: usize) -> Result<()> {
+    ensure_known(target_version)?;
+    boot(backend, conn).await?;
 
     tracing::debug!("Checking database's version");
 
-    if let Some(version) = current_version(conn).await? {
-        tracing::debug!("... {}", version);
-        migrate(conn, version).await?;
-    } else {
-        tracing::debug!("... none; this is a fresh start");
-        migrate(conn, 0).await?;
+    let version = current_version(backend, conn).await?.unwrap_or(0);
+
+    tracing::debug!("... {}", version);
+
+    ensure_supported(version)?;
+
+    if version > target_version {
+        bail!(
+            "Database is at version {}, which is newer than {} - use `janet migrate down` to roll it back",
+            version,
+            target_version
+        );
+    }
+
+    for version in version..target_version {
+        apply(backend, conn, version, version + 1).await?;
     }
 
     Ok(())
 }
 
-async fn boot(conn: &mut SqliteConnection) -> Result<()> {
-    sqlx::query(include_str!("migrations/00-boot.sql"))
-        .execute(&mut *conn)
-        .await?;
+/// Rolls database back to given version
+pub async fn rollback(
+    backend: Backend,
+    conn: &mut AnyConnection,
+    target_version: usize,
+) -> Result<()> {
+    ensure_known(target_version)?;
+    boot(backend, conn).await?;
+
+    tracing::debug!("Checking database's version");
+
+    let version = current_version(backend, conn).await?.unwrap_or(0);
+
+    tracing::debug!("... {}", version);
+
+    ensure_supported(version)?;
+
+    if version < target_version {
+        bail!(
+            "Database is at version {}, which is older than {} - use `janet migrate up` to migrate it",
+            version,
+            target_version
+        );
+    }
+
+    for version in (target_version..version).rev() {
+        apply(backend, conn, version + 1, version).await?;
+    }
 
     Ok(())
 }
 
-async fn current_version(conn: &mut SqliteConnection) -> Result<Option<usize>> {
-    let version = sqlx::query_as::<_, (Option<i64>,)>("SELECT max(id) FROM migrations")
-        .fetch_one(conn)
+/// Returns database's current version, or `None` if the database hasn't been
+/// migrated yet.
+///
+/// Doesn't modify the database, so it's safe to call e.g. during dry runs.
+pub async fn current_version(backend: Backend, conn: &mut AnyConnection) -> Result<Option<usize>> {
+    let booted = match backend {
+        Backend::Sqlite => {
+            "SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name = 'migrations'"
+        }
+
+        #[cfg(feature = "postgres")]
+        Backend::Postgres => {
+            "SELECT count(*) FROM information_schema.tables WHERE table_schema = current_schema() AND table_name = 'migrations'"
+        }
+    };
+
+    let booted = sqlx::query_as::<_, (i64,)>(booted)
+        .fetch_one(&mut *conn)
         .await?
         .0
-        .map(|version| version as usize);
+        > 0;
+
+    if !booted {
+        return Ok(None);
+    }
+
+    let version =
+        sqlx::query_as::<_, (Option<i64>,)>("SELECT CAST(max(id) AS BIGINT) FROM migrations")
+            .fetch_one(conn)
+            .await?
+            .0
+            .map(|version| version as usize);
 
     Ok(version)
 }
 
-async fn migrate(conn: &mut SqliteConnection, start_from_migration: usize) -> Result<()> {
-    for (migration_id, migration) in MIGRATIONS.iter().enumerate().skip(start_from_migration) {
-        let migration_id = migration_id + 1;
+async fn boot(backend: Backend, conn: &mut AnyConnection) -> Result<()> {
+    let boot = match backend {
+        Backend::Sqlite => include_str!("migrations/sqlite/00-boot.sql"),
+
+        #[cfg(feature = "postgres")]
+        Backend::Postgres => include_str!("migrations/postgres/00-boot.sql"),
+    };
 
-        tracing::info!(
-            "Migrating database from version {} to {}",
-            migration_id - 1,
-            migration_id,
+    conn.execute(boot).await?;
+
+    Ok(())
+}
+
+fn ensure_known(version: usize) -> Result<()> {
+    if version > LATEST_VERSION {
+        bail!(
+            "Unknown version: {} (the latest one is {})",
+            version,
+            LATEST_VERSION
         );
+    }
 
-        sqlx::query(migration)
-            .execute(&mut *conn)
+    Ok(())
+}
+
+/// Ensures we know how to handle database at given version - otherwise, if
+/// someone downgraded Janet, we'd end up working on a schema we know nothing
+/// about.
+fn ensure_supported(version: usize) -> Result<()> {
+    if version > LATEST_VERSION {
+        bail!(
+            "Database is at version {}, but this build of Janet supports only versions up to {} - please upgrade Janet",
+            version,
+            LATEST_VERSION
+        );
+    }
+
+    Ok(())
+}
+
+/// Migrates database between two adjacent versions (either up or down).
+///
+/// Both the migration and the `migrations` table are modified inside a single
+/// transaction, so a failed migration leaves no trace.
+async fn apply(backend: Backend, conn: &mut AnyConnection, from: usize, to: usize) -> Result<()> {
+    tracing::info!("Migrating database from version {} to {}", from, to);
+
+    let migrations = migrations(backend);
+    let mut tx = conn.begin().await?;
+
+    if to > from {
+        // Migrations consist of many statements, which Postgres doesn't
+        // support inside prepared statements - so instead of going through
+        // `sqlx::query()`, we have to execute them directly
+        tx.execute(migrations[from].up)
+            .await
+            .with_context(|| format!("Couldn't execute migration {}", to))?;
+
+        sqlx::query("INSERT INTO migrations (id) VALUES ($1)")
+            .bind(to as i64)
+            .execute(&mut tx)
+            .await?;
+    } else {
+        tx.execute(migrations[to].down)
             .await
-            .with_context(|| format!("Couldn't execute migration {}", migration_id))?;
+            .with_context(|| format!("Couldn't revert migration {}", from))?;
 
-        sqlx::query("INSERT INTO migrations (id) VALUES (?)")
-            .bind(migration_id as i64)
-            .execute(&mut *conn)
+        sqlx::query("DELETE FROM migrations WHERE id = $1")
+            .bind(from as i64)
+            .execute(&mut tx)
             .await?;
     }
 
+    tx.commit().await?;
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::any::AnyConnectOptions;
+    use sqlx::sqlite::SqliteConnectOptions;
+    use sqlx::ConnectOptions;
+
+    async fn connect() -> AnyConnection {
+        AnyConnectOptions::from(
+            SqliteConnectOptions::new()
+                .filename(":memory:")
+                .foreign_keys(true)
+                .statement_cache_capacity(0),
+        )
+        .connect()
+        .await
+        .unwrap()
+    }
+
+    async fn schema(conn: &mut AnyConnection) -> Vec<(String, String, Option<String>)> {
+        sqlx::query_as(
+            "SELECT type, name, sql FROM sqlite_master WHERE tbl_name != 'migrations' ORDER BY name",
+        )
+        .fetch_all(conn)
+        .await
+        .unwrap()
+    }
+
+    async fn version_of(conn: &mut AnyConnection) -> usize {
+        current_version(Backend::Sqlite, conn)
+            .await
+            .unwrap()
+            .unwrap_or(0)
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn every_migration_can_be_reverted() {
+        let mut conn = connect().await;
+
+        for version in 1..=LATEST_VERSION {
+            let schema_before = schema(&mut conn).await;
+
+            run(Backend::Sqlite, &mut conn, version).await.unwrap();
+            assert_eq!(version, version_of(&mut conn).await);
+
+            let schema_after = schema(&mut conn).await;
+
+            rollback(Backend::Sqlite, &mut conn, version - 1)
+                .await
+                .unwrap();
+            assert_eq!(version - 1, version_of(&mut conn).await);
+            assert_eq!(schema_before, schema(&mut conn).await);
+
+            run(Backend::Sqlite, &mut conn, version).await.unwrap();
+            assert_eq!(version, version_of(&mut conn).await);
+            assert_eq!(schema_after, schema(&mut conn).await);
+        }
+
+        rollback(Backend::Sqlite, &mut conn, 0).await.unwrap();
+        assert_eq!(0, version_of(&mut conn).await);
+        assert!(schema(&mut conn).await.is_empty());
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn failed_migration_leaves_no_trace() {
+        let mut conn = connect().await;
+
+        // Migration #1 creates `reminders` as its last table, so this makes it
+        // fail halfway through
+        sqlx::query("CREATE TABLE reminders (id TEXT)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        assert!(run(Backend::Sqlite, &mut conn, 1).await.is_err());
+        assert_eq!(0, version_of(&mut conn).await);
+
+        let tables: Vec<_> = schema(&mut conn)
+            .await
+            .into_iter()
+            .map(|(_, name, _)| name)
+            .collect();
+
+        assert_eq!(vec!["reminders".to_string()], tables);
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn database_newer_than_binary_is_refused() {
+        let mut conn = connect().await;
+
+        run(Backend::Sqlite, &mut conn, LATEST_VERSION)
+            .await
+            .unwrap();
+
+        sqlx::query("INSERT INTO migrations (id) VALUES ($1)")
+            .bind((LATEST_VERSION + 1) as i64)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        assert!(run(Backend::Sqlite, &mut conn, LATEST_VERSION)
+            .await
+            .is_err());
+        assert!(rollback(Backend::Sqlite, &mut conn, 0).await.is_err());
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn database_is_not_migrated_down_implicitly() {
+        let mut conn = connect().await;
+
+        run(Backend::Sqlite, &mut conn, LATEST_VERSION)
+            .await
+            .unwrap();
+
+        assert!(run(Backend::Sqlite, &mut conn, 1).await.is_err());
+        assert_eq!(LATEST_VERSION, version_of(&mut conn).await);
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn local_timestamps_are_converted_into_utc() {
+        use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+
+        let mut conn = connect().await;
+
+        run(Backend::Sqlite, &mut conn, 12).await.unwrap();
+
+        sqlx::query("INSERT INTO users (id, ext_id, created_at) VALUES ('user-1', 1, $1)")
+            .bind("2021-04-25 12:34:56")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        sqlx::query("INSERT INTO users (id, ext_id, created_at) VALUES ('user-2', 2, $1)")
+            .bind(Utc.ymd(2021, 4, 25).and_hms(10, 0, 0))
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        run(Backend::Sqlite, &mut conn, 13).await.unwrap();
+
+        let created_at: Vec<(DateTime<Utc>,)> =
+            sqlx::query_as("SELECT created_at FROM users ORDER BY ext_id")
+                .fetch_all(&mut conn)
+                .await
+                .unwrap();
+
+        let expected = Local
+            .from_local_datetime(
+                &NaiveDateTime::parse_from_str("2021-04-25 12:34:56", "%Y-%m-%d %H:%M:%S").unwrap(),
+            )
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(expected, created_at[0].0);
+        assert_eq!(Utc.ymd(2021, 4, 25).and_hms(10, 0, 0), created_at[1].0);
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn all_backends_have_the_same_migrations() {
+        assert_eq!(SQLITE_MIGRATIONS.len(), POSTGRES_MIGRATIONS.len());
+    }
+}
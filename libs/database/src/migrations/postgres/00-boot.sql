@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS migrations
+(
+    id BIGINT NOT NULL PRIMARY KEY
+);
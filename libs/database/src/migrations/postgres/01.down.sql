@@ -0,0 +1,6 @@
+DROP TABLE reminders;
+DROP TABLE merge_request_dependencies;
+DROP TABLE merge_requests;
+DROP TABLE projects;
+DROP TABLE users;
+DROP TABLE logs;
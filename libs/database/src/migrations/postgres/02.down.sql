@@ -0,0 +1,4 @@
+ALTER TABLE merge_requests RENAME COLUMN ext_iid TO iid;
+ALTER TABLE merge_requests RENAME COLUMN ext_state TO state;
+
+ALTER TABLE merge_request_dependencies RENAME COLUMN ext_discussion_id TO discussion_ext_id;
@@ -0,0 +1,4 @@
+ALTER TABLE merge_requests RENAME COLUMN iid TO ext_iid;
+ALTER TABLE merge_requests RENAME COLUMN state TO ext_state;
+
+ALTER TABLE merge_request_dependencies RENAME COLUMN discussion_ext_id TO ext_discussion_id;
@@ -0,0 +1,16 @@
+-- Reminders from before this migration didn't have discussion ids, so there's
+-- no way to carry them over
+DROP TABLE reminders;
+
+CREATE TABLE reminders
+(
+    id               TEXT        NOT NULL PRIMARY KEY,
+    user_id          TEXT        NOT NULL,
+    merge_request_id TEXT        NOT NULL,
+    remind_at        TIMESTAMPTZ NOT NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (user_id) REFERENCES users (id),
+    FOREIGN KEY (merge_request_id) REFERENCES merge_requests (id)
+);
+
+CREATE INDEX idx_reminders_remind_at ON reminders (remind_at);
@@ -0,0 +1,11 @@
+-- Concurrent `depends on` comments could've ended up creating the same
+-- dependency twice, so let's get rid of duplicates before adding the constraint
+DELETE
+FROM merge_request_dependencies
+WHERE id NOT IN (
+    SELECT min(id)
+    FROM merge_request_dependencies
+    GROUP BY user_id, ext_discussion_id, src_merge_request_id, dst_merge_request_id
+);
+
+CREATE UNIQUE INDEX idx_merge_request_dependencies_unique ON merge_request_dependencies (user_id, ext_discussion_id, src_merge_request_id, dst_merge_request_id);
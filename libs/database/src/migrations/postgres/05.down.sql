@@ -0,0 +1,4 @@
+DROP INDEX idx_logs_created_at;
+DROP INDEX idx_logs_event;
+
+ALTER TABLE logs DROP COLUMN id;
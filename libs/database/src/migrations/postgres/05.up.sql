@@ -0,0 +1,4 @@
+ALTER TABLE logs ADD COLUMN id BIGSERIAL PRIMARY KEY;
+
+CREATE INDEX idx_logs_event ON logs (event);
+CREATE INDEX idx_logs_created_at ON logs (created_at);
@@ -0,0 +1,7 @@
+DROP INDEX idx_logs_correlation_id;
+
+ALTER TABLE logs DROP COLUMN gitlab_calls;
+ALTER TABLE logs DROP COLUMN duration_ms;
+ALTER TABLE logs DROP COLUMN error;
+ALTER TABLE logs DROP COLUMN outcome;
+ALTER TABLE logs DROP COLUMN correlation_id;
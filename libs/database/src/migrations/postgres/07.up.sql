@@ -0,0 +1,7 @@
+CREATE TABLE notification_preferences
+(
+    user_id    TEXT        NOT NULL PRIMARY KEY,
+    channel    TEXT        NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    FOREIGN KEY (user_id) REFERENCES users (id)
+);
@@ -0,0 +1,20 @@
+-- Reminders that wait just for an event can't be expressed without the
+-- `trigger_*` columns, so there's no way to carry them over
+DELETE
+FROM reminder_recipients
+WHERE reminder_id IN (SELECT id FROM reminders WHERE remind_at IS NULL);
+
+DELETE
+FROM reminders
+WHERE remind_at IS NULL;
+
+DROP INDEX idx_reminders_trigger;
+
+ALTER TABLE reminders
+    DROP COLUMN trigger_merge_request_id;
+
+ALTER TABLE reminders
+    DROP COLUMN trigger_event;
+
+ALTER TABLE reminders
+    ALTER COLUMN remind_at SET NOT NULL;
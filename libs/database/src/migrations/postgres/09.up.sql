@@ -0,0 +1,12 @@
+-- Reminders can now wait for an event (e.g. for a pipeline to succeed) instead
+-- of / in addition to waiting for a specific time
+ALTER TABLE reminders
+    ALTER COLUMN remind_at DROP NOT NULL;
+
+ALTER TABLE reminders
+    ADD COLUMN trigger_event TEXT;
+
+ALTER TABLE reminders
+    ADD COLUMN trigger_merge_request_id TEXT REFERENCES merge_requests (id);
+
+CREATE INDEX idx_reminders_trigger ON reminders (trigger_merge_request_id, trigger_event);
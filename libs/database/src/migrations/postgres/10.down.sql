@@ -0,0 +1,5 @@
+ALTER TABLE reminders
+    DROP COLUMN inactivity_secs;
+
+ALTER TABLE reminders
+    DROP COLUMN on_activity;
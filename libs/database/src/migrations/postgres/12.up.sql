@@ -0,0 +1,11 @@
+CREATE TABLE digest_subscriptions
+(
+    user_id        TEXT        NOT NULL PRIMARY KEY,
+    day_of_week    BIGINT,
+    send_at        TEXT        NOT NULL,
+    timezone       TEXT,
+    next_digest_at TIMESTAMPTZ NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users (id)
+);
+
+CREATE INDEX idx_digest_subscriptions_next_digest_at ON digest_subscriptions (next_digest_at);
@@ -0,0 +1,3 @@
+-- Postgres' timestamps have always been stored in UTC, so there's nothing to
+-- convert; this migration exists only to keep versions in sync with SQLite
+SELECT 1;
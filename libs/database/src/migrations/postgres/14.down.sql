@@ -0,0 +1,8 @@
+ALTER TABLE digest_subscriptions
+    DROP COLUMN ext_issue_iid;
+
+ALTER TABLE digest_subscriptions
+    DROP COLUMN ext_issue_project_id;
+
+ALTER TABLE digest_subscriptions
+    DROP COLUMN last_digest_at;
@@ -0,0 +1,8 @@
+ALTER TABLE digest_subscriptions
+    ADD COLUMN last_digest_at TIMESTAMPTZ;
+
+ALTER TABLE digest_subscriptions
+    ADD COLUMN ext_issue_project_id BIGINT;
+
+ALTER TABLE digest_subscriptions
+    ADD COLUMN ext_issue_iid BIGINT;
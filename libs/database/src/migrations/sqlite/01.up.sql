@@ -1,5 +1,3 @@
-BEGIN TRANSACTION;
-
 CREATE TABLE logs
 (
     event      TEXT     NOT NULL,
@@ -79,5 +77,3 @@ CREATE TABLE reminders
 ) WITHOUT ROWID;
 
 CREATE INDEX idx_reminders_remind_at ON reminders (remind_at);
-
-COMMIT;
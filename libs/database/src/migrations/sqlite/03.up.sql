@@ -1,5 +1,3 @@
-BEGIN TRANSACTION;
-
 -- Since this feature wasn't ready for v0.1 anyway, it's perfectly fine to drop
 -- the entire table
 DROP TABLE reminders;
@@ -18,5 +16,3 @@ CREATE TABLE reminders
 ) WITHOUT ROWID;
 
 CREATE INDEX idx_reminders_remind_at ON reminders (remind_at);
-
-COMMIT;
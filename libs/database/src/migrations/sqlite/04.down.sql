@@ -0,0 +1 @@
+DROP INDEX idx_merge_request_dependencies_unique;
@@ -0,0 +1,15 @@
+ALTER TABLE logs RENAME TO logs_old;
+
+CREATE TABLE logs
+(
+    event      TEXT     NOT NULL,
+    payload    TEXT     NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT (datetime('now', 'localtime'))
+);
+
+INSERT INTO logs (event, payload, created_at)
+SELECT event, payload, created_at
+FROM logs_old
+ORDER BY id;
+
+DROP TABLE logs_old;
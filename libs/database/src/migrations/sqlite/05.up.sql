@@ -0,0 +1,21 @@
+-- SQLite doesn't support adding primary keys to existing tables, so we have to
+-- re-create the entire table
+ALTER TABLE logs RENAME TO logs_old;
+
+CREATE TABLE logs
+(
+    id         INTEGER  NOT NULL PRIMARY KEY,
+    event      TEXT     NOT NULL,
+    payload    TEXT     NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT (datetime('now', 'localtime'))
+);
+
+INSERT INTO logs (id, event, payload, created_at)
+SELECT rowid, event, payload, created_at
+FROM logs_old
+ORDER BY rowid;
+
+DROP TABLE logs_old;
+
+CREATE INDEX idx_logs_event ON logs (event);
+CREATE INDEX idx_logs_created_at ON logs (created_at);
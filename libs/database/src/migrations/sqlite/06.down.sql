@@ -0,0 +1,19 @@
+-- SQLite's `DROP COLUMN` is fairly recent, so let's re-create the table
+ALTER TABLE logs RENAME TO logs_old;
+
+CREATE TABLE logs
+(
+    id         INTEGER  NOT NULL PRIMARY KEY,
+    event      TEXT     NOT NULL,
+    payload    TEXT     NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT (datetime('now', 'localtime'))
+);
+
+INSERT INTO logs (id, event, payload, created_at)
+SELECT id, event, payload, created_at
+FROM logs_old;
+
+DROP TABLE logs_old;
+
+CREATE INDEX idx_logs_event ON logs (event);
+CREATE INDEX idx_logs_created_at ON logs (created_at);
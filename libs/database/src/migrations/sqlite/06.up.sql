@@ -0,0 +1,7 @@
+ALTER TABLE logs ADD COLUMN correlation_id TEXT;
+ALTER TABLE logs ADD COLUMN outcome TEXT;
+ALTER TABLE logs ADD COLUMN error TEXT;
+ALTER TABLE logs ADD COLUMN duration_ms INT;
+ALTER TABLE logs ADD COLUMN gitlab_calls TEXT;
+
+CREATE INDEX idx_logs_correlation_id ON logs (correlation_id);
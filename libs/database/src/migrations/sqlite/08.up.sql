@@ -0,0 +1,13 @@
+CREATE TABLE reminder_recipients
+(
+    reminder_id TEXT NOT NULL,
+    user_id     TEXT NOT NULL,
+    PRIMARY KEY (reminder_id, user_id),
+    FOREIGN KEY (reminder_id) REFERENCES reminders (id),
+    FOREIGN KEY (user_id) REFERENCES users (id)
+) WITHOUT ROWID;
+
+-- Up to this point reminders could've been addressed only to their authors
+INSERT INTO reminder_recipients (reminder_id, user_id)
+SELECT id, user_id
+FROM reminders;
@@ -0,0 +1,49 @@
+-- Reminders that wait just for an event can't be expressed without the
+-- `trigger_*` columns, so there's no way to carry them over
+CREATE TABLE reminders_old AS
+SELECT *
+FROM reminders
+WHERE remind_at IS NOT NULL;
+
+CREATE TABLE reminder_recipients_old AS
+SELECT *
+FROM reminder_recipients
+WHERE reminder_id IN (SELECT id FROM reminders_old);
+
+DROP TABLE reminder_recipients;
+DROP TABLE reminders;
+
+CREATE TABLE reminders
+(
+    id                TEXT     NOT NULL PRIMARY KEY,
+    user_id           TEXT     NOT NULL,
+    merge_request_id  TEXT     NOT NULL,
+    ext_discussion_id TEXT     NOT NULL,
+    message           TEXT,
+    remind_at         DATETIME NOT NULL,
+    created_at        DATETIME NOT NULL DEFAULT (datetime('now', 'localtime')),
+    FOREIGN KEY (user_id) REFERENCES users (id),
+    FOREIGN KEY (merge_request_id) REFERENCES merge_requests (id)
+) WITHOUT ROWID;
+
+CREATE INDEX idx_reminders_remind_at ON reminders (remind_at);
+
+INSERT INTO reminders (id, user_id, merge_request_id, ext_discussion_id, message, remind_at, created_at)
+SELECT id, user_id, merge_request_id, ext_discussion_id, message, remind_at, created_at
+FROM reminders_old;
+
+CREATE TABLE reminder_recipients
+(
+    reminder_id TEXT NOT NULL,
+    user_id     TEXT NOT NULL,
+    PRIMARY KEY (reminder_id, user_id),
+    FOREIGN KEY (reminder_id) REFERENCES reminders (id),
+    FOREIGN KEY (user_id) REFERENCES users (id)
+) WITHOUT ROWID;
+
+INSERT INTO reminder_recipients (reminder_id, user_id)
+SELECT reminder_id, user_id
+FROM reminder_recipients_old;
+
+DROP TABLE reminder_recipients_old;
+DROP TABLE reminders_old;
@@ -0,0 +1,5 @@
+ALTER TABLE reminders
+    ADD COLUMN on_activity TEXT;
+
+ALTER TABLE reminders
+    ADD COLUMN inactivity_secs INTEGER;
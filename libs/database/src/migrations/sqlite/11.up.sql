@@ -0,0 +1,8 @@
+CREATE TABLE nudges
+(
+    merge_request_id TEXT     NOT NULL,
+    level            INTEGER  NOT NULL,
+    nudged_at        DATETIME NOT NULL,
+    PRIMARY KEY (merge_request_id, level),
+    FOREIGN KEY (merge_request_id) REFERENCES merge_requests (id)
+) WITHOUT ROWID;
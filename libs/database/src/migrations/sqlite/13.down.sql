@@ -0,0 +1,3 @@
+-- UTC timestamps are understood by older versions of Janet as well, so there's
+-- nothing to revert
+SELECT 1;
@@ -0,0 +1,35 @@
+-- Timestamps used to be filled by columns' default values, which are in local
+-- time (e.g. `2021-04-25 12:00:00`), while the ones provided by Janet are in
+-- UTC (e.g. `2021-04-25T10:00:00+00:00`); since Janet now provides all of them
+-- by itself, let's convert the remaining local ones into UTC
+UPDATE logs
+SET created_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', created_at, 'utc')
+WHERE created_at NOT LIKE '%T%';
+
+UPDATE users
+SET created_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', created_at, 'utc')
+WHERE created_at NOT LIKE '%T%';
+
+UPDATE projects
+SET created_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', created_at, 'utc')
+WHERE created_at NOT LIKE '%T%';
+
+UPDATE merge_requests
+SET created_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', created_at, 'utc')
+WHERE created_at NOT LIKE '%T%';
+
+UPDATE merge_requests
+SET checked_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', checked_at, 'utc')
+WHERE checked_at NOT LIKE '%T%';
+
+UPDATE merge_request_dependencies
+SET created_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', created_at, 'utc')
+WHERE created_at NOT LIKE '%T%';
+
+UPDATE reminders
+SET created_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', created_at, 'utc')
+WHERE created_at NOT LIKE '%T%';
+
+UPDATE notification_preferences
+SET created_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', created_at, 'utc')
+WHERE created_at NOT LIKE '%T%';
@@ -0,0 +1,24 @@
+-- SQLite can't drop columns in-place, so we have to re-create the table
+CREATE TABLE digest_subscriptions_old AS
+SELECT *
+FROM digest_subscriptions;
+
+DROP TABLE digest_subscriptions;
+
+CREATE TABLE digest_subscriptions
+(
+    user_id        TEXT     NOT NULL PRIMARY KEY,
+    day_of_week    INTEGER,
+    send_at        TEXT     NOT NULL,
+    timezone       TEXT,
+    next_digest_at DATETIME NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users (id)
+) WITHOUT ROWID;
+
+CREATE INDEX idx_digest_subscriptions_next_digest_at ON digest_subscriptions (next_digest_at);
+
+INSERT INTO digest_subscriptions (user_id, day_of_week, send_at, timezone, next_digest_at)
+SELECT user_id, day_of_week, send_at, timezone, next_digest_at
+FROM digest_subscriptions_old;
+
+DROP TABLE digest_subscriptions_old;
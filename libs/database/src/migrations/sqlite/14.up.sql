@@ -0,0 +1,8 @@
+ALTER TABLE digest_subscriptions
+    ADD COLUMN last_digest_at DATETIME;
+
+ALTER TABLE digest_subscriptions
+    ADD COLUMN ext_issue_project_id INTEGER;
+
+ALTER TABLE digest_subscriptions
+    ADD COLUMN ext_issue_iid INTEGER;
@@ -1,4 +1,4 @@
-pub use self::{janet::*, test_context::*};
+pub use self::{janet::*, notifiers::*, test_context::*};
 
 pub const CONFIG: &str = indoc::indoc!(
     r#"
@@ -17,8 +17,21 @@ pub const CONFIG: &str = indoc::indoc!(
     addr = "{{ http.addr }}"
 
     [log]
+
+    [notifiers.webhook]
+    url = "{{ notifiers.url }}/webhook"
+
+    [notifiers.slack]
+    webhook_url = "{{ notifiers.url }}/slack"
+    users = { someone = "U250" }
+
+    [notifiers.matrix]
+    homeserver_url = "{{ notifiers.url }}/matrix"
+    access_token = "token"
+    room_id = "!room:localhost"
 "#
 );
 
 mod janet;
+mod notifiers;
 mod test_context;
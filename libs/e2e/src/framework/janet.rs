@@ -1,12 +1,15 @@
 use anyhow::*;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::Serialize;
 use std::path::Path;
-use std::process::Stdio;
+use std::process::{Output, Stdio};
 use std::thread;
 use tokio::io::AsyncReadExt;
 use tokio::{process, time};
 
+/// Value of the `X-Gitlab-Event-UUID` header sent with spoofed webhooks
+pub const SPOOFED_EVENT_UUID: &str = "00000000-0000-0000-0000-000000000250";
+
 pub struct Janet {
     url: String,
     child: process::Child,
@@ -15,12 +18,7 @@ pub struct Janet {
 
 impl Janet {
     pub async fn start(addr: impl AsRef<str>, config: impl AsRef<Path>) -> Result<Self> {
-        #[cfg(debug_assertions)]
-        let process = Path::new("target/debug/janet");
-
-        #[cfg(not(debug_assertions))]
-        let process = Path::new("target/release/janet");
-
+        let process = Self::process();
         let url = format!("http://{}", addr.as_ref());
         let config = config.as_ref();
 
@@ -44,11 +42,31 @@ impl Janet {
         Ok(this)
     }
 
+    /// Runs a one-off Janet's command (e.g. `check-config`) and waits for it
+    /// to complete
+    pub async fn run(
+        config: impl AsRef<Path>,
+        args: &[&str],
+        envs: &[(&str, &str)],
+    ) -> Result<Output> {
+        let process = Self::process();
+
+        process::Command::new(process)
+            .arg("--config")
+            .arg(config.as_ref())
+            .args(args)
+            .envs(envs.iter().cloned())
+            .output()
+            .await
+            .with_context(|| format!("Couldn't launch Janet: {}", process.display()))
+    }
+
     pub async fn spoof_gitlab_webhook(&self, body: &impl Serialize) {
         let body = serde_json::to_string(body).unwrap();
 
         self.client
             .post(&format!("{}/webhooks/gitlab", self.url))
+            .header("X-Gitlab-Event-UUID", SPOOFED_EVENT_UUID)
             .body(body)
             .send()
             .await
@@ -59,6 +77,58 @@ impl Janet {
             .unwrap();
     }
 
+    pub async fn health(&self, probe: &str) -> (StatusCode, serde_json::Value) {
+        let response = self
+            .client
+            .get(&format!("{}/health/{}", self.url, probe))
+            .send()
+            .await
+            .context("Couldn't send request")
+            .unwrap();
+
+        let status = response.status();
+
+        let body = response
+            .json()
+            .await
+            .context("Couldn't parse response")
+            .unwrap();
+
+        (status, body)
+    }
+
+    pub async fn metrics(&self) -> String {
+        self.client
+            .get(&format!("{}/metrics", self.url))
+            .send()
+            .await
+            .context("Couldn't send request")
+            .unwrap()
+            .error_for_status()
+            .context("Got an unexpected response status code")
+            .unwrap()
+            .text()
+            .await
+            .context("Couldn't read response")
+            .unwrap()
+    }
+
+    /// Sends `SIGHUP`, making Janet reload its configuration
+    pub async fn reload_config(&self) {
+        let status = process::Command::new("kill")
+            .arg("-HUP")
+            .arg(self.child.id().to_string())
+            .status()
+            .await
+            .context("Couldn't send SIGHUP")
+            .unwrap();
+
+        assert!(status.success(), "Couldn't send SIGHUP");
+
+        // Reloading happens asynchronously, so let's give Janet a moment
+        time::delay_for(time::Duration::from_millis(250)).await;
+    }
+
     pub async fn kill(&mut self) -> Result<(String, String)> {
         self.child.kill()?;
 
@@ -91,6 +161,16 @@ impl Janet {
         Ok((stdout, stderr))
     }
 
+    fn process() -> &'static Path {
+        #[cfg(debug_assertions)]
+        let process = Path::new("target/debug/janet");
+
+        #[cfg(not(debug_assertions))]
+        let process = Path::new("target/release/janet");
+
+        process
+    }
+
     async fn wait_until_reachable(&self) -> Result<()> {
         let mut i = 0usize;
 
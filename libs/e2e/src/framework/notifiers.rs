@@ -0,0 +1,65 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, header, method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Stand-in for the services chat notifiers talk to (a generic webhook, Slack
+/// and Matrix), all served from a single local HTTP server
+pub struct NotifiersMockServer {
+    inner: MockServer,
+}
+
+impl NotifiersMockServer {
+    pub async fn start() -> Self {
+        Self {
+            inner: MockServer::start().await,
+        }
+    }
+
+    pub fn url(&self) -> String {
+        self.inner.uri()
+    }
+
+    pub async fn expect_webhook(&self, body: Value) {
+        Mock::given(method("POST"))
+            .and(path("/webhook"))
+            .and(body_json(&body))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&self.inner)
+            .await;
+    }
+
+    pub async fn expect_slack_message(&self, text: impl AsRef<str>) {
+        let body = json!({
+            "text": text.as_ref(),
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/slack"))
+            .and(body_json(&body))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&self.inner)
+            .await;
+    }
+
+    pub async fn expect_matrix_message(&self, text: impl AsRef<str>) {
+        let body = json!({
+            "msgtype": "m.text",
+            "body": text.as_ref(),
+        });
+
+        Mock::given(method("PUT"))
+            .and(path_regex(
+                r"^/matrix/_matrix/client/r0/rooms/!room:localhost/send/m\.room\.message/.+$",
+            ))
+            .and(header("authorization", "Bearer token"))
+            .and(body_json(&body))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "event_id": "$event",
+            })))
+            .expect(1)
+            .mount(&self.inner)
+            .await;
+    }
+}
@@ -1,4 +1,4 @@
-use crate::framework::{Janet, CONFIG};
+use crate::framework::{Janet, NotifiersMockServer, CONFIG};
 use anyhow::*;
 use lib_gitlab::mock::GitLabMockServer;
 use rand::Rng;
@@ -11,7 +11,9 @@ use tokio::time::{delay_for, Duration};
 
 pub struct TestContext {
     pub temp: TempDir,
+    pub config: PathBuf,
     pub gitlab: GitLabMockServer,
+    pub notifiers: NotifiersMockServer,
     pub janet: Janet,
 }
 
@@ -22,6 +24,7 @@ impl TestContext {
         let temp = TempDir::new("janet-").context("Couldn't create temporary directory")?;
 
         let gitlab = GitLabMockServer::start().await;
+        let notifiers = NotifiersMockServer::start().await;
 
         let (http_addr, http_addr_guard) = Self::reserve_socket().await?;
 
@@ -31,17 +34,20 @@ impl TestContext {
             temp.path(),
             &database_path.display().to_string(),
             gitlab.url().as_str(),
+            &notifiers.url(),
             &http_addr,
         )
         .await?;
 
         drop(http_addr_guard);
 
-        let janet = Janet::start(http_addr, config_path).await?;
+        let janet = Janet::start(http_addr, &config_path).await?;
 
         Ok(Self {
             temp,
+            config: config_path,
             gitlab,
+            notifiers,
             janet,
         })
     }
@@ -74,6 +80,7 @@ impl TestContext {
         temp: &Path,
         database_path: &str,
         gitlab_url: &str,
+        notifiers_url: &str,
         http_addr: &str,
     ) -> Result<PathBuf> {
         let path = temp.join("config.toml");
@@ -81,6 +88,7 @@ impl TestContext {
         let content = String::from(CONFIG)
             .replace("{{ database.path }}", database_path)
             .replace("{{ gitlab.url }}", gitlab_url)
+            .replace("{{ notifiers.url }}", notifiers_url)
             .replace("{{ http.addr }}", http_addr);
 
         fs::write(&path, content)
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::future::Future;
+
+/// Request sent to GitLab, as recorded by [`record_calls()`]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GitLabCall {
+    /// Endpoint's name, e.g. `create_merge_request_note`
+    pub endpoint: String,
+
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+
+    /// Request's path, e.g. `/api/v4/projects/10/merge_requests/1`
+    pub path: String,
+
+    /// Response's status code, or `error` if no response came back (e.g. due to
+    /// a timeout)
+    pub status: String,
+
+    pub duration_ms: u64,
+}
+
+tokio::task_local! {
+    static CALLS: RefCell<Vec<GitLabCall>>;
+}
+
+/// Runs given future, recording all the requests it sends to GitLab.
+///
+/// Used to build the audit trail - so that it's possible to tell e.g. whether
+/// Janet actually tried to send a note and what GitLab responded with.
+pub async fn record_calls<F: Future>(f: F) -> (F::Output, Vec<GitLabCall>) {
+    CALLS
+        .scope(RefCell::new(Vec::new()), async {
+            let output = f.await;
+            let calls = CALLS.with(|calls| calls.take());
+
+            (output, calls)
+        })
+        .await
+}
+
+/// Records given call, if there's anybody listening (i.e. if we're inside
+/// [`record_calls()`])
+pub(crate) fn record_call(call: GitLabCall) {
+    let _ = CALLS.try_with(|calls| calls.borrow_mut().push(call));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(endpoint: &str) -> GitLabCall {
+        GitLabCall {
+            endpoint: endpoint.into(),
+            method: "GET".into(),
+            path: "/".into(),
+            status: "200".into(),
+            duration_ms: 0,
+        }
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn calls_are_recorded_only_inside_the_scope() {
+        record_call(call("outside"));
+
+        let (output, calls) = record_calls(async {
+            record_call(call("inside-1"));
+            tokio::task::yield_now().await;
+            record_call(call("inside-2"));
+
+            123
+        })
+        .await;
+
+        assert_eq!(123, output);
+        assert_eq!(vec![call("inside-1"), call("inside-2")], calls);
+    }
+}
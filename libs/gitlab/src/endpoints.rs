@@ -1,7 +1,16 @@
+mod branch_merge_requests;
+mod close_issue;
+mod create_issue;
+mod create_merge_request_comment;
 mod create_merge_request_note;
+mod current_user;
+mod group_members;
 mod merge_request;
-mod merge_requests;
 mod namespace;
+mod open_merge_requests;
 mod ping;
 mod project;
+mod project_members;
+mod reviewed_merge_requests;
 mod user;
+mod user_by_username;
@@ -0,0 +1,91 @@
+use crate::{GitLabClient, MergeRequest, ProjectId};
+use anyhow::*;
+
+impl GitLabClient {
+    /// Returns merge requests of given project that are still open and whose
+    /// source branch is given branch.
+    #[tracing::instrument(skip(self))]
+    pub async fn branch_merge_requests(
+        &self,
+        project: ProjectId,
+        branch: &str,
+    ) -> Result<Vec<MergeRequest>> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self
+                .url
+                .join("api/")?
+                .join("v4/")?
+                .join("projects/")?
+                .join(&format!("{}/", project.inner()))?
+                .join("merge_requests")?;
+
+            self.send_paginated(
+                "branch_merge_requests",
+                url,
+                &[("state", "opened"), ("source_branch", branch)],
+            )
+            .await?
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't find branch's merge requests");
+                err
+            })
+            .with_context(|| {
+                format!(
+                    "Couldn't find branch's merge requests: {}, {}",
+                    project.inner(),
+                    branch
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions as pa;
+
+    mod given_branch_with_merge_requests {
+        use super::*;
+        use crate::mock::merge_request_100;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_them() {
+            let (server, client) = GitLabClient::mock().await;
+            let expected = vec![merge_request_100()];
+
+            server
+                .expect_branch_merge_requests(ProjectId::new(10), "feature", &expected)
+                .await;
+
+            let actual = client
+                .branch_merge_requests(ProjectId::new(10), "feature")
+                .await
+                .unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+    }
+
+    mod given_branch_without_merge_requests {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_nothing() {
+            let (server, client) = GitLabClient::mock().await;
+
+            server
+                .expect_branch_merge_requests(ProjectId::new(10), "feature", &[])
+                .await;
+
+            let actual = client
+                .branch_merge_requests(ProjectId::new(10), "feature")
+                .await
+                .unwrap();
+
+            assert!(actual.is_empty());
+        }
+    }
+}
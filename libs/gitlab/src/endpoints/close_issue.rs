@@ -0,0 +1,63 @@
+use crate::{GitLabClient, IssueIid, ProjectId};
+use anyhow::*;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Request {
+    state_event: &'static str,
+}
+
+impl GitLabClient {
+    /// Closes given issue; used to get rid of issues that have been replaced
+    /// by newer ones (e.g. digests).
+    #[tracing::instrument(skip(self))]
+    pub async fn close_issue(&self, project: ProjectId, issue: IssueIid) -> Result<()> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self
+                .url
+                .join("api/")?
+                .join("v4/")?
+                .join("projects/")?
+                .join(&format!("{}/", project.inner()))?
+                .join("issues/")?
+                .join(&issue.inner().to_string())?;
+
+            let request = Request {
+                state_event: "close",
+            };
+
+            self.send_write("close_issue", self.client.put(url).json(&request))
+                .await?;
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't close issue");
+                err
+            })
+            .context("Couldn't close issue")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod given_existing_issue {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn closes_it() {
+            let (server, gitlab) = GitLabClient::mock().await;
+
+            server
+                .expect_issue_closed(ProjectId::new(10), IssueIid::new(7))
+                .await;
+
+            gitlab
+                .close_issue(ProjectId::new(10), IssueIid::new(7))
+                .await
+                .unwrap();
+        }
+    }
+}
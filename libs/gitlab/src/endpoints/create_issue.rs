@@ -0,0 +1,127 @@
+use crate::{GitLabClient, IssueIid, ProjectId, UserId};
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+struct Request {
+    title: String,
+    description: String,
+    assignee_ids: Vec<usize>,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    iid: IssueIid,
+}
+
+impl GitLabClient {
+    /// Creates an issue assigned to given user; used to deliver messages that
+    /// aren't bound to any merge request (e.g. digests).
+    ///
+    /// Returns issue's iid; `None` in the dry-run mode, since no issue gets
+    /// actually created then.
+    #[tracing::instrument(skip(self, description))]
+    pub async fn create_issue(
+        &self,
+        project: ProjectId,
+        assignee: UserId,
+        title: String,
+        description: String,
+    ) -> Result<Option<IssueIid>> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self
+                .url
+                .join("api/")?
+                .join("v4/")?
+                .join("projects/")?
+                .join(&format!("{}/", project.inner()))?
+                .join("issues")?;
+
+            let request = Request {
+                title,
+                description,
+                assignee_ids: vec![assignee.inner()],
+            };
+
+            let response = self
+                .send_write("create_issue", self.client.post(url).json(&request))
+                .await?;
+
+            match response {
+                Some(response) => Some(response.json::<Response>().await?.iid),
+                None => None,
+            }
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't create issue");
+                err
+            })
+            .context("Couldn't create issue")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod given_existing_project {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_created_issue() {
+            let (server, gitlab) = GitLabClient::mock().await;
+
+            server
+                .expect_issue_created(
+                    ProjectId::new(10),
+                    UserId::new(250),
+                    "Digest",
+                    IssueIid::new(7),
+                )
+                .await;
+
+            let actual = gitlab
+                .create_issue(
+                    ProjectId::new(10),
+                    UserId::new(250),
+                    "Digest".to_string(),
+                    "Nothing to review".to_string(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(Some(IssueIid::new(7)), actual);
+        }
+    }
+
+    mod in_dry_run_mode {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn records_issue_instead_of_creating_it() {
+            let (_server, gitlab) = GitLabClient::mock().await;
+            let gitlab = gitlab.dry_run();
+
+            let actual = gitlab
+                .create_issue(
+                    ProjectId::new(10),
+                    UserId::new(250),
+                    "Digest".to_string(),
+                    "Nothing to review".to_string(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(None, actual);
+
+            let requests = gitlab.take_dry_run_requests();
+
+            assert_eq!(1, requests.len());
+            assert_eq!("create_issue", requests[0].endpoint);
+            assert_eq!("POST", requests[0].method);
+            assert_eq!("/api/v4/projects/10/issues", requests[0].path);
+        }
+    }
+}
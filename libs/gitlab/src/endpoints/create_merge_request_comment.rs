@@ -0,0 +1,81 @@
+use crate::{GitLabClient, MergeRequestIid, ProjectId};
+use anyhow::*;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Request {
+    body: String,
+}
+
+impl GitLabClient {
+    /// Creates a note that starts a new discussion (as opposed to
+    /// `create_merge_request_note()`, which replies in an existing one)
+    #[tracing::instrument(skip(self))]
+    pub async fn create_merge_request_comment(
+        &self,
+        project: ProjectId,
+        merge_request: MergeRequestIid,
+        note: String,
+    ) -> Result<()> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self
+                .url
+                .join("api/")?
+                .join("v4/")?
+                .join("projects/")?
+                .join(&format!("{}/", project.inner()))?
+                .join("merge_requests/")?
+                .join(&format!("{}/", merge_request.inner()))?
+                .join("notes")?;
+
+            let request = Request { body: note };
+
+            self.send_write(
+                "create_merge_request_comment",
+                self.client.post(url).json(&request),
+            )
+            .await?;
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't create merge request comment");
+                err
+            })
+            .context("Couldn't create merge request comment")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod in_dry_run_mode {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn records_comment_instead_of_sending_it() {
+            let (_server, gitlab) = GitLabClient::mock().await;
+            let gitlab = gitlab.dry_run();
+
+            gitlab
+                .create_merge_request_comment(
+                    ProjectId::new(10),
+                    MergeRequestIid::new(1),
+                    "@someone ping".to_string(),
+                )
+                .await
+                .unwrap();
+
+            let requests = gitlab.take_dry_run_requests();
+
+            assert_eq!(1, requests.len());
+            assert_eq!("create_merge_request_comment", requests[0].endpoint);
+            assert_eq!("POST", requests[0].method);
+            assert_eq!(
+                "/api/v4/projects/10/merge_requests/1/notes",
+                requests[0].path
+            );
+        }
+    }
+}
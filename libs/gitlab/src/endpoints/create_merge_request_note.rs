@@ -33,12 +33,11 @@ impl GitLabClient {
 
             let request = Request { body: note };
 
-            self.client
-                .post(url)
-                .json(&request)
-                .send()
-                .await?
-                .error_for_status()?;
+            self.send_write(
+                "create_merge_request_note",
+                self.client.post(url).json(&request),
+            )
+            .await?;
         }: Result<_>)
             .map_err(|err| {
                 tracing::warn!({ err = ?err }, "Couldn't create merge request note");
@@ -50,5 +49,43 @@ impl GitLabClient {
 
 #[cfg(test)]
 mod tests {
-    // TODO
+    use super::*;
+
+    mod in_dry_run_mode {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn records_note_instead_of_sending_it() {
+            // Mock server doesn't expect any request, so if the client had
+            // actually sent one, it would've failed
+            let (_server, gitlab) = GitLabClient::mock().await;
+            let gitlab = gitlab.dry_run();
+
+            gitlab
+                .create_merge_request_note(
+                    ProjectId::new(10),
+                    MergeRequestIid::new(1),
+                    &DiscussionId::new("cafebabe"),
+                    "@someone :+1:".to_string(),
+                )
+                .await
+                .unwrap();
+
+            let requests = gitlab.take_dry_run_requests();
+
+            assert_eq!(1, requests.len());
+            assert_eq!("create_merge_request_note", requests[0].endpoint);
+            assert_eq!("POST", requests[0].method);
+            assert_eq!(
+                "/api/v4/projects/10/merge_requests/1/discussions/cafebabe/notes",
+                requests[0].path
+            );
+            assert_eq!(
+                Some(serde_json::json!({ "body": "@someone :+1:" })),
+                requests[0].body
+            );
+
+            assert!(gitlab.take_dry_run_requests().is_empty());
+        }
+    }
 }
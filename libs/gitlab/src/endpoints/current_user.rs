@@ -0,0 +1,47 @@
+use crate::{GitLabClient, User};
+use anyhow::*;
+
+impl GitLabClient {
+    /// Returns the user owning the personal access token Janet is using
+    #[tracing::instrument(skip(self))]
+    pub async fn current_user(&self) -> Result<User> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self.url.join("api/")?.join("v4/")?.join("user")?;
+
+            self.send("current_user", self.client.get(url))
+                .await?
+                .json()
+                .await?
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't find current user");
+                err
+            })
+            .context("Couldn't find current user")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions as pa;
+
+    mod given_valid_token {
+        use super::*;
+        use crate::mock::user_250;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_token_owner() {
+            let (server, client) = GitLabClient::mock().await;
+            let expected = user_250();
+
+            server.expect_current_user(&expected).await;
+
+            let actual = client.current_user().await.unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+    }
+}
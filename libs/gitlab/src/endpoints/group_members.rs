@@ -0,0 +1,77 @@
+use crate::{GitLabClient, User};
+use anyhow::*;
+
+impl GitLabClient {
+    /// Returns members of given group (e.g. `frontend-team`), including the
+    /// ones inherited from its parent groups.
+    #[tracing::instrument(skip(self))]
+    pub async fn group_members(&self, id: &str) -> Result<Vec<User>> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let id = id.replace("/", "%2f");
+
+            let url = self
+                .url
+                .join("api/")?
+                .join("v4/")?
+                .join("groups/")?
+                .join(&format!("{}/", id))?
+                .join("members/")?
+                .join("all")?;
+
+            self.send_paginated("group_members", url, &[]).await?
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't find group's members");
+                err
+            })
+            .with_context(|| format!("Couldn't find group's members: {}", id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UserId;
+    use pretty_assertions as pa;
+
+    mod given_existing_group {
+        use super::*;
+        use crate::mock::user_250;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_its_members() {
+            let (server, client) = GitLabClient::mock().await;
+            let expected = vec![user_250()];
+
+            server
+                .expect_group_members("alpha/frontend", &expected)
+                .await;
+
+            let actual = client.group_members("alpha/frontend").await.unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_members_from_all_pages() {
+            let (server, client) = GitLabClient::mock().await;
+
+            let expected: Vec<_> = (0..250)
+                .map(|id| User {
+                    id: UserId::new(id),
+                    username: format!("user-{}", id),
+                })
+                .collect();
+
+            server
+                .expect_group_members("alpha/frontend", &expected)
+                .await;
+
+            let actual = client.group_members("alpha/frontend").await.unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+    }
+}
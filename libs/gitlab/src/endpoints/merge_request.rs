@@ -20,11 +20,8 @@ impl GitLabClient {
                 .join("merge_requests/")?
                 .join(&merge_request.inner().to_string())?;
 
-            self.client
-                .get(url)
-                .send()
+            self.send("merge_request", self.client.get(url))
                 .await?
-                .error_for_status()?
                 .json()
                 .await?
         }: Result<_>)
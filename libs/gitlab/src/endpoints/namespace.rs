@@ -16,11 +16,8 @@ impl GitLabClient {
                 .join("namespaces/")?
                 .join(&id)?;
 
-            self.client
-                .get(url)
-                .send()
+            self.send("namespace", self.client.get(url))
                 .await?
-                .error_for_status()?
                 .json()
                 .await?
         }: Result<_>)
@@ -0,0 +1,95 @@
+use crate::{GitLabClient, MergeRequest, ProjectId};
+use anyhow::*;
+
+impl GitLabClient {
+    /// Returns merge requests of given project that are still open, the least
+    /// recently updated ones first.
+    #[tracing::instrument(skip(self))]
+    pub async fn open_merge_requests(&self, project: ProjectId) -> Result<Vec<MergeRequest>> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self
+                .url
+                .join("api/")?
+                .join("v4/")?
+                .join("projects/")?
+                .join(&format!("{}/", project.inner()))?
+                .join("merge_requests")?;
+
+            self.send_paginated(
+                "open_merge_requests",
+                url,
+                &[
+                    ("state", "opened"),
+                    ("order_by", "updated_at"),
+                    ("sort", "asc"),
+                ],
+            )
+            .await?
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't find project's open merge requests");
+                err
+            })
+            .with_context(|| {
+                format!(
+                    "Couldn't find project's open merge requests: {}",
+                    project.inner()
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MergeRequestId, MergeRequestIid};
+    use pretty_assertions as pa;
+
+    mod given_existing_project {
+        use super::*;
+        use crate::mock::{merge_request_100, merge_request_101};
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_its_open_merge_requests() {
+            let (server, client) = GitLabClient::mock().await;
+            let expected = vec![merge_request_100(), merge_request_101()];
+
+            server
+                .expect_open_merge_requests(ProjectId::new(10), &expected)
+                .await;
+
+            let actual = client
+                .open_merge_requests(ProjectId::new(10))
+                .await
+                .unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_merge_requests_from_all_pages() {
+            let (server, client) = GitLabClient::mock().await;
+
+            let expected: Vec<_> = (0..250)
+                .map(|id| MergeRequest {
+                    id: MergeRequestId::new(1000 + id),
+                    iid: MergeRequestIid::new(id),
+                    ..merge_request_100()
+                })
+                .collect();
+
+            server
+                .expect_open_merge_requests(ProjectId::new(10), &expected)
+                .await;
+
+            let actual = client
+                .open_merge_requests(ProjectId::new(10))
+                .await
+                .unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+    }
+}
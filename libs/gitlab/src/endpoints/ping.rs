@@ -7,11 +7,7 @@ impl GitLabClient {
         tracing::debug!("Sending request");
 
         (try {
-            self.client
-                .get(self.url.clone())
-                .send()
-                .await?
-                .error_for_status()?;
+            self.send("ping", self.client.get(self.url.clone())).await?;
         }: Result<_>)
             .map_err(|err| {
                 tracing::warn!({ err = ?err }, "Couldn't ping");
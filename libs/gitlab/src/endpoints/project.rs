@@ -16,11 +16,8 @@ impl GitLabClient {
                 .join("projects/")?
                 .join(&id)?;
 
-            self.client
-                .get(url)
-                .send()
+            self.send("project", self.client.get(url))
                 .await?
-                .error_for_status()?
                 .json()
                 .await?
         }: Result<_>)
@@ -53,4 +50,23 @@ mod tests {
             pa::assert_eq!(expected, actual);
         }
     }
+
+    mod given_existing_project_path {
+        use super::*;
+        use crate::mock::project_10;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_it() {
+            let (server, client) = GitLabClient::mock().await;
+            let expected = project_10();
+
+            server
+                .expect_project_by_path("alpha/beta/project", &expected)
+                .await;
+
+            let actual = client.project("alpha/beta/project").await.unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+    }
 }
@@ -0,0 +1,75 @@
+use crate::{GitLabClient, ProjectId, User};
+use anyhow::*;
+
+impl GitLabClient {
+    /// Returns members of given project, including the ones inherited from its
+    /// groups.
+    #[tracing::instrument(skip(self))]
+    pub async fn project_members(&self, project: ProjectId) -> Result<Vec<User>> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self
+                .url
+                .join("api/")?
+                .join("v4/")?
+                .join("projects/")?
+                .join(&format!("{}/", project.inner()))?
+                .join("members/")?
+                .join("all")?;
+
+            self.send_paginated("project_members", url, &[]).await?
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't find project's members");
+                err
+            })
+            .with_context(|| format!("Couldn't find project's members: {}", project.inner()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UserId;
+    use pretty_assertions as pa;
+
+    mod given_existing_project {
+        use super::*;
+        use crate::mock::user_250;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_its_members() {
+            let (server, client) = GitLabClient::mock().await;
+            let expected = vec![user_250()];
+
+            server
+                .expect_project_members(ProjectId::new(10), &expected)
+                .await;
+
+            let actual = client.project_members(ProjectId::new(10)).await.unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_members_from_all_pages() {
+            let (server, client) = GitLabClient::mock().await;
+
+            let expected: Vec<_> = (0..250)
+                .map(|id| User {
+                    id: UserId::new(id),
+                    username: format!("user-{}", id),
+                })
+                .collect();
+
+            server
+                .expect_project_members(ProjectId::new(10), &expected)
+                .await;
+
+            let actual = client.project_members(ProjectId::new(10)).await.unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+    }
+}
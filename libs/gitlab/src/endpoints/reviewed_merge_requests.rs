@@ -0,0 +1,64 @@
+use crate::{GitLabClient, MergeRequest, UserId};
+use anyhow::*;
+
+impl GitLabClient {
+    /// Returns open merge requests (from all the projects) where given user is
+    /// one of the reviewers.
+    #[tracing::instrument(skip(self))]
+    pub async fn reviewed_merge_requests(&self, user: UserId) -> Result<Vec<MergeRequest>> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self.url.join("api/")?.join("v4/")?.join("merge_requests")?;
+
+            self.send_paginated(
+                "reviewed_merge_requests",
+                url,
+                &[
+                    ("reviewer_id", user.inner().to_string().as_str()),
+                    ("state", "opened"),
+                    ("scope", "all"),
+                ],
+            )
+            .await?
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't find user's reviewed merge requests");
+                err
+            })
+            .with_context(|| {
+                format!(
+                    "Couldn't find user's reviewed merge requests: {}",
+                    user.inner()
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions as pa;
+
+    mod given_existing_user {
+        use super::*;
+        use crate::mock::{merge_request_100, merge_request_101};
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_merge_requests_they_review() {
+            let (server, client) = GitLabClient::mock().await;
+            let expected = vec![merge_request_100(), merge_request_101()];
+
+            server
+                .expect_reviewed_merge_requests(UserId::new(250), &expected)
+                .await;
+
+            let actual = client
+                .reviewed_merge_requests(UserId::new(250))
+                .await
+                .unwrap();
+
+            pa::assert_eq!(expected, actual);
+        }
+    }
+}
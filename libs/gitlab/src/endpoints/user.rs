@@ -14,11 +14,8 @@ impl GitLabClient {
                 .join("users/")?
                 .join(&id.inner().to_string())?;
 
-            self.client
-                .get(url)
-                .send()
+            self.send("user", self.client.get(url))
                 .await?
-                .error_for_status()?
                 .json()
                 .await?
         }: Result<_>)
@@ -0,0 +1,64 @@
+use crate::{GitLabClient, User};
+use anyhow::*;
+
+impl GitLabClient {
+    /// Returns user with given username (e.g. `someone`) or `None` if there's
+    /// no such user.
+    #[tracing::instrument(skip(self))]
+    pub async fn user_by_username(&self, username: &str) -> Result<Option<User>> {
+        tracing::debug!("Sending request");
+
+        (try {
+            let url = self.url.join("api/")?.join("v4/")?.join("users")?;
+
+            let request = self.client.get(url).query(&[("username", username)]);
+
+            let users: Vec<User> = self.send("user_by_username", request).await?.json().await?;
+
+            users.into_iter().next()
+        }: Result<_>)
+            .map_err(|err| {
+                tracing::warn!({ err = ?err }, "Couldn't find user");
+                err
+            })
+            .with_context(|| format!("Couldn't find user: {}", username))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions as pa;
+
+    mod given_existing_user {
+        use super::*;
+        use crate::mock::user_250;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_it() {
+            let (server, client) = GitLabClient::mock().await;
+            let expected = user_250();
+
+            server.expect_user(&expected).await;
+
+            let actual = client.user_by_username("someone").await.unwrap();
+
+            pa::assert_eq!(Some(expected), actual);
+        }
+    }
+
+    mod given_unknown_user {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn returns_none() {
+            let (server, client) = GitLabClient::mock().await;
+
+            server.expect_no_user("nobody").await;
+
+            let actual = client.user_by_username("nobody").await.unwrap();
+
+            pa::assert_eq!(None, actual);
+        }
+    }
+}
@@ -2,10 +2,12 @@
 #![feature(try_blocks)]
 #![feature(type_ascription)]
 
-pub use self::{config::*, models::*};
+pub use self::{audit::*, config::*, models::*};
 
+mod audit;
 mod config;
 mod endpoints;
+mod metrics;
 mod models;
 
 #[cfg(any(test, feature = "mock"))]
@@ -13,13 +15,41 @@ pub mod mock;
 
 use anyhow::Result;
 use reqwest::header::HeaderMap;
-use reqwest::{header, Client, Url};
+use reqwest::{header, Client, RequestBuilder, Response, Url};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::iter::FromIterator;
-use std::time::Duration;
+use std::mem;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How many items we ask for in a single request to paginated endpoints; that's
+/// the maximum GitLab allows
+const PER_PAGE: &str = "100";
 
 pub struct GitLabClient {
     url: Url,
     client: Client,
+
+    /// When set, requests that'd modify GitLab (e.g. creating notes) are not
+    /// sent, but recorded here instead - see: [`Self::dry_run()`]
+    dry_run: Option<Mutex<Vec<DryRunRequest>>>,
+}
+
+/// Request that would've been sent to GitLab, if not for the dry-run mode
+#[derive(Clone, Debug, Serialize)]
+pub struct DryRunRequest {
+    /// Endpoint's name, e.g. `create_merge_request_note`
+    pub endpoint: String,
+
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+
+    /// Request's path, e.g. `/api/v4/projects/10/merge_requests/1/notes`
+    pub path: String,
+
+    /// Request's body, if it was a JSON
+    pub body: Option<serde_json::Value>,
 }
 
 impl GitLabClient {
@@ -36,7 +66,34 @@ impl GitLabClient {
             .timeout(Duration::from_secs(15))
             .build()?;
 
-        Ok(Self { url, client })
+        Ok(Self {
+            url,
+            client,
+            dry_run: None,
+        })
+    }
+
+    /// Returns address of the GitLab instance this client talks to
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Switches client into the dry-run mode, in which requests that'd modify
+    /// GitLab are not sent, but recorded instead (they can be retrieved using
+    /// [`Self::take_dry_run_requests()`]); read-only requests are sent as
+    /// usual.
+    pub fn dry_run(mut self) -> Self {
+        self.dry_run = Some(Default::default());
+        self
+    }
+
+    /// Returns requests recorded in the dry-run mode since the last call to
+    /// this function
+    pub fn take_dry_run_requests(&self) -> Vec<DryRunRequest> {
+        self.dry_run
+            .as_ref()
+            .map(|requests| mem::take(&mut *requests.lock().unwrap()))
+            .unwrap_or_default()
     }
 
     #[cfg(any(test, feature = "mock"))]
@@ -59,4 +116,121 @@ impl GitLabClient {
 
         Ok(gitlab)
     }
+
+    /// Sends given request (which is expected to modify GitLab's state, e.g.
+    /// by creating a note), unless we're in the dry-run mode - in which case
+    /// the request just gets recorded and there's no response.
+    async fn send_write(
+        &self,
+        endpoint: &'static str,
+        request: RequestBuilder,
+    ) -> Result<Option<Response>> {
+        if let Some(requests) = &self.dry_run {
+            let request = request.build()?;
+
+            let body = request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .and_then(|body| serde_json::from_slice(body).ok());
+
+            let method = request.method().to_string();
+            let path = request.url().path().to_string();
+
+            audit::record_call(GitLabCall {
+                endpoint: endpoint.to_string(),
+                method: method.clone(),
+                path: path.clone(),
+                status: "dry-run".to_string(),
+                duration_ms: 0,
+            });
+
+            requests.lock().unwrap().push(DryRunRequest {
+                endpoint: endpoint.to_string(),
+                method,
+                path,
+                body,
+            });
+
+            return Ok(None);
+        }
+
+        Ok(Some(self.send(endpoint, request).await?))
+    }
+
+    /// Sends given request and returns its response, recording the call's
+    /// status and latency under given endpoint's name (both in metrics and in
+    /// the audit trail - see: [`record_calls()`]).
+    async fn send(&self, endpoint: &'static str, request: RequestBuilder) -> Result<Response> {
+        let request = request.build()?;
+        let method = request.method().to_string();
+        let path = request.url().path().to_string();
+
+        let timer = metrics::REQUEST_DURATION
+            .with_label_values(&[endpoint])
+            .start_timer();
+
+        let started_at = Instant::now();
+        let response = self.client.execute(request).await;
+
+        timer.observe_duration();
+
+        let status = match &response {
+            Ok(response) => response.status().as_str().to_string(),
+            Err(_) => "error".to_string(),
+        };
+
+        metrics::REQUESTS
+            .with_label_values(&[endpoint, &status])
+            .inc();
+
+        audit::record_call(GitLabCall {
+            endpoint: endpoint.to_string(),
+            method,
+            path,
+            status,
+            duration_ms: started_at.elapsed().as_millis() as u64,
+        });
+
+        Ok(response?.error_for_status()?)
+    }
+
+    /// Sends a `GET` request to given paginated endpoint and returns items from
+    /// all of its pages, following GitLab's `X-Next-Page` header.
+    async fn send_paginated<T>(
+        &self,
+        endpoint: &'static str,
+        url: Url,
+        query: &[(&str, &str)],
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let mut items = Vec::new();
+        let mut page = "1".to_string();
+
+        loop {
+            let request = self
+                .client
+                .get(url.clone())
+                .query(query)
+                .query(&[("per_page", PER_PAGE), ("page", page.as_str())]);
+
+            let response = self.send(endpoint, request).await?;
+
+            // GitLab leaves this header empty on the last page
+            let next_page = response
+                .headers()
+                .get("x-next-page")
+                .and_then(|next_page| next_page.to_str().ok())
+                .filter(|next_page| !next_page.is_empty())
+                .map(ToString::to_string);
+
+            items.extend(response.json::<Vec<T>>().await?);
+
+            match next_page {
+                Some(next_page) => page = next_page,
+                None => return Ok(items),
+            }
+        }
+    }
 }
@@ -0,0 +1,20 @@
+use once_cell::sync::Lazy;
+use prometheus::{register_histogram_vec, register_int_counter_vec, HistogramVec, IntCounterVec};
+
+pub(crate) static REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "janet_gitlab_requests_total",
+        "Number of requests sent to GitLab, by endpoint and response status",
+        &["endpoint", "status"]
+    )
+    .unwrap()
+});
+
+pub(crate) static REQUEST_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "janet_gitlab_request_duration_seconds",
+        "Latency of requests sent to GitLab, by endpoint",
+        &["endpoint"]
+    )
+    .unwrap()
+});
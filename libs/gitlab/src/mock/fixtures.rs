@@ -22,6 +22,13 @@ pub fn merge_request_100() -> MergeRequest {
         iid: MergeRequestIid::new(1),
         web_url: "http://gitlab.com/merge_requests/100".to_string(),
         state: "opened".to_string(),
+        author: Default::default(),
+        assignees: Default::default(),
+        reviewers: Default::default(),
+        labels: Default::default(),
+        updated_at: None,
+        merged_at: None,
+        closed_at: None,
     }
 }
 
@@ -32,6 +39,13 @@ pub fn merge_request_101() -> MergeRequest {
         iid: MergeRequestIid::new(2),
         web_url: "http://gitlab.com/merge_requests/101".to_string(),
         state: "opened".to_string(),
+        author: Default::default(),
+        assignees: Default::default(),
+        reviewers: Default::default(),
+        labels: Default::default(),
+        updated_at: None,
+        merged_at: None,
+        closed_at: None,
     }
 }
 
@@ -41,3 +55,10 @@ pub fn user_250() -> User {
         username: "someone".to_string(),
     }
 }
+
+pub fn user_251() -> User {
+    User {
+        id: UserId::new(251),
+        username: "someone-else".to_string(),
+    }
+}
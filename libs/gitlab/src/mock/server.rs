@@ -1,8 +1,23 @@
-use crate::{DiscussionId, MergeRequest, MergeRequestIid, Namespace, Project, ProjectId, User};
+use crate::{
+    DiscussionId,
+    IssueIid,
+    MergeRequest,
+    MergeRequestIid,
+    Namespace,
+    Project,
+    ProjectId,
+    User,
+    UserId,
+};
+use serde::Serialize;
 use serde_json::json;
 use url::Url;
-use wiremock::matchers::{body_json, method, path};
-use wiremock::{Mock, MockServer, ResponseTemplate};
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockBuilder, MockServer, Request, ResponseTemplate};
+
+/// Size of pages served by paginated endpoints (same as the one requested by
+/// `GitLabClient`)
+const PER_PAGE: usize = 100;
 
 pub struct GitLabMockServer {
     inner: MockServer,
@@ -29,6 +44,16 @@ impl GitLabMockServer {
         self.inner.uri().parse().unwrap()
     }
 
+    pub async fn expect_current_user(&self, user: &User) {
+        let response = ResponseTemplate::new(200).set_body_json(user);
+
+        Mock::given(method("GET"))
+            .and(path("/api/v4/user"))
+            .respond_with(response)
+            .mount(&self.inner)
+            .await;
+    }
+
     pub async fn expect_merge_request(&self, merge_request: &MergeRequest) {
         let url = format!(
             "/api/v4/projects/{}/merge_requests/{}",
@@ -45,6 +70,87 @@ impl GitLabMockServer {
             .await;
     }
 
+    pub async fn expect_group_members(&self, group_path: &str, members: &[User]) {
+        let url = format!(
+            "/api/v4/groups/{}/members/all",
+            group_path.replace("/", "%2f")
+        );
+
+        self.mount_paginated(|| Mock::given(method("GET")).and(path(&url)), members)
+            .await;
+    }
+
+    pub async fn expect_issue_created(
+        &self,
+        project: ProjectId,
+        assignee: UserId,
+        title: impl AsRef<str>,
+        iid: IssueIid,
+    ) {
+        let url = format!("/api/v4/projects/{}/issues", project.inner());
+        let title = title.as_ref().to_string();
+
+        // Issue's description is too long to be matched as a whole, so we're
+        // checking just the title & assignee
+        let matches_issue = move |request: &Request| {
+            let body: serde_json::Value = match serde_json::from_slice(&request.body) {
+                Ok(body) => body,
+                Err(_) => return false,
+            };
+
+            body["title"] == json!(title) && body["assignee_ids"] == json!([assignee.inner()])
+        };
+
+        Mock::given(method("POST"))
+            .and(path(url))
+            .and(matches_issue)
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "iid": iid })))
+            .expect(1)
+            .mount(&self.inner)
+            .await;
+    }
+
+    pub async fn expect_issue_closed(&self, project: ProjectId, iid: IssueIid) {
+        let url = format!(
+            "/api/v4/projects/{}/issues/{}",
+            project.inner(),
+            iid.inner()
+        );
+
+        Mock::given(method("PUT"))
+            .and(path(url))
+            .and(body_json(&json!({ "state_event": "close" })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&self.inner)
+            .await;
+    }
+
+    pub async fn expect_merge_request_comment_created(
+        &self,
+        project: ProjectId,
+        merge_request: MergeRequestIid,
+        note: impl AsRef<str>,
+    ) {
+        let url = format!(
+            "/api/v4/projects/{}/merge_requests/{}/notes",
+            project.inner(),
+            merge_request.inner(),
+        );
+
+        let body = json!({
+            "body": note.as_ref(),
+        });
+
+        Mock::given(method("POST"))
+            .and(path(url))
+            .and(body_json(&body))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&self.inner)
+            .await;
+    }
+
     pub async fn expect_merge_request_note_created(
         &self,
         project: ProjectId,
@@ -83,6 +189,61 @@ impl GitLabMockServer {
             .await;
     }
 
+    pub async fn expect_branch_merge_requests(
+        &self,
+        project: ProjectId,
+        branch: &str,
+        merge_requests: &[MergeRequest],
+    ) {
+        let url = format!("/api/v4/projects/{}/merge_requests", project.inner());
+
+        self.mount_paginated(
+            || {
+                Mock::given(method("GET"))
+                    .and(path(&url))
+                    .and(query_param("state", "opened"))
+                    .and(query_param("source_branch", branch))
+            },
+            merge_requests,
+        )
+        .await;
+    }
+
+    pub async fn expect_open_merge_requests(
+        &self,
+        project: ProjectId,
+        merge_requests: &[MergeRequest],
+    ) {
+        let url = format!("/api/v4/projects/{}/merge_requests", project.inner());
+
+        self.mount_paginated(
+            || {
+                Mock::given(method("GET"))
+                    .and(path(&url))
+                    .and(query_param("state", "opened"))
+            },
+            merge_requests,
+        )
+        .await;
+    }
+
+    pub async fn expect_reviewed_merge_requests(
+        &self,
+        user: UserId,
+        merge_requests: &[MergeRequest],
+    ) {
+        self.mount_paginated(
+            || {
+                Mock::given(method("GET"))
+                    .and(path("/api/v4/merge_requests"))
+                    .and(query_param("reviewer_id", user.inner().to_string()))
+                    .and(query_param("state", "opened"))
+            },
+            merge_requests,
+        )
+        .await;
+    }
+
     pub async fn expect_project(&self, project: &Project) {
         let url = format!("/api/v4/projects/{}", project.id.inner());
         let response = ResponseTemplate::new(200).set_body_json(project);
@@ -94,6 +255,26 @@ impl GitLabMockServer {
             .await;
     }
 
+    pub async fn expect_project_members(&self, project: ProjectId, members: &[User]) {
+        let url = format!("/api/v4/projects/{}/members/all", project.inner());
+
+        self.mount_paginated(|| Mock::given(method("GET")).and(path(&url)), members)
+            .await;
+    }
+
+    /// Mounts project under given path (e.g. `alpha/beta/project`), as
+    /// referred to by the path-encoded `projects/` endpoint
+    pub async fn expect_project_by_path(&self, project_path: &str, project: &Project) {
+        let url = format!("/api/v4/projects/{}", project_path.replace("/", "%2f"));
+        let response = ResponseTemplate::new(200).set_body_json(project);
+
+        Mock::given(method("GET"))
+            .and(path(url))
+            .respond_with(response)
+            .mount(&self.inner)
+            .await;
+    }
+
     pub async fn expect_user(&self, user: &User) {
         let url = format!("/api/v4/users/{}", user.id.inner());
         let response = ResponseTemplate::new(200).set_body_json(user);
@@ -103,5 +284,55 @@ impl GitLabMockServer {
             .respond_with(response)
             .mount(&self.inner)
             .await;
+
+        let response = ResponseTemplate::new(200).set_body_json(vec![user]);
+
+        Mock::given(method("GET"))
+            .and(path("/api/v4/users"))
+            .and(query_param("username", user.username.as_str()))
+            .respond_with(response)
+            .mount(&self.inner)
+            .await;
+    }
+
+    /// Mounts an empty result for the `users?username=` lookup, as returned by
+    /// GitLab for users that don't exist
+    pub async fn expect_no_user(&self, username: &str) {
+        let response = ResponseTemplate::new(200).set_body_json(Vec::<User>::new());
+
+        Mock::given(method("GET"))
+            .and(path("/api/v4/users"))
+            .and(query_param("username", username))
+            .respond_with(response)
+            .mount(&self.inner)
+            .await;
+    }
+
+    /// Mounts given items split into pages, the way GitLab serves them (i.e.
+    /// with the `X-Next-Page` header pointing at the next page, if any)
+    async fn mount_paginated<T>(&self, mock: impl Fn() -> MockBuilder, items: &[T])
+    where
+        T: Serialize,
+    {
+        let pages: Vec<_> = if items.is_empty() {
+            vec![items]
+        } else {
+            items.chunks(PER_PAGE).collect()
+        };
+
+        for (page_idx, page) in pages.iter().enumerate() {
+            let mut response = ResponseTemplate::new(200).set_body_json(page);
+
+            if page_idx + 1 < pages.len() {
+                response =
+                    response.insert_header("x-next-page", (page_idx + 2).to_string().as_str());
+            }
+
+            mock()
+                .and(query_param("page", (page_idx + 1).to_string()))
+                .respond_with(response)
+                .mount(&self.inner)
+                .await;
+        }
     }
 }
@@ -1,5 +1,6 @@
 pub use self::{
     discussion_id::*,
+    issue_iid::*,
     merge_request::*,
     merge_request_id::*,
     merge_request_iid::*,
@@ -15,6 +16,7 @@ pub use self::{
 };
 
 mod discussion_id;
+mod issue_iid;
 mod merge_request;
 mod merge_request_id;
 mod merge_request_iid;
@@ -1,4 +1,5 @@
-use crate::{MergeRequestId, MergeRequestIid, ProjectId};
+use crate::{MergeRequestId, MergeRequestIid, ProjectId, User};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -8,4 +9,27 @@ pub struct MergeRequest {
     pub project_id: ProjectId,
     pub state: String,
     pub web_url: String,
+
+    #[serde(default)]
+    pub author: Option<User>,
+
+    #[serde(default)]
+    pub assignees: Vec<User>,
+
+    #[serde(default)]
+    pub reviewers: Vec<User>,
+
+    #[serde(default)]
+    pub labels: Vec<String>,
+
+    /// When anything (e.g. a note) happened on the merge request for the last
+    /// time
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub merged_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub closed_at: Option<DateTime<Utc>>,
 }
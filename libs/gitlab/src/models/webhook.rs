@@ -14,9 +14,44 @@ pub enum WebhookEvent {
         project: WebhookProject,
         merge_request: WebhookMergeRequest,
         object_attributes: WebhookNoteAttrs,
+
+        /// Note's author; optional, since we rely on it only to recognize our
+        /// own notes
+        #[serde(default)]
+        user: Option<WebhookUser>,
+    },
+
+    Pipeline {
+        project: WebhookProject,
+
+        /// Merge request the pipeline was run for; missing for pipelines run
+        /// e.g. on branches
+        merge_request: Option<WebhookMergeRequest>,
+
+        object_attributes: WebhookPipelineAttrs,
     },
 }
 
+impl WebhookEvent {
+    /// Parses webhook's body.
+    ///
+    /// Contrary to merge request & note hooks, pipeline hooks don't contain
+    /// the `event_type` field - for those we fall back to `object_kind`.
+    pub fn from_slice(body: &[u8]) -> serde_json::Result<Self> {
+        let mut body: serde_json::Value = serde_json::from_slice(body)?;
+
+        if let Some(body) = body.as_object_mut() {
+            if !body.contains_key("event_type") {
+                if let Some(object_kind) = body.get("object_kind").cloned() {
+                    body.insert("event_type".into(), object_kind);
+                }
+            }
+        }
+
+        serde_json::from_value(body)
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
 pub struct WebhookProject {
     pub id: ProjectId,
@@ -40,3 +75,70 @@ pub struct WebhookNoteAttrs {
     pub description: String,
     pub discussion_id: DiscussionId,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct WebhookUser {
+    pub username: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct WebhookPipelineAttrs {
+    /// E.g. `success` or `failed`
+    pub status: String,
+
+    /// Branch (or tag) the pipeline was run for; used to find merge requests
+    /// of pipelines that were run on branches
+    #[serde(rename = "ref")]
+    #[serde(default)]
+    pub ref_: Option<String>,
+
+    /// Whether `ref_` points at a tag
+    #[serde(default)]
+    pub tag: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn pipeline_hooks_are_recognized_by_object_kind() {
+        let body = json!({
+            "object_kind": "pipeline",
+            "project": {
+                "id": 10,
+                "namespace": "alpha",
+            },
+            "merge_request": {
+                "id": 100,
+                "iid": 1,
+            },
+            "object_attributes": {
+                "id": 1000,
+                "status": "success",
+                "ref": "feature",
+                "tag": false,
+            },
+        });
+
+        let actual = WebhookEvent::from_slice(body.to_string().as_bytes()).unwrap();
+
+        let expected = WebhookEvent::Pipeline {
+            project: WebhookProject {
+                id: ProjectId::new(10),
+                namespace: NamespaceName::new("alpha"),
+            },
+            merge_request: Some(WebhookMergeRequest {
+                iid: MergeRequestIid::new(1),
+            }),
+            object_attributes: WebhookPipelineAttrs {
+                status: "success".into(),
+                ref_: Some("feature".into()),
+                tag: false,
+            },
+        };
+
+        assert_eq!(expected, actual);
+    }
+}
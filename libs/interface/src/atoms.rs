@@ -2,17 +2,23 @@ pub use self::{
     date::*,
     date_time::*,
     day_of_week::*,
+    digest_schedule::*,
     merge_request_iid::*,
     merge_request_ptr::*,
+    month::*,
     name::*,
     namespace_ptr::*,
+    notification_channel::*,
     project_id::*,
     project_name::*,
     project_ptr::*,
     ptr_context::*,
+    recipient::*,
     relative_date::*,
     relative_time::*,
     time::*,
+    timezone::*,
+    trigger::*,
     url::*,
     usize::*,
 };
@@ -20,24 +26,30 @@ pub use self::{
 mod date;
 mod date_time;
 mod day_of_week;
+mod digest_schedule;
 mod merge_request_iid;
 mod merge_request_ptr;
+mod month;
 mod name;
 mod namespace_ptr;
+mod notification_channel;
 mod project_id;
 mod project_name;
 mod project_ptr;
 mod ptr_context;
+mod recipient;
 mod relative_date;
 mod relative_time;
 mod time;
+mod timezone;
+mod trigger;
 mod url;
 mod usize;
 
-use nom::IResult;
+use crate::ParseResult;
 
 pub trait Atom: Sized {
-    fn parse(i: &str) -> IResult<&str, Self>;
+    fn parse(i: &str) -> ParseResult<Self>;
 
     #[cfg(test)]
     fn parse_unwrap(i: &str) -> Self {
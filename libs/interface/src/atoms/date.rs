@@ -1,18 +1,18 @@
 use crate::RelativeDate;
 use chrono::NaiveDate;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 mod atom;
 mod resolve;
 
-/// A date, e.g. `2018-01-01`, `monday` or `in 3d`.
+/// A date, e.g. `2018-01-01`, `24.12.2020`, `monday`, `on dec 24` or `in 3d`.
 ///
 /// Used as a part of the `date` component of the `DateTime` atom.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum Date {
-    /// E.g. `2018-01-01`
+    /// E.g. `2018-01-01` or `24.12.2020`
     Absolute(NaiveDate),
 
-    /// E.g. `monday` or `in 3d`
+    /// E.g. `monday`, `on dec 24` or `in 3d`
     Relative(RelativeDate),
 }
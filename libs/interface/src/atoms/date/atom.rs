@@ -1,42 +1,87 @@
-use crate::{Atom, Date, RelativeDate};
+use crate::{Atom, Date, ParseError, ParseResult, RelativeDate};
 use chrono::NaiveDate;
 use nom::branch::alt;
 use nom::character::complete::char;
-use nom::{IResult, Parser};
+use nom::Parser;
 
 impl Atom for Date {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         alt((absolute, relative))(i)
     }
 }
 
-fn absolute(i: &str) -> IResult<&str, Date> {
-    usize::parse
+fn absolute(start: &str) -> ParseResult<Date> {
+    // e.g. `2018-01-02`
+    let iso = usize::parse
         .and(char('-'))
         .and(usize::parse)
         .and(char('-'))
         .and(usize::parse)
-        .map(|((((year, _), month), _), day)| {
-            Date::Absolute(NaiveDate::from_ymd(year as i32, month as u32, day as u32))
-        })
-        .parse(i)
+        .map(|((((year, _), month), _), day)| (year, month, day));
+
+    // e.g. `02.01.2018`
+    let dotted = usize::parse
+        .and(char('.'))
+        .and(usize::parse)
+        .and(char('.'))
+        .and(usize::parse)
+        .map(|((((day, _), month), _), year)| (year, month, day));
+
+    let (i, (year, month, day)) = alt((iso, dotted))(start)?;
+
+    if year > 9999 {
+        return ParseError::fail(start, "year must be 0-9999");
+    }
+
+    if !(1..=12).contains(&month) {
+        return ParseError::fail(start, "month must be 1-12");
+    }
+
+    if !(1..=31).contains(&day) {
+        return ParseError::fail(start, "day must be 1-31");
+    }
+
+    match NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32) {
+        Some(date) => Ok((i, Date::Absolute(date))),
+        None => ParseError::fail(start, "there's no such day in that month"),
+    }
 }
 
-fn relative(i: &str) -> IResult<&str, Date> {
+fn relative(i: &str) -> ParseResult<Date> {
     RelativeDate::parse.map(Date::Relative).parse(i)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::DayOfWeek;
+    use crate::{DayOfWeek, Month};
     use test_case::test_case;
 
     #[test_case("2018-01-02" => Date::Absolute(NaiveDate::from_ymd(2018, 01, 02)) ; "2018-01-02")]
+    #[test_case("02.01.2018" => Date::Absolute(NaiveDate::from_ymd(2018, 01, 02)) ; "02.01.2018")]
+    #[test_case("24.12.2020" => Date::Absolute(NaiveDate::from_ymd(2020, 12, 24)) ; "24.12.2020")]
     fn absolute(input: &str) -> Date {
         Date::parse_unwrap(input)
     }
 
+    #[test_case("12345-01-02" => "year must be 0-9999" ; "12345-01-02")]
+    #[test_case("2018-00-02" => "month must be 1-12" ; "2018-00-02")]
+    #[test_case("2018-13-02" => "month must be 1-12" ; "2018-13-02")]
+    #[test_case("2018-01-00" => "day must be 1-31" ; "2018-01-00")]
+    #[test_case("2018-01-32" => "day must be 1-31" ; "2018-01-32")]
+    #[test_case("2018-02-30" => "there's no such day in that month" ; "2018-02-30")]
+    #[test_case("24.13.2020" => "month must be 1-12" ; "24.13.2020")]
+    #[test_case("31.04.2020" => "there's no such day in that month" ; "31.04.2020")]
+    fn invalid_absolute(input: &str) -> &'static str {
+        match Date::parse(input) {
+            Err(nom::Err::Failure(ParseError {
+                reason: Some(reason),
+                ..
+            })) => reason,
+            result => panic!("Expected failure, got: {:?}", result),
+        }
+    }
+
     #[test_case("today" => Date::Relative(RelativeDate::Days(0)) ; "today")]
     #[test_case("tomorrow" => Date::Relative(RelativeDate::Days(1)) ; "tomorrow")]
     #[test_case("123d" => Date::Relative(RelativeDate::Days(123)) ; "123d")]
@@ -48,6 +93,12 @@ mod tests {
     #[test_case("next week" => Date::Relative(RelativeDate::Weeks(1)) ; "next week")]
     #[test_case("123w" => Date::Relative(RelativeDate::Weeks(123)) ; "123w")]
     #[test_case("in 123w" => Date::Relative(RelativeDate::Weeks(123)) ; "in 123w")]
+    //
+    #[test_case("next month" => Date::Relative(RelativeDate::Months(1)) ; "next month")]
+    #[test_case("in 2 years" => Date::Relative(RelativeDate::Years(2)) ; "in 2 years")]
+    #[test_case("on dec 24" => Date::Relative(RelativeDate::DayOfMonth { month: Month::December, day: 24 }) ; "on dec 24")]
+    #[test_case("end of week" => Date::Relative(RelativeDate::EndOfWeek) ; "end of week")]
+    #[test_case("end of month" => Date::Relative(RelativeDate::EndOfMonth) ; "end of month")]
     fn relative(input: &str) -> Date {
         Date::parse_unwrap(input)
     }
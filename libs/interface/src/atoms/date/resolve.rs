@@ -1,11 +1,27 @@
-use crate::Date;
+use crate::{Calendar, Date, RelativeDate};
 use chrono::NaiveDate;
 
 impl Date {
-    pub fn resolve(&self, now: NaiveDate) -> NaiveDate {
+    pub fn resolve(&self, now: NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
         match self {
-            Self::Absolute(date) => *date,
-            Self::Relative(date) => date.resolve(now),
+            Self::Absolute(date) => Some(*date),
+            Self::Relative(date) => date.resolve(now, calendar),
+        }
+    }
+
+    /// Returns whether this date points at a specific day (e.g. `on friday`),
+    /// as opposed to being a number of days from now (e.g. `in 3d`)
+    pub(crate) fn is_specific_day(&self) -> bool {
+        match self {
+            Self::Absolute(_) => true,
+
+            Self::Relative(date) => matches!(
+                date,
+                RelativeDate::DayOfWeek(_)
+                    | RelativeDate::DayOfMonth { .. }
+                    | RelativeDate::EndOfWeek
+                    | RelativeDate::EndOfMonth
+            ),
         }
     }
 }
@@ -20,11 +36,11 @@ mod tests {
         #[test]
         fn resolves_it() {
             let actual = Date::Absolute(NaiveDate::from_ymd(2020, 03, 11))
-                .resolve(NaiveDate::from_ymd(2012, 01, 01));
+                .resolve(NaiveDate::from_ymd(2012, 01, 01), &Calendar::default());
 
             let expected = NaiveDate::from_ymd(2020, 03, 11);
 
-            assert_eq!(expected, actual);
+            assert_eq!(Some(expected), actual);
         }
     }
 
@@ -34,12 +50,12 @@ mod tests {
 
         #[test]
         fn resolves_it() {
-            let actual =
-                Date::Relative(RelativeDate::Days(3)).resolve(NaiveDate::from_ymd(2012, 01, 01));
+            let actual = Date::Relative(RelativeDate::Days(3))
+                .resolve(NaiveDate::from_ymd(2012, 01, 01), &Calendar::default());
 
             let expected = NaiveDate::from_ymd(2012, 01, 04);
 
-            assert_eq!(expected, actual);
+            assert_eq!(Some(expected), actual);
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::{Date, Time};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 mod atom;
 mod resolve;
@@ -8,7 +8,7 @@ mod resolve;
 ///
 /// This structure exposes a `.resolve()` function that allows to transform it
 /// into a specific date-time.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct DateTime {
     pub date: Option<Date>,
     pub time: Option<Time>,
@@ -1,10 +1,10 @@
-use crate::{Atom, Date, DateTime, Time};
+use crate::{Atom, Date, DateTime, ParseResult, Time};
 use nom::branch::alt;
 use nom::character::complete::char;
-use nom::{IResult, Parser};
+use nom::Parser;
 
 impl Atom for DateTime {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         let date_and_time = Date::parse
             .and(char(' '))
             .and(Time::parse)
@@ -33,7 +33,7 @@ impl Atom for DateTime {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{DayOfWeek, RelativeDate, RelativeTime};
+    use crate::{DayOfWeek, Month, RelativeDate, RelativeTime};
     use chrono::{NaiveDate, NaiveTime};
     use pretty_assertions as pa;
 
@@ -264,4 +264,96 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn natural_language() {
+        assert(
+            "tomorrow morning",
+            DateTime {
+                date: Some(Date::Relative(RelativeDate::Days(1))),
+                time: Some(Time::Absolute(NaiveTime::from_hms(09, 00, 00))),
+            },
+        );
+
+        assert(
+            "next month",
+            DateTime {
+                date: Some(Date::Relative(RelativeDate::Months(1))),
+                time: None,
+            },
+        );
+
+        for &input in &[
+            "on dec 24 at 3pm",
+            "dec 24th 3 pm",
+            "on december 24 at 15:00",
+        ] {
+            assert(
+                input,
+                DateTime {
+                    date: Some(Date::Relative(RelativeDate::DayOfMonth {
+                        month: Month::December,
+                        day: 24,
+                    })),
+                    time: Some(Time::Absolute(NaiveTime::from_hms(15, 00, 00))),
+                },
+            );
+        }
+
+        assert(
+            "at noon",
+            DateTime {
+                date: None,
+                time: Some(Time::Absolute(NaiveTime::from_hms(12, 00, 00))),
+            },
+        );
+
+        for &input in &["end of week", "at the end of the week"] {
+            assert(
+                input,
+                DateTime {
+                    date: Some(Date::Relative(RelativeDate::EndOfWeek)),
+                    time: None,
+                },
+            );
+        }
+
+        assert(
+            "end of month in the afternoon",
+            DateTime {
+                date: Some(Date::Relative(RelativeDate::EndOfMonth)),
+                time: Some(Time::Absolute(NaiveTime::from_hms(14, 00, 00))),
+            },
+        );
+
+        assert(
+            "in 90 minutes",
+            DateTime {
+                date: None,
+                time: Some(Time::Relative(RelativeTime {
+                    minutes: Some(90),
+                    ..Default::default()
+                })),
+            },
+        );
+
+        assert(
+            "in 2 days 3 hours",
+            DateTime {
+                date: Some(Date::Relative(RelativeDate::Days(2))),
+                time: Some(Time::Relative(RelativeTime {
+                    hours: Some(3),
+                    ..Default::default()
+                })),
+            },
+        );
+
+        assert(
+            "24.12.2020 at midnight",
+            DateTime {
+                date: Some(Date::Absolute(NaiveDate::from_ymd(2020, 12, 24))),
+                time: Some(Time::Absolute(NaiveTime::from_hms(00, 00, 00))),
+            },
+        );
+    }
 }
@@ -1,34 +1,49 @@
-use crate::DateTime;
-use anyhow::*;
-use chrono::{Datelike, Local, NaiveDateTime, Timelike, Utc};
+use crate::{Calendar, DateTime};
+use chrono::{Local, NaiveDateTime, TimeZone, Utc};
 
 impl DateTime {
-    pub fn resolve(&self, mut now: NaiveDateTime) -> NaiveDateTime {
+    /// Resolves this date-time relative to given one.
+    ///
+    /// When the calendar skips non-business days, dates that don't point at a
+    /// specific day (e.g. `in 3d`, as opposed to `on saturday`) get moved to
+    /// the nearest business day.
+    ///
+    /// Returns `None` if the result doesn't fit in `NaiveDateTime` (e.g. for
+    /// `in 99999999 years`).
+    pub fn resolve(&self, mut now: NaiveDateTime, calendar: &Calendar) -> Option<NaiveDateTime> {
         if let Some(date) = &self.date {
-            now = NaiveDateTime::new(date.resolve(now.date()), now.time());
+            now = NaiveDateTime::new(date.resolve(now.date(), calendar)?, now.time());
         }
 
         if let Some(time) = &self.time {
-            now = time.resolve(now);
+            now = time.resolve(now)?;
         }
 
-        now
-    }
+        let is_specific_day = self.date.map_or(false, |date| date.is_specific_day());
 
-    pub fn resolve_utc(&self, now: chrono::DateTime<Local>) -> Result<chrono::DateTime<Utc>> {
-        let resolved = self.resolve(now.naive_local());
+        if calendar.skips_non_business_days() && !is_specific_day {
+            now = NaiveDateTime::new(calendar.next_business_day(now.date())?, now.time());
+        }
 
-        let now: Option<_> = try {
-            now.with_year(resolved.year())?
-                .with_month(resolved.month())?
-                .with_day(resolved.day())?
-                .with_hour(resolved.hour())?
-                .with_minute(resolved.minute())?
-                .with_second(resolved.second())?
-                .with_timezone(&Utc)
-        };
+        Some(now)
+    }
 
-        now.ok_or_else(|| anyhow!("Given datetime resolved to an unrepresentable value"))
+    /// Resolves this date-time relative to given one, in UTC; returns `None`
+    /// if the result can't be represented (e.g. past year 262143).
+    ///
+    /// When the resolved time is ambiguous (e.g. falls into the hour repeated
+    /// when clocks go back), the earlier one is picked.
+    pub fn resolve_utc(
+        &self,
+        now: chrono::DateTime<Local>,
+        calendar: &Calendar,
+    ) -> Option<chrono::DateTime<Utc>> {
+        let resolved = self.resolve(now.naive_local(), calendar)?;
+
+        Local
+            .from_local_datetime(&resolved)
+            .earliest()
+            .map(|resolved| resolved.with_timezone(&Utc))
     }
 }
 
@@ -57,7 +72,8 @@ mod tests {
                     date: Some(Date::Relative(RelativeDate::Days(2))),
                     time: None,
                 }
-                .resolve(now());
+                .resolve(now(), &Calendar::default())
+                .unwrap();
 
                 let expected = NaiveDateTime::new(NaiveDate::from_ymd(2012, 01, 03), now().time());
 
@@ -78,7 +94,8 @@ mod tests {
                         ..Default::default()
                     })),
                 }
-                .resolve(now());
+                .resolve(now(), &Calendar::default())
+                .unwrap();
 
                 let expected = NaiveDateTime::new(now().date(), NaiveTime::from_hms(11, 43, 45));
 
@@ -99,7 +116,8 @@ mod tests {
                         ..Default::default()
                     })),
                 }
-                .resolve(now());
+                .resolve(now(), &Calendar::default())
+                .unwrap();
 
                 let expected = NaiveDateTime::new(
                     NaiveDate::from_ymd(2012, 01, 03),
@@ -110,6 +128,73 @@ mod tests {
             }
         }
 
+        mod given_natural_language {
+            use super::*;
+            use crate::Atom;
+            use test_case::test_case;
+
+            // `now()` is Sunday, 2012-01-01 01:23:45
+            #[test_case("tomorrow morning" => "2012-01-02 09:00:00" ; "tomorrow morning")]
+            #[test_case("at noon" => "2012-01-01 12:00:00" ; "at noon")]
+            #[test_case("in the evening" => "2012-01-01 18:00:00" ; "in the evening")]
+            #[test_case("at 3pm" => "2012-01-01 15:00:00" ; "at 3pm")]
+            #[test_case("in 90 minutes" => "2012-01-01 02:53:45" ; "in 90 minutes")]
+            #[test_case("in 2 days 3 hours" => "2012-01-03 04:23:45" ; "in 2 days 3 hours")]
+            #[test_case("next month" => "2012-02-01 01:23:45" ; "next month")]
+            #[test_case("next year" => "2013-01-01 01:23:45" ; "next year")]
+            #[test_case("on dec 24 at 3pm" => "2012-12-24 15:00:00" ; "on dec 24 at 3pm")]
+            #[test_case("end of week" => "2012-01-06 01:23:45" ; "end of week")]
+            #[test_case("end of month at 5pm" => "2012-01-31 17:00:00" ; "end of month at 5pm")]
+            #[test_case("24.12.2020 at midnight" => "2020-12-24 00:00:00" ; "24.12.2020 at midnight")]
+            fn resolves_it(input: &str) -> String {
+                DateTime::parse_unwrap(input)
+                    .resolve(now(), &Calendar::default())
+                    .unwrap()
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            }
+        }
+
+        mod given_calendar_skipping_non_business_days {
+            use super::*;
+            use crate::Atom;
+            use test_case::test_case;
+
+            // `now()` is Sunday, 2012-01-01 01:23:45 (a holiday in Poland),
+            // and 2012-01-06 is Epiphany (another holiday)
+            #[test_case("in 3h" => "2012-01-02 04:23:45" ; "moves relative time")]
+            #[test_case("in 5d" => "2012-01-09 01:23:45" ; "moves relative date")]
+            #[test_case("in 2d at 12:00" => "2012-01-03 12:00:00" ; "keeps business day")]
+            #[test_case("next workday" => "2012-01-02 01:23:45" ; "business days")]
+            #[test_case("on saturday" => "2012-01-07 01:23:45" ; "keeps specific day of week")]
+            #[test_case("2012-01-06" => "2012-01-06 01:23:45" ; "keeps specific date")]
+            fn resolves_it(input: &str) -> String {
+                let calendar = Calendar::default()
+                    .with_country("PL")
+                    .unwrap()
+                    .with_skip_non_business_days(true);
+
+                DateTime::parse_unwrap(input)
+                    .resolve(now(), &calendar)
+                    .unwrap()
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            }
+        }
+
+        mod given_datetime_too_far_in_the_future {
+            use super::*;
+            use test_case::test_case;
+
+            #[test_case(Some(Date::Relative(RelativeDate::Days(usize::MAX))), None ; "date")]
+            #[test_case(None, Some(Time::Relative(RelativeTime { hours: Some(usize::MAX), ..Default::default() })) ; "time")]
+            fn returns_none(date: Option<Date>, time: Option<Time>) {
+                let actual = DateTime { date, time }.resolve(now(), &Calendar::default());
+
+                assert_eq!(None, actual);
+            }
+        }
+
         mod given_empty_datetime {
             use super::*;
 
@@ -119,7 +204,8 @@ mod tests {
                     date: None,
                     time: None,
                 }
-                .resolve(now());
+                .resolve(now(), &Calendar::default())
+                .unwrap();
 
                 let expected = now();
 
@@ -146,7 +232,7 @@ mod tests {
                         ..Default::default()
                     })),
                 }
-                .resolve_utc(now)
+                .resolve_utc(now, &Calendar::default())
                 .unwrap();
 
                 let expected = now + Duration::hours(3);
@@ -167,7 +253,7 @@ mod tests {
                     date: None,
                     time: Some(Time::Absolute(NaiveTime::from_hms(12, 34, 56))),
                 }
-                .resolve_utc(now)
+                .resolve_utc(now, &Calendar::default())
                 .unwrap();
 
                 let expected = now
@@ -176,10 +262,38 @@ mod tests {
                     .with_minute(34)
                     .unwrap()
                     .with_second(56)
+                    .unwrap()
+                    .with_nanosecond(0)
                     .unwrap();
 
                 assert_eq!(expected, actual);
             }
         }
+
+        mod given_datetime_crossing_month_boundary {
+            use super::*;
+            use crate::Atom;
+            use chrono::Datelike;
+            use test_case::test_case;
+
+            // Since February has no 31st, these cannot be resolved by adjusting
+            // `now` field-by-field
+            #[test_case("next month" => (2021, 2, 28) ; "next month")]
+            #[test_case("in 1d" => (2021, 2, 1) ; "in 1d")]
+            #[test_case("on feb 3" => (2021, 2, 3) ; "on feb 3")]
+            fn resolves_it(input: &str) -> (i32, u32, u32) {
+                let now = Local.ymd(2021, 1, 31).and_hms(10, 0, 0);
+
+                let actual = DateTime::parse_unwrap(input)
+                    .resolve_utc(now, &Calendar::default())
+                    .unwrap();
+
+                let date = actual.with_timezone(&Local).date();
+
+                assert_eq!(now.time(), actual.with_timezone(&Local).time());
+
+                (date.year(), date.month(), date.day())
+            }
+        }
     }
 }
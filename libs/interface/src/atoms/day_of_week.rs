@@ -1,11 +1,11 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 mod atom;
 
 /// A day of week, e.g. `sunday`.
 ///
 /// Used as a part of the `date` component of the `DateTime` atom.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum DayOfWeek {
     Monday,
     Tuesday,
@@ -17,6 +17,20 @@ pub enum DayOfWeek {
 }
 
 impl DayOfWeek {
+    /// Creates day from its ISO 8601 weekday number (`1` for Monday).
+    pub fn from_number_from_monday(number: u32) -> Option<Self> {
+        match number {
+            1 => Some(Self::Monday),
+            2 => Some(Self::Tuesday),
+            3 => Some(Self::Wednesday),
+            4 => Some(Self::Thursday),
+            5 => Some(Self::Friday),
+            6 => Some(Self::Saturday),
+            7 => Some(Self::Sunday),
+            _ => None,
+        }
+    }
+
     /// Returns ISO 8601 weekday number.
     pub fn number_from_monday(self) -> u32 {
         match self {
@@ -1,11 +1,10 @@
-use crate::{Atom, DayOfWeek};
+use crate::{Atom, DayOfWeek, ParseResult};
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::combinator::value;
-use nom::IResult;
 
 impl Atom for DayOfWeek {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         alt((
             value(Self::Monday, tag_no_case("monday")),
             value(Self::Tuesday, tag_no_case("tuesday")),
@@ -0,0 +1,22 @@
+use crate::{DayOfWeek, Timezone};
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+
+mod atom;
+mod resolve;
+
+/// How often user wants to get their digest, e.g. `daily at 9:00` or `weekly
+/// on friday at 8am Europe/Warsaw`.
+///
+/// Used as a part of the `digest me` command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct DigestSchedule {
+    /// Day the digest should be sent on; `None` for daily digests
+    pub day: Option<DayOfWeek>,
+
+    /// Time of day the digest should be sent at
+    pub time: NaiveTime,
+
+    /// Timezone `time` refers to; `None` means Janet's local timezone
+    pub timezone: Option<Timezone>,
+}
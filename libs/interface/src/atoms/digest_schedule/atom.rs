@@ -0,0 +1,101 @@
+use crate::{Atom, DayOfWeek, DigestSchedule, ParseError, ParseResult, Time, Timezone};
+use chrono::NaiveTime;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::space1;
+use nom::combinator::{opt, value};
+use nom::sequence::preceded;
+use nom::Parser;
+
+impl Atom for DigestSchedule {
+    fn parse(i: &str) -> ParseResult<Self> {
+        let (i, day) = alt((
+            value(None, alt((tag_no_case("daily"), tag_no_case("every day")))),
+            preceded(
+                tag_no_case("weekly"),
+                opt(preceded(tag_no_case(" on "), DayOfWeek::parse)),
+            )
+            .map(|day| Some(day.unwrap_or(DayOfWeek::Monday))),
+            preceded(tag_no_case("every "), DayOfWeek::parse).map(Some),
+        ))(i)?;
+
+        let (i, time) = opt(preceded(space1, time_of_day))(i)?;
+        let (i, timezone) = opt(preceded(space1, Timezone::parse))(i)?;
+
+        Ok((
+            i,
+            DigestSchedule {
+                day,
+                time: time.unwrap_or_else(|| NaiveTime::from_hms(9, 0, 0)),
+                timezone,
+            },
+        ))
+    }
+}
+
+fn time_of_day(i: &str) -> ParseResult<NaiveTime> {
+    let (o, time) = Time::parse(i)?;
+
+    match time {
+        Time::Absolute(time) => Ok((o, time)),
+        Time::Relative(_) => ParseError::fail(i, "digest has to be sent at a specific time"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn schedule(day: Option<DayOfWeek>, hour: u32, minute: u32) -> DigestSchedule {
+        DigestSchedule {
+            day,
+            time: NaiveTime::from_hms(hour, minute, 0),
+            timezone: None,
+        }
+    }
+
+    #[test_case("daily" => schedule(None, 9, 0) ; "daily")]
+    #[test_case("every day" => schedule(None, 9, 0) ; "every day")]
+    #[test_case("daily at 8:30" => schedule(None, 8, 30) ; "daily at time")]
+    #[test_case("daily in the morning" => schedule(None, 9, 0) ; "daily in the morning")]
+    #[test_case("weekly" => schedule(Some(DayOfWeek::Monday), 9, 0) ; "weekly")]
+    #[test_case("weekly on friday at 3pm" => schedule(Some(DayOfWeek::Friday), 15, 0) ; "weekly on day at time")]
+    #[test_case("every tuesday at noon" => schedule(Some(DayOfWeek::Tuesday), 12, 0) ; "every day of week")]
+    fn without_timezone(input: &str) -> DigestSchedule {
+        DigestSchedule::parse_unwrap(input)
+    }
+
+    #[test]
+    fn with_timezone() {
+        let actual = DigestSchedule::parse_unwrap("daily at 9:00 Europe/Warsaw");
+
+        let expected = DigestSchedule {
+            timezone: Some("Europe/Warsaw".parse().unwrap()),
+            ..schedule(None, 9, 0)
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn with_timezone_but_without_time() {
+        let actual = DigestSchedule::parse_unwrap("weekly UTC");
+
+        let expected = DigestSchedule {
+            timezone: Some("UTC".parse().unwrap()),
+            ..schedule(Some(DayOfWeek::Monday), 9, 0)
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn with_relative_time() {
+        let actual = DigestSchedule::parse("daily in 3h");
+
+        let expected = ParseError::fail("in 3h", "digest has to be sent at a specific time");
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::DigestSchedule;
+use chrono::{DateTime, Datelike, Duration, Local, TimeZone, Utc};
+
+impl DigestSchedule {
+    /// Returns the first moment after `now` when the digest should be sent.
+    pub fn next_after(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self.timezone {
+            Some(timezone) => self.next_after_in(&timezone.tz(), now),
+            None => self.next_after_in(&Local, now),
+        }
+    }
+
+    fn next_after_in<Tz: TimeZone>(&self, tz: &Tz, now: DateTime<Utc>) -> DateTime<Utc> {
+        let today = now.with_timezone(tz).date().naive_local();
+
+        // A week is always enough to find the matching day - we're checking a
+        // few more in case the time doesn't exist on that day (e.g. it falls
+        // into a DST gap)
+        (0..14)
+            .map(|days| today + Duration::days(days))
+            .filter(|date| {
+                self.day.map_or(true, |day| {
+                    date.weekday().number_from_monday() == day.number_from_monday()
+                })
+            })
+            .filter_map(|date| tz.from_local_datetime(&date.and_time(self.time)).earliest())
+            .map(|at| at.with_timezone(&Utc))
+            .find(|at| *at > now)
+            .expect("Couldn't find digest's next date")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DayOfWeek;
+    use chrono::NaiveTime;
+    use test_case::test_case;
+
+    /// Wednesday, 2018-01-03 10:00 UTC
+    fn now() -> DateTime<Utc> {
+        Utc.ymd(2018, 1, 3).and_hms(10, 0, 0)
+    }
+
+    fn schedule(day: Option<DayOfWeek>, hour: u32, timezone: &str) -> DigestSchedule {
+        DigestSchedule {
+            day,
+            time: NaiveTime::from_hms(hour, 0, 0),
+            timezone: Some(timezone.parse().unwrap()),
+        }
+    }
+
+    #[test_case(schedule(None, 12, "UTC") => Utc.ymd(2018, 1, 3).and_hms(12, 0, 0) ; "daily, later today")]
+    #[test_case(schedule(None, 9, "UTC") => Utc.ymd(2018, 1, 4).and_hms(9, 0, 0) ; "daily, already sent today")]
+    #[test_case(schedule(None, 10, "UTC") => Utc.ymd(2018, 1, 4).and_hms(10, 0, 0) ; "daily, right now")]
+    #[test_case(schedule(None, 9, "Europe/Warsaw") => Utc.ymd(2018, 1, 4).and_hms(8, 0, 0) ; "daily, in other timezone")]
+    #[test_case(schedule(None, 9, "America/New_York") => Utc.ymd(2018, 1, 3).and_hms(14, 0, 0) ; "daily, in timezone behind utc")]
+    #[test_case(schedule(Some(DayOfWeek::Friday), 9, "UTC") => Utc.ymd(2018, 1, 5).and_hms(9, 0, 0) ; "weekly, later this week")]
+    #[test_case(schedule(Some(DayOfWeek::Monday), 9, "UTC") => Utc.ymd(2018, 1, 8).and_hms(9, 0, 0) ; "weekly, next week")]
+    #[test_case(schedule(Some(DayOfWeek::Wednesday), 9, "UTC") => Utc.ymd(2018, 1, 10).and_hms(9, 0, 0) ; "weekly, already sent this week")]
+    fn test(schedule: DigestSchedule) -> DateTime<Utc> {
+        schedule.next_after(now())
+    }
+}
@@ -1,10 +1,9 @@
-use crate::Atom;
+use crate::{Atom, ParseResult};
 use lib_gitlab::MergeRequestIid;
 use nom::combinator::map;
-use nom::IResult;
 
 impl Atom for MergeRequestIid {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         map(usize::parse, Self::new)(i)
     }
 }
@@ -1,6 +1,6 @@
 use crate::ProjectPtr;
 use lib_gitlab::MergeRequestIid;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 mod atom;
@@ -10,7 +10,7 @@ mod resolve;
 ///
 /// This structure exposes a `.resolve()` function that allows to transform it
 /// into specific project & merge request ids.
-#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum MergeRequestPtr {
     /// E.g. `!123` or `foo!123`
@@ -1,17 +1,17 @@
-use crate::{Atom, MergeRequestPtr, ProjectPtr};
+use crate::{Atom, MergeRequestPtr, ParseResult, ProjectPtr};
 use lib_gitlab::MergeRequestIid;
 use nom::branch::alt;
 use nom::character::complete::char;
 use nom::combinator::opt;
-use nom::{IResult, Parser};
+use nom::Parser;
 
 impl Atom for MergeRequestPtr {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         alt((id, url))(i)
     }
 }
 
-fn id(i: &str) -> IResult<&str, MergeRequestPtr> {
+fn id(i: &str) -> ParseResult<MergeRequestPtr> {
     opt(ProjectPtr::parse)
         .and(char('!'))
         .and(MergeRequestIid::parse)
@@ -22,7 +22,7 @@ fn id(i: &str) -> IResult<&str, MergeRequestPtr> {
         .parse(i)
 }
 
-fn url(i: &str) -> IResult<&str, MergeRequestPtr> {
+fn url(i: &str) -> ParseResult<MergeRequestPtr> {
     Atom::parse.map(MergeRequestPtr::Url).parse(i)
 }
 
@@ -31,25 +31,161 @@ impl MergeRequestPtr {
                 }
 
                 Self::Url(url) => {
-                    let url = url.path().to_lowercase();
-                    let merge_requests = gitlab.merge_requests().await?;
-
-                    // This is suboptimal at best, but seems like GitLab doesn't allow to search
-                    // merge requests by web_url via API
-                    //
-                    // TODO check if this is really the only reliable way to approach this
-                    for merge_request in merge_requests {
-                        if let Ok(mr_url) = Url::parse(&merge_request.web_url) {
-                            if mr_url.path().to_lowercase() == url {
-                                return Ok((merge_request.project_id, merge_request.iid));
-                            }
-                        }
-                    }
-
-                    bail!("Found mo merge requests matching given URL");
+                    let (project, merge_request) = parse_url(gitlab.url(), url)?;
+                    let project = gitlab.project(&project).await?;
+
+                    (project.id, merge_request)
                 }
             }
         }: Result<_>)
             .with_context(|| format!("Couldn't resolve merge request ptr: {:?}", self))
     }
 }
+
+/// Extracts project's path & merge request's iid from merge request's URL, e.g.
+/// `https://gitlab.com/alpha/beta/project/-/merge_requests/123/diffs` gives
+/// `("alpha/beta/project", 123)`.
+///
+/// Both the current (`.../-/merge_requests/...`) and the legacy
+/// (`.../merge_requests/...`) formats are supported; `gitlab` is the address of
+/// our GitLab instance - URLs pointing anywhere else are rejected.
+fn parse_url(gitlab: &Url, url: &Url) -> Result<(String, MergeRequestIid)> {
+    if url.host_str() != gitlab.host_str() || url.port() != gitlab.port() {
+        bail!("URL points at a different GitLab instance than the configured one");
+    }
+
+    // GitLab can be hosted under a sub-path (e.g. `https://example.com/gitlab`),
+    // which has to be skipped before we get to the project's path
+    let base: Vec<_> = segments(gitlab).collect();
+    let segments: Vec<_> = segments(url).collect();
+
+    let segments = segments.strip_prefix(base.as_slice()).ok_or_else(|| {
+        anyhow!("URL points at a different GitLab instance than the configured one")
+    })?;
+
+    let (idx, merge_request) = segments
+        .windows(2)
+        .enumerate()
+        .find_map(|(idx, window)| {
+            if window[0] != "merge_requests" {
+                return None;
+            }
+
+            let merge_request = window[1].parse().ok()?;
+
+            Some((idx, MergeRequestIid::new(merge_request)))
+        })
+        .ok_or_else(|| anyhow!("URL doesn't point at a merge request"))?;
+
+    let mut project = &segments[..idx];
+
+    if let Some((&"-", rest)) = project.split_last() {
+        project = rest;
+    }
+
+    // Projects always belong to some namespace (a user or a group)
+    if project.len() < 2 {
+        bail!("URL doesn't contain project's path");
+    }
+
+    Ok((project.join("/"), merge_request))
+}
+
+fn segments(url: &Url) -> impl Iterator<Item = &str> {
+    url.path_segments()
+        .into_iter()
+        .flatten()
+        .filter(|segment| !segment.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_gitlab::mock::project_10;
+    use std::str::FromStr;
+
+    fn url(url: &str) -> Url {
+        Url::from_str(url).unwrap()
+    }
+
+    mod parse_url {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case("https://gitlab.com/", "https://gitlab.com/alpha/project/-/merge_requests/123", "alpha/project", 123 ; "project in namespace")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com/alpha/beta/gamma/project/-/merge_requests/123", "alpha/beta/gamma/project", 123 ; "project in subgroups")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com/alpha/project/merge_requests/123", "alpha/project", 123 ; "legacy url")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com/alpha/project/-/merge_requests/123/diffs", "alpha/project", 123 ; "url with tab")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com/alpha/project/-/merge_requests/123#note_1", "alpha/project", 123 ; "url with fragment")]
+        #[test_case("https://gitlab.com", "http://gitlab.com/alpha/project/-/merge_requests/123", "alpha/project", 123 ; "url with different scheme")]
+        #[test_case("https://example.com/gitlab/", "https://example.com/gitlab/alpha/project/-/merge_requests/123", "alpha/project", 123 ; "gitlab under sub-path")]
+        fn accepts(gitlab: &str, input: &str, project: &str, merge_request: usize) {
+            let actual = parse_url(&url(gitlab), &url(input)).unwrap();
+
+            assert_eq!(
+                (project.to_string(), MergeRequestIid::new(merge_request)),
+                actual
+            );
+        }
+
+        #[test_case("https://gitlab.com/", "https://gitlab.example.com/alpha/project/-/merge_requests/123" ; "different host")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com:8080/alpha/project/-/merge_requests/123" ; "different port")]
+        #[test_case("https://example.com/gitlab/", "https://example.com/alpha/project/-/merge_requests/123" ; "missing sub-path")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com/alpha/project/-/issues/123" ; "issue")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com/alpha/project/-/merge_requests" ; "missing iid")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com/alpha/project/-/merge_requests/new" ; "invalid iid")]
+        #[test_case("https://gitlab.com/", "https://gitlab.com/project/-/merge_requests/123" ; "missing namespace")]
+        fn rejects(gitlab: &str, input: &str) {
+            assert!(parse_url(&url(gitlab), &url(input)).is_err());
+        }
+    }
+
+    mod resolve {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn given_url_of_existing_merge_request_returns_its_ids() {
+            let (server, gitlab) = GitLabClient::mock().await;
+
+            server
+                .expect_project_by_path("alpha/beta/project", &project_10())
+                .await;
+
+            let ptr = MergeRequestPtr::Url(
+                gitlab
+                    .url()
+                    .join("alpha/beta/project/-/merge_requests/123")
+                    .unwrap(),
+            );
+
+            let actual = ptr.resolve(&gitlab, &Default::default()).await.unwrap();
+
+            assert_eq!((ProjectId::new(10), MergeRequestIid::new(123)), actual);
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn given_url_of_unknown_project_fails() {
+            let (_server, gitlab) = GitLabClient::mock().await;
+
+            let ptr = MergeRequestPtr::Url(
+                gitlab
+                    .url()
+                    .join("alpha/beta/project/-/merge_requests/123")
+                    .unwrap(),
+            );
+
+            assert!(ptr.resolve(&gitlab, &Default::default()).await.is_err());
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn given_url_of_different_instance_fails_without_asking_gitlab() {
+            let (_server, gitlab) = GitLabClient::mock().await;
+
+            let ptr = MergeRequestPtr::Url(url(
+                "https://gitlab.example.com/alpha/project/-/merge_requests/123",
+            ));
+
+            assert!(ptr.resolve(&gitlab, &Default::default()).await.is_err());
+        }
+    }
+}
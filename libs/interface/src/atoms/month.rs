@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+mod atom;
+
+/// A month, e.g. `december` or `dec`.
+///
+/// Used as a part of the `date` component of the `DateTime` atom.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    /// Returns month's number, starting from 1 for January.
+    pub fn number(self) -> u32 {
+        match self {
+            Self::January => 1,
+            Self::February => 2,
+            Self::March => 3,
+            Self::April => 4,
+            Self::May => 5,
+            Self::June => 6,
+            Self::July => 7,
+            Self::August => 8,
+            Self::September => 9,
+            Self::October => 10,
+            Self::November => 11,
+            Self::December => 12,
+        }
+    }
+
+    /// Returns the maximum number of days this month can have (i.e. 29 for
+    /// February).
+    pub fn max_days(self) -> u32 {
+        match self {
+            Self::February => 29,
+            Self::April | Self::June | Self::September | Self::November => 30,
+            _ => 31,
+        }
+    }
+}
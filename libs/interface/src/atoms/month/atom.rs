@@ -0,0 +1,92 @@
+use crate::{Atom, Month, ParseResult};
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::combinator::value;
+
+impl Atom for Month {
+    fn parse(i: &str) -> ParseResult<Self> {
+        alt((
+            value(
+                Self::January,
+                alt((tag_no_case("january"), tag_no_case("jan"))),
+            ),
+            value(
+                Self::February,
+                alt((tag_no_case("february"), tag_no_case("feb"))),
+            ),
+            value(Self::March, alt((tag_no_case("march"), tag_no_case("mar")))),
+            value(Self::April, alt((tag_no_case("april"), tag_no_case("apr")))),
+            value(Self::May, tag_no_case("may")),
+            value(Self::June, alt((tag_no_case("june"), tag_no_case("jun")))),
+            value(Self::July, alt((tag_no_case("july"), tag_no_case("jul")))),
+            value(
+                Self::August,
+                alt((tag_no_case("august"), tag_no_case("aug"))),
+            ),
+            value(
+                Self::September,
+                alt((
+                    tag_no_case("september"),
+                    tag_no_case("sept"),
+                    tag_no_case("sep"),
+                )),
+            ),
+            value(
+                Self::October,
+                alt((tag_no_case("october"), tag_no_case("oct"))),
+            ),
+            value(
+                Self::November,
+                alt((tag_no_case("november"), tag_no_case("nov"))),
+            ),
+            value(
+                Self::December,
+                alt((tag_no_case("december"), tag_no_case("dec"))),
+            ),
+        ))(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("january" => Month::January ; "january")]
+    #[test_case("february" => Month::February ; "february")]
+    #[test_case("march" => Month::March ; "march")]
+    #[test_case("april" => Month::April ; "april")]
+    #[test_case("may" => Month::May ; "may")]
+    #[test_case("june" => Month::June ; "june")]
+    #[test_case("july" => Month::July ; "july")]
+    #[test_case("august" => Month::August ; "august")]
+    #[test_case("september" => Month::September ; "september")]
+    #[test_case("october" => Month::October ; "october")]
+    #[test_case("november" => Month::November ; "november")]
+    #[test_case("december" => Month::December ; "december")]
+    fn full(input: &str) -> Month {
+        Atom::parse_unwrap(input)
+    }
+
+    #[test_case("jan" => Month::January ; "jan")]
+    #[test_case("feb" => Month::February ; "feb")]
+    #[test_case("mar" => Month::March ; "mar")]
+    #[test_case("apr" => Month::April ; "apr")]
+    #[test_case("jun" => Month::June ; "jun")]
+    #[test_case("jul" => Month::July ; "jul")]
+    #[test_case("aug" => Month::August ; "aug")]
+    #[test_case("sep" => Month::September ; "sep")]
+    #[test_case("sept" => Month::September ; "sept")]
+    #[test_case("oct" => Month::October ; "oct")]
+    #[test_case("nov" => Month::November ; "nov")]
+    #[test_case("dec" => Month::December ; "dec")]
+    fn abbreviated(input: &str) -> Month {
+        Atom::parse_unwrap(input)
+    }
+
+    #[test_case("January" => Month::January ; "january")]
+    #[test_case("DEC" => Month::December ; "dec")]
+    fn mixed_case(input: &str) -> Month {
+        Atom::parse_unwrap(input)
+    }
+}
@@ -1,10 +1,9 @@
-use crate::{Atom, Name};
+use crate::{Atom, Name, ParseResult};
 use nom::bytes::complete::take_while1;
 use nom::combinator::map;
-use nom::IResult;
 
 impl Atom for Name {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         map(
             take_while1(|c: char| c.is_alphanumeric() || c == '-' || c == '_'),
             Self::new,
@@ -1,9 +1,9 @@
 use lib_gitlab::{NamespaceId, NamespaceName};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 mod resolve;
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum NamespacePtr {
     Id(NamespaceId),
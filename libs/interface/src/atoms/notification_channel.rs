@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+mod atom;
+
+/// A channel through which user wants to get notified, e.g. `slack`.
+///
+/// Used as a part of the `notify me via` command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum NotificationChannel {
+    GitLab,
+    Webhook,
+    Slack,
+    Matrix,
+}
+
+impl NotificationChannel {
+    /// Returns channel's name, as written by users (e.g. `slack`)
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::GitLab => "gitlab",
+            Self::Webhook => "webhook",
+            Self::Slack => "slack",
+            Self::Matrix => "matrix",
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{Atom, NotificationChannel, ParseResult};
+use anyhow::*;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::combinator::{all_consuming, value};
+use std::str::FromStr;
+
+impl Atom for NotificationChannel {
+    fn parse(i: &str) -> ParseResult<Self> {
+        alt((
+            value(Self::GitLab, tag_no_case("gitlab")),
+            value(Self::Webhook, tag_no_case("webhook")),
+            value(Self::Slack, tag_no_case("slack")),
+            value(Self::Matrix, tag_no_case("matrix")),
+        ))(i)
+    }
+}
+
+impl FromStr for NotificationChannel {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        all_consuming(Self::parse)(s)
+            .map(|(_, this)| this)
+            .map_err(|_| anyhow!("Unknown notification channel: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("gitlab" => NotificationChannel::GitLab ; "gitlab")]
+    #[test_case("webhook" => NotificationChannel::Webhook ; "webhook")]
+    #[test_case("slack" => NotificationChannel::Slack ; "slack")]
+    #[test_case("matrix" => NotificationChannel::Matrix ; "matrix")]
+    fn lower_case(input: &str) -> NotificationChannel {
+        Atom::parse_unwrap(input)
+    }
+
+    #[test_case("GitLab" => NotificationChannel::GitLab ; "gitlab")]
+    #[test_case("Webhook" => NotificationChannel::Webhook ; "webhook")]
+    #[test_case("Slack" => NotificationChannel::Slack ; "slack")]
+    #[test_case("Matrix" => NotificationChannel::Matrix ; "matrix")]
+    fn mixed_case(input: &str) -> NotificationChannel {
+        Atom::parse_unwrap(input)
+    }
+
+    #[test_case(NotificationChannel::GitLab ; "gitlab")]
+    #[test_case(NotificationChannel::Webhook ; "webhook")]
+    #[test_case(NotificationChannel::Slack ; "slack")]
+    #[test_case(NotificationChannel::Matrix ; "matrix")]
+    fn round_trips_through_str(channel: NotificationChannel) {
+        assert_eq!(channel, channel.as_str().parse().unwrap());
+    }
+
+    #[test]
+    fn rejects_unknown_channels() {
+        assert!("email".parse::<NotificationChannel>().is_err());
+    }
+}
@@ -1,10 +1,9 @@
-use crate::{Atom, Name};
+use crate::{Atom, Name, ParseResult};
 use lib_gitlab::ProjectName;
 use nom::combinator::map;
-use nom::IResult;
 
 impl Atom for ProjectName {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         map(Name::parse, |name| Self::new(name.into_inner()))(i)
     }
 }
@@ -1,11 +1,11 @@
 use crate::NamespacePtr;
 use lib_gitlab::{ProjectId, ProjectName};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 mod atom;
 mod resolve;
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum ProjectPtr {
     Id(ProjectId),
@@ -1,22 +1,21 @@
-use crate::{Atom, Name, NamespacePtr, ProjectPtr};
+use crate::{Atom, Name, NamespacePtr, ParseResult, ProjectPtr};
 use lib_gitlab::{NamespaceName, ProjectId, ProjectName};
 use nom::branch::alt;
 use nom::character::complete::char;
 use nom::combinator::map;
 use nom::multi::separated_list1;
-use nom::IResult;
 
 impl Atom for ProjectPtr {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         alt((id, name))(i)
     }
 }
 
-fn id(i: &str) -> IResult<&str, ProjectPtr> {
+fn id(i: &str) -> ParseResult<ProjectPtr> {
     map(ProjectId::parse, ProjectPtr::Id)(i)
 }
 
-fn name(i: &str) -> IResult<&str, ProjectPtr> {
+fn name(i: &str) -> ParseResult<ProjectPtr> {
     let (i, mut path) = separated_list1(char('/'), Name::parse)(i)?;
 
     let (namespace, name) = if path.len() == 1 {
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+mod atom;
+
+/// Someone who should get reminded, e.g. `me` or `@frontend-team`.
+///
+/// Used as a part of the `remind` command.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum Recipient {
+    /// E.g. `me` - the user who issued the command
+    Me,
+
+    /// E.g. `@someone` or `@frontend-team` - user or group, as mentioned on
+    /// GitLab (without the `@`); whether it's a user or a group is known only
+    /// after asking GitLab
+    Mention(String),
+
+    /// E.g. `reviewers` - merge request's reviewers
+    Reviewers,
+}
@@ -0,0 +1,46 @@
+use crate::{Atom, ParseResult, Recipient};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case, take_while1};
+use nom::combinator::{map, value};
+use nom::sequence::preceded;
+
+impl Atom for Recipient {
+    fn parse(i: &str) -> ParseResult<Self> {
+        alt((
+            value(Self::Me, tag_no_case("me")),
+            value(Self::Reviewers, tag_no_case("reviewers")),
+            map(
+                preceded(
+                    tag("@"),
+                    take_while1(|c: char| {
+                        c.is_alphanumeric() || c == '-' || c == '_' || c == '.' || c == '/'
+                    }),
+                ),
+                |name: &str| Self::Mention(name.to_string()),
+            ),
+        ))(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("me" => Recipient::Me ; "me")]
+    #[test_case("Me" => Recipient::Me ; "me mixed case")]
+    #[test_case("reviewers" => Recipient::Reviewers ; "reviewers")]
+    #[test_case("@someone" => Recipient::Mention("someone".into()) ; "user")]
+    #[test_case("@john.doe" => Recipient::Mention("john.doe".into()) ; "user with dot")]
+    #[test_case("@frontend-team" => Recipient::Mention("frontend-team".into()) ; "group")]
+    #[test_case("@alpha/frontend_team" => Recipient::Mention("alpha/frontend_team".into()) ; "subgroup")]
+    fn test(input: &str) -> Recipient {
+        Recipient::parse_unwrap(input)
+    }
+
+    #[test_case("@" ; "empty mention")]
+    #[test_case("someone" ; "mention without at")]
+    fn rejects(input: &str) {
+        assert!(Recipient::parse(input).is_err());
+    }
+}
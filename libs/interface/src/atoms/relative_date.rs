@@ -1,5 +1,5 @@
-use crate::DayOfWeek;
-use serde::Serialize;
+use crate::{DayOfWeek, Month};
+use serde::{Deserialize, Serialize};
 
 mod atom;
 mod resolve;
@@ -7,14 +7,14 @@ mod resolve;
 /// A relative date, e.g. `the day after tomorrow`.
 ///
 /// Used as a part of the `date` component of the `DateTime` atom.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum RelativeDate {
     /// Today + `n` days; e.g.:
     ///
     /// - `today` as `Days(0)`,
     /// - `tomorrow` as `Days(1)`,
     /// - `the day after tomorrow` as `Days(2)`,
-    /// - `in 123 days` as `Days(123)`.
+    /// - `in 123d` / `in 123 days` as `Days(123)`.
     Days(usize),
 
     /// Nearest day of week; e.g.:
@@ -23,10 +23,47 @@ pub enum RelativeDate {
     /// - `friday`.
     DayOfWeek(DayOfWeek),
 
+    /// Today + `n` business days (see: `Calendar`); e.g.:
+    ///
+    /// - `next workday` as `BusinessDays(1)`,
+    /// - `in 2 business days` as `BusinessDays(2)`.
+    BusinessDays(usize),
+
     /// Today + `n` weeks; e.g.:
     ///
     /// - `next week` as `Weeks(1)`,
     /// - `in 1 week` as `Weeks(1)`,
     /// - `in 2 weeks` as `Weeks(2)`.
     Weeks(usize),
+
+    /// Today + `n` months (clamped to the last day of the target month, if
+    /// needed); e.g.:
+    ///
+    /// - `next month` as `Months(1)`,
+    /// - `in 2 months` as `Months(2)`.
+    Months(usize),
+
+    /// Today + `n` years; e.g.:
+    ///
+    /// - `next year` as `Years(1)`,
+    /// - `in 2 years` as `Years(2)`.
+    Years(usize),
+
+    /// Nearest given day of given month (today included); e.g.:
+    ///
+    /// - `on dec 24`,
+    /// - `december 24th`.
+    DayOfMonth { month: Month, day: u32 },
+
+    /// Nearest Friday (today included); e.g.:
+    ///
+    /// - `end of week`,
+    /// - `at the end of the week`.
+    EndOfWeek,
+
+    /// Last day of current month; e.g.:
+    ///
+    /// - `end of month`,
+    /// - `at the end of the month`.
+    EndOfMonth,
 }
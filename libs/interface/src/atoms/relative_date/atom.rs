@@ -1,48 +1,176 @@
-use crate::{Atom, DayOfWeek, RelativeDate};
+use crate::{Atom, DayOfWeek, Month, ParseError, ParseResult, RelativeDate};
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
+use nom::character::complete::{space0, space1};
 use nom::combinator::{opt, value};
-use nom::{IResult, Parser};
+use nom::Parser;
+
+/// How far into the future relative dates can reach; it's way more than anyone
+/// needs, and it keeps resolving them far from overflowing
+const MAX_YEARS: usize = 100;
 
 impl Atom for RelativeDate {
-    fn parse(i: &str) -> IResult<&str, Self> {
-        alt((day, day_of_week, week))(i)
+    fn parse(i: &str) -> ParseResult<Self> {
+        alt((
+            day,
+            day_of_week,
+            business_day,
+            week,
+            month,
+            year,
+            day_of_month,
+            end_of,
+        ))(i)
     }
 }
 
-fn day(i: &str) -> IResult<&str, RelativeDate> {
+fn day(i: &str) -> ParseResult<RelativeDate> {
     let predefined = alt((
         value(RelativeDate::Days(0), tag_no_case("today")),
         value(RelativeDate::Days(1), tag_no_case("tomorrow")),
         value(RelativeDate::Days(2), tag_no_case("the day after tomorrow")),
     ));
 
-    let dynamic = opt(tag_no_case("in "))
-        .and(usize::parse)
-        .and(tag_no_case("d"))
-        .map(|((_, days), _)| RelativeDate::Days(days));
+    let dynamic = amount(
+        MAX_YEARS * 366,
+        alt((tag_no_case("days"), tag_no_case("day"), tag_no_case("d"))),
+    )
+    .map(RelativeDate::Days);
 
     alt((predefined, dynamic))(i)
 }
 
-fn day_of_week(i: &str) -> IResult<&str, RelativeDate> {
+fn day_of_week(i: &str) -> ParseResult<RelativeDate> {
     opt(tag_no_case("on "))
         .and(DayOfWeek::parse)
         .map(|(_, weekday)| RelativeDate::DayOfWeek(weekday))
         .parse(i)
 }
 
-fn week(i: &str) -> IResult<&str, RelativeDate> {
+fn business_day(i: &str) -> ParseResult<RelativeDate> {
+    let predefined = value(
+        RelativeDate::BusinessDays(1),
+        alt((
+            tag_no_case("next workday"),
+            tag_no_case("next business day"),
+            tag_no_case("next working day"),
+        )),
+    );
+
+    let dynamic = amount(
+        MAX_YEARS * 366,
+        alt((
+            tag_no_case("workdays"),
+            tag_no_case("workday"),
+            tag_no_case("business days"),
+            tag_no_case("business day"),
+            tag_no_case("working days"),
+            tag_no_case("working day"),
+        )),
+    )
+    .map(RelativeDate::BusinessDays);
+
+    alt((predefined, dynamic))(i)
+}
+
+fn week(i: &str) -> ParseResult<RelativeDate> {
     let predefined = value(RelativeDate::Weeks(1), tag_no_case("next week"));
 
-    let dynamic = opt(tag_no_case("in "))
-        .and(usize::parse)
-        .and(tag_no_case("w"))
-        .map(|((_, weeks), _)| RelativeDate::Weeks(weeks));
+    let dynamic = amount(
+        MAX_YEARS * 53,
+        alt((tag_no_case("weeks"), tag_no_case("week"), tag_no_case("w"))),
+    )
+    .map(RelativeDate::Weeks);
 
     alt((predefined, dynamic))(i)
 }
 
+fn month(i: &str) -> ParseResult<RelativeDate> {
+    let predefined = value(RelativeDate::Months(1), tag_no_case("next month"));
+
+    let dynamic = amount(
+        MAX_YEARS * 12,
+        alt((
+            tag_no_case("months"),
+            tag_no_case("month"),
+            tag_no_case("mo"),
+        )),
+    )
+    .map(RelativeDate::Months);
+
+    alt((predefined, dynamic))(i)
+}
+
+fn year(i: &str) -> ParseResult<RelativeDate> {
+    let predefined = value(RelativeDate::Years(1), tag_no_case("next year"));
+
+    let dynamic = amount(
+        MAX_YEARS,
+        alt((tag_no_case("years"), tag_no_case("year"), tag_no_case("y"))),
+    )
+    .map(RelativeDate::Years);
+
+    alt((predefined, dynamic))(i)
+}
+
+fn day_of_month(i: &str) -> ParseResult<RelativeDate> {
+    let (i, _) = opt(tag_no_case("on "))(i)?;
+    let (i, month) = Month::parse(i)?;
+    let (start, _) = space1(i)?;
+    let (i, day) = usize::parse(start)?;
+
+    let (i, _) = opt(alt((
+        tag_no_case("st"),
+        tag_no_case("nd"),
+        tag_no_case("rd"),
+        tag_no_case("th"),
+    )))(i)?;
+
+    if day < 1 || day > month.max_days() as usize {
+        return ParseError::fail(start, "there's no such day in that month");
+    }
+
+    Ok((
+        i,
+        RelativeDate::DayOfMonth {
+            month,
+            day: day as u32,
+        },
+    ))
+}
+
+fn end_of(i: &str) -> ParseResult<RelativeDate> {
+    let (i, _) = opt(tag_no_case("at "))(i)?;
+    let (i, _) = opt(tag_no_case("the "))(i)?;
+    let (i, _) = tag_no_case("end of ")(i)?;
+    let (i, _) = opt(tag_no_case("the "))(i)?;
+
+    alt((
+        value(RelativeDate::EndOfWeek, tag_no_case("week")),
+        value(RelativeDate::EndOfMonth, tag_no_case("month")),
+    ))(i)
+}
+
+/// Parses a number followed by given unit, e.g. `in 3d` or `3 days`; numbers
+/// above `max` get rejected.
+fn amount<'a>(
+    max: usize,
+    mut unit: impl Parser<&'a str, &'a str, ParseError<'a>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, usize> {
+    move |i| {
+        let (start, _) = opt(tag_no_case("in "))(i)?;
+        let (i, amount) = usize::parse(start)?;
+        let (i, _) = space0(i)?;
+        let (i, _) = unit.parse(i)?;
+
+        if amount > max {
+            return ParseError::fail(start, "that's too far in the future");
+        }
+
+        Ok((i, amount))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,6 +185,12 @@ mod tests {
     //
     #[test_case("123d" => RelativeDate::Days(123) ; "123d")]
     #[test_case("in 123d" => RelativeDate::Days(123) ; "in 123d")]
+    //
+    #[test_case("1 day" => RelativeDate::Days(1) ; "1 day")]
+    #[test_case("in 1 day" => RelativeDate::Days(1) ; "in 1 day")]
+    #[test_case("in 2 days" => RelativeDate::Days(2) ; "in 2 days")]
+    #[test_case("in 2days" => RelativeDate::Days(2) ; "in 2days")]
+    #[test_case("In 2 Days" => RelativeDate::Days(2) ; "in 2 days mixed case")]
     fn day(input: &str) -> RelativeDate {
         RelativeDate::parse_unwrap(input)
     }
@@ -80,6 +214,18 @@ mod tests {
         RelativeDate::parse_unwrap(input)
     }
 
+    #[test_case("next workday" => RelativeDate::BusinessDays(1) ; "next workday")]
+    #[test_case("next business day" => RelativeDate::BusinessDays(1) ; "next business day")]
+    #[test_case("next working day" => RelativeDate::BusinessDays(1) ; "next working day")]
+    //
+    #[test_case("1 business day" => RelativeDate::BusinessDays(1) ; "1 business day")]
+    #[test_case("in 2 business days" => RelativeDate::BusinessDays(2) ; "in 2 business days")]
+    #[test_case("in 2 working days" => RelativeDate::BusinessDays(2) ; "in 2 working days")]
+    #[test_case("in 3 workdays" => RelativeDate::BusinessDays(3) ; "in 3 workdays")]
+    fn business_day(input: &str) -> RelativeDate {
+        RelativeDate::parse_unwrap(input)
+    }
+
     #[test_case("next week" => RelativeDate::Weeks(1) ; "next week")]
     //
     #[test_case("1w" => RelativeDate::Weeks(1) ; "1w")]
@@ -87,7 +233,84 @@ mod tests {
     //
     #[test_case("123w" => RelativeDate::Weeks(123) ; "123w")]
     #[test_case("in 123w" => RelativeDate::Weeks(123) ; "in 123w")]
+    //
+    #[test_case("1 week" => RelativeDate::Weeks(1) ; "1 week")]
+    #[test_case("in 1 week" => RelativeDate::Weeks(1) ; "in 1 week")]
+    #[test_case("in 2 weeks" => RelativeDate::Weeks(2) ; "in 2 weeks")]
     fn week(input: &str) -> RelativeDate {
         RelativeDate::parse_unwrap(input)
     }
+
+    #[test_case("next month" => RelativeDate::Months(1) ; "next month")]
+    //
+    #[test_case("1mo" => RelativeDate::Months(1) ; "1mo")]
+    #[test_case("in 3mo" => RelativeDate::Months(3) ; "in 3mo")]
+    //
+    #[test_case("1 month" => RelativeDate::Months(1) ; "1 month")]
+    #[test_case("in 1 month" => RelativeDate::Months(1) ; "in 1 month")]
+    #[test_case("in 6 months" => RelativeDate::Months(6) ; "in 6 months")]
+    fn month(input: &str) -> RelativeDate {
+        RelativeDate::parse_unwrap(input)
+    }
+
+    #[test_case("next year" => RelativeDate::Years(1) ; "next year")]
+    //
+    #[test_case("1y" => RelativeDate::Years(1) ; "1y")]
+    #[test_case("in 2y" => RelativeDate::Years(2) ; "in 2y")]
+    //
+    #[test_case("1 year" => RelativeDate::Years(1) ; "1 year")]
+    #[test_case("in 1 year" => RelativeDate::Years(1) ; "in 1 year")]
+    #[test_case("in 2 years" => RelativeDate::Years(2) ; "in 2 years")]
+    fn year(input: &str) -> RelativeDate {
+        RelativeDate::parse_unwrap(input)
+    }
+
+    #[test_case("dec 24" => RelativeDate::DayOfMonth { month: Month::December, day: 24 } ; "dec 24")]
+    #[test_case("on dec 24" => RelativeDate::DayOfMonth { month: Month::December, day: 24 } ; "on dec 24")]
+    #[test_case("on december 24" => RelativeDate::DayOfMonth { month: Month::December, day: 24 } ; "on december 24")]
+    #[test_case("on December 24th" => RelativeDate::DayOfMonth { month: Month::December, day: 24 } ; "on december 24th")]
+    #[test_case("jan 1st" => RelativeDate::DayOfMonth { month: Month::January, day: 1 } ; "jan 1st")]
+    #[test_case("mar 2nd" => RelativeDate::DayOfMonth { month: Month::March, day: 2 } ; "mar 2nd")]
+    #[test_case("may 3rd" => RelativeDate::DayOfMonth { month: Month::May, day: 3 } ; "may 3rd")]
+    #[test_case("feb 29" => RelativeDate::DayOfMonth { month: Month::February, day: 29 } ; "feb 29")]
+    fn day_of_month(input: &str) -> RelativeDate {
+        RelativeDate::parse_unwrap(input)
+    }
+
+    #[test_case("dec 0" ; "dec 0")]
+    #[test_case("dec 32" ; "dec 32")]
+    #[test_case("feb 30" ; "feb 30")]
+    #[test_case("apr 31" ; "apr 31")]
+    fn invalid_day_of_month(input: &str) {
+        let (_, day) = input.split_at(4);
+
+        assert_eq!(
+            ParseError::fail(day, "there's no such day in that month"),
+            RelativeDate::parse(input),
+        );
+    }
+
+    #[test_case("end of week" => RelativeDate::EndOfWeek ; "end of week")]
+    #[test_case("end of the week" => RelativeDate::EndOfWeek ; "end of the week")]
+    #[test_case("the end of the week" => RelativeDate::EndOfWeek ; "the end of the week")]
+    #[test_case("at the end of the week" => RelativeDate::EndOfWeek ; "at the end of the week")]
+    //
+    #[test_case("end of month" => RelativeDate::EndOfMonth ; "end of month")]
+    #[test_case("end of the month" => RelativeDate::EndOfMonth ; "end of the month")]
+    #[test_case("at the end of the month" => RelativeDate::EndOfMonth ; "at the end of the month")]
+    fn end_of(input: &str) -> RelativeDate {
+        RelativeDate::parse_unwrap(input)
+    }
+
+    #[test_case("in 36601d" ; "days")]
+    #[test_case("in 36601 business days" ; "business days")]
+    #[test_case("in 5301w" ; "weeks")]
+    #[test_case("in 1201 months" ; "months")]
+    #[test_case("in 101 years" ; "years")]
+    fn too_far_in_the_future(input: &str) {
+        assert_eq!(
+            ParseError::fail(&input[3..], "that's too far in the future"),
+            RelativeDate::parse(input),
+        );
+    }
 }
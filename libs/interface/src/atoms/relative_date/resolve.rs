@@ -1,46 +1,126 @@
-use crate::RelativeDate;
-use chrono::{Datelike, Duration, NaiveDate};
+use crate::{Calendar, RelativeDate};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 
 impl RelativeDate {
-    pub fn resolve(self, now: NaiveDate) -> NaiveDate {
+    /// Resolves this date relative to given one; returns `None` if the result
+    /// doesn't fit in `NaiveDate`
+    pub fn resolve(self, now: NaiveDate, calendar: &Calendar) -> Option<NaiveDate> {
         match self {
-            Self::Days(days) => now + Duration::days(days as i64),
+            Self::Days(days) => add_days(now, days),
 
             Self::DayOfWeek(dow) => {
-                let src_weekday = now.weekday().number_from_monday() as i64;
-                let dst_weekday = dow.number_from_monday() as i64;
+                let src_weekday = now.weekday().number_from_monday() as usize;
+                let dst_weekday = dow.number_from_monday() as usize;
 
                 match src_weekday.cmp(&dst_weekday) {
                     // e.g. src = Monday, dst = Wednesday
-                    Ordering::Less => now + Duration::days(dst_weekday - src_weekday),
+                    Ordering::Less => add_days(now, dst_weekday - src_weekday),
 
                     // e.g. src = Monday, dst = Monday
-                    Ordering::Equal => now + Duration::weeks(1),
+                    Ordering::Equal => add_days(now, 7),
 
                     // e.g. src = Wednesday, dst = Monday
-                    Ordering::Greater => now + Duration::days(7 - src_weekday + dst_weekday),
+                    Ordering::Greater => add_days(now, 7 - src_weekday + dst_weekday),
                 }
             }
 
-            Self::Weeks(weeks) => now + Duration::weeks(weeks as i64),
+            Self::BusinessDays(days) => calendar.add_business_days(now, days),
+
+            Self::Weeks(weeks) => add_days(now, weeks.checked_mul(7)?),
+
+            Self::Months(months) => add_months(now, months),
+
+            Self::Years(years) => add_months(now, years.checked_mul(12)?),
+
+            Self::DayOfMonth { month, day } => {
+                // Since February 29th doesn't happen each year, we might have
+                // to look a few years ahead (but no more than eight, which is
+                // the longest gap between leap years)
+                (now.year()..)
+                    .take(9)
+                    .filter_map(|year| NaiveDate::from_ymd_opt(year, month.number(), day))
+                    .find(|date| *date >= now)
+            }
+
+            Self::EndOfWeek => {
+                let src_weekday = now.weekday().number_from_monday() as i64;
+                let dst_weekday = Weekday::Fri.number_from_monday() as i64;
+
+                add_days(now, (dst_weekday - src_weekday).rem_euclid(7) as usize)
+            }
+
+            Self::EndOfMonth => add_months(now.with_day(1)?, 1)?.pred_opt(),
         }
     }
 }
 
+/// Adds given number of days to given date; returns `None` if the result
+/// doesn't fit in `NaiveDate`.
+fn add_days(date: NaiveDate, days: usize) -> Option<NaiveDate> {
+    // `Duration::days()` panics on overflow, but anything above `i32::MAX` days
+    // is way past what `NaiveDate` can represent anyway
+    let days = i32::try_from(days).ok()?;
+
+    date.checked_add_signed(Duration::days(days as i64))
+}
+
+/// Adds given number of months to given date, clamping the day if the target
+/// month is shorter (e.g. January 31st + 1 month = February 28th / 29th);
+/// returns `None` if the result doesn't fit in `NaiveDate`.
+fn add_months(date: NaiveDate, months: usize) -> Option<NaiveDate> {
+    let month0 = (date.month0() as usize).checked_add(months)?;
+    let year = date.year().checked_add(i32::try_from(month0 / 12).ok()?)?;
+    let month = (month0 % 12) as u32 + 1;
+
+    (1..=date.day())
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn ymd_to_date((y, m, d): (i32, u32, u32)) -> NaiveDate {
+        NaiveDate::from_ymd(y, m, d)
+    }
+
+    fn date_to_ymd(date: NaiveDate) -> (i32, u32, u32) {
+        (date.year(), date.month(), date.day())
+    }
+
     mod given_relative_days {
         use super::*;
 
         #[test]
         fn returns_current_date_plus_that_number_of_days() {
-            let actual = RelativeDate::Days(3).resolve(NaiveDate::from_ymd(2012, 01, 01));
+            let actual = RelativeDate::Days(3)
+                .resolve(NaiveDate::from_ymd(2012, 01, 01), &Calendar::default());
             let expected = NaiveDate::from_ymd(2012, 01, 04);
 
-            assert_eq!(expected, actual);
+            assert_eq!(Some(expected), actual);
+        }
+    }
+
+    mod given_relative_date_too_far_in_the_future {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case(RelativeDate::Days(200_000_000) ; "days")]
+        #[test_case(RelativeDate::Days(usize::MAX) ; "max days")]
+        #[test_case(RelativeDate::Weeks(50_000_000) ; "weeks")]
+        #[test_case(RelativeDate::Weeks(usize::MAX) ; "max weeks")]
+        #[test_case(RelativeDate::Months(5_000_000) ; "months")]
+        #[test_case(RelativeDate::Months(usize::MAX) ; "max months")]
+        #[test_case(RelativeDate::Years(300_000) ; "years")]
+        #[test_case(RelativeDate::Years(usize::MAX / 6) ; "years overflowing months")]
+        #[test_case(RelativeDate::Years(usize::MAX) ; "max years")]
+        fn returns_none(date: RelativeDate) {
+            let actual = date.resolve(NaiveDate::from_ymd(2012, 01, 01), &Calendar::default());
+
+            assert_eq!(None, actual);
         }
     }
 
@@ -54,11 +134,11 @@ mod tests {
             #[test]
             fn returns_current_date_plus_seven_days() {
                 let actual = RelativeDate::DayOfWeek(DayOfWeek::Sunday)
-                    .resolve(NaiveDate::from_ymd(2012, 01, 01));
+                    .resolve(NaiveDate::from_ymd(2012, 01, 01), &Calendar::default());
 
                 let expected = NaiveDate::from_ymd(2012, 01, 08);
 
-                assert_eq!(expected, actual);
+                assert_eq!(Some(expected), actual);
             }
         }
 
@@ -70,21 +150,21 @@ mod tests {
                 // Case 1: Sunday (2012-01-01) -> Tuesday (2012-01-03)
                 {
                     let actual = RelativeDate::DayOfWeek(DayOfWeek::Tuesday)
-                        .resolve(NaiveDate::from_ymd(2012, 01, 01));
+                        .resolve(NaiveDate::from_ymd(2012, 01, 01), &Calendar::default());
 
                     let expected = NaiveDate::from_ymd(2012, 01, 03);
 
-                    assert_eq!(expected, actual);
+                    assert_eq!(Some(expected), actual);
                 }
 
                 // Case 2: Tuesday (2012-01-03) -> Sunday (2012-01-08)
                 {
                     let actual = RelativeDate::DayOfWeek(DayOfWeek::Sunday)
-                        .resolve(NaiveDate::from_ymd(2012, 01, 03));
+                        .resolve(NaiveDate::from_ymd(2012, 01, 03), &Calendar::default());
 
                     let expected = NaiveDate::from_ymd(2012, 01, 08);
 
-                    assert_eq!(expected, actual);
+                    assert_eq!(Some(expected), actual);
                 }
             }
         }
@@ -95,10 +175,122 @@ mod tests {
 
         #[test]
         fn returns_current_date_plus_that_number_of_weeks() {
-            let actual = RelativeDate::Weeks(3).resolve(NaiveDate::from_ymd(2012, 01, 01));
+            let actual = RelativeDate::Weeks(3)
+                .resolve(NaiveDate::from_ymd(2012, 01, 01), &Calendar::default());
             let expected = NaiveDate::from_ymd(2012, 01, 22);
 
-            assert_eq!(expected, actual);
+            assert_eq!(Some(expected), actual);
+        }
+    }
+
+    mod given_relative_months {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case((2012, 01, 15), 1 => (2012, 02, 15) ; "mid-month")]
+        #[test_case((2012, 01, 31), 1 => (2012, 02, 29) ; "to shorter month in leap year")]
+        #[test_case((2013, 01, 31), 1 => (2013, 02, 28) ; "to shorter month")]
+        #[test_case((2012, 11, 30), 2 => (2013, 01, 30) ; "over new year")]
+        #[test_case((2012, 01, 01), 25 => (2014, 02, 01) ; "over a few years")]
+        fn returns_current_date_plus_that_number_of_months(
+            now: (i32, u32, u32),
+            months: usize,
+        ) -> (i32, u32, u32) {
+            let actual =
+                RelativeDate::Months(months).resolve(ymd_to_date(now), &Calendar::default());
+
+            date_to_ymd(actual.unwrap())
+        }
+    }
+
+    mod given_relative_years {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case((2013, 05, 12), 1 => (2014, 05, 12) ; "regular day")]
+        #[test_case((2012, 02, 29), 1 => (2013, 02, 28) ; "leap day")]
+        #[test_case((2012, 02, 29), 4 => (2016, 02, 29) ; "leap day to leap year")]
+        fn returns_current_date_plus_that_number_of_years(
+            now: (i32, u32, u32),
+            years: usize,
+        ) -> (i32, u32, u32) {
+            let actual = RelativeDate::Years(years).resolve(ymd_to_date(now), &Calendar::default());
+
+            date_to_ymd(actual.unwrap())
+        }
+    }
+
+    mod given_relative_day_of_month {
+        use super::*;
+        use crate::Month;
+        use test_case::test_case;
+
+        #[test_case((2012, 01, 01), Month::December, 24 => (2012, 12, 24) ; "later this year")]
+        #[test_case((2012, 12, 24), Month::December, 24 => (2012, 12, 24) ; "today")]
+        #[test_case((2012, 12, 25), Month::December, 24 => (2013, 12, 24) ; "already passed")]
+        #[test_case((2012, 03, 01), Month::February, 29 => (2016, 02, 29) ; "leap day")]
+        fn returns_the_nearest_such_date(
+            now: (i32, u32, u32),
+            month: Month,
+            day: u32,
+        ) -> (i32, u32, u32) {
+            let actual = RelativeDate::DayOfMonth { month, day }
+                .resolve(ymd_to_date(now), &Calendar::default());
+
+            date_to_ymd(actual.unwrap())
+        }
+    }
+
+    mod given_relative_end_of_week {
+        use super::*;
+        use test_case::test_case;
+
+        // 2012-01-02 is Monday
+        #[test_case((2012, 01, 02) => (2012, 01, 06) ; "monday")]
+        #[test_case((2012, 01, 05) => (2012, 01, 06) ; "thursday")]
+        #[test_case((2012, 01, 06) => (2012, 01, 06) ; "friday")]
+        #[test_case((2012, 01, 07) => (2012, 01, 13) ; "saturday")]
+        #[test_case((2012, 01, 08) => (2012, 01, 13) ; "sunday")]
+        fn returns_the_nearest_friday(now: (i32, u32, u32)) -> (i32, u32, u32) {
+            let actual = RelativeDate::EndOfWeek.resolve(ymd_to_date(now), &Calendar::default());
+
+            date_to_ymd(actual.unwrap())
+        }
+    }
+
+    mod given_relative_end_of_month {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case((2012, 01, 15) => (2012, 01, 31) ; "january")]
+        #[test_case((2012, 02, 01) => (2012, 02, 29) ; "february in leap year")]
+        #[test_case((2013, 02, 28) => (2013, 02, 28) ; "last day")]
+        #[test_case((2012, 12, 01) => (2012, 12, 31) ; "december")]
+        fn returns_the_last_day_of_current_month(now: (i32, u32, u32)) -> (i32, u32, u32) {
+            let actual = RelativeDate::EndOfMonth.resolve(ymd_to_date(now), &Calendar::default());
+
+            date_to_ymd(actual.unwrap())
+        }
+    }
+
+    mod given_relative_business_days {
+        use super::*;
+        use test_case::test_case;
+
+        // 2012-01-02 is Monday, 2012-01-06 is Epiphany (a holiday in Poland)
+        #[test_case((2012, 01, 02), 1 => (2012, 01, 03) ; "monday + 1")]
+        #[test_case((2012, 01, 04), 1 => (2012, 01, 05) ; "wednesday + 1")]
+        #[test_case((2012, 01, 05), 1 => (2012, 01, 09) ; "thursday + 1, over holiday")]
+        #[test_case((2012, 01, 05), 2 => (2012, 01, 10) ; "thursday + 2, over holiday")]
+        #[test_case((2012, 01, 01), 1 => (2012, 01, 02) ; "sunday + 1")]
+        fn returns_current_date_plus_that_number_of_business_days(
+            now: (i32, u32, u32),
+            days: usize,
+        ) -> (i32, u32, u32) {
+            let calendar = Calendar::default().with_country("PL").unwrap();
+            let actual = RelativeDate::BusinessDays(days).resolve(ymd_to_date(now), &calendar);
+
+            date_to_ymd(actual.unwrap())
         }
     }
 }
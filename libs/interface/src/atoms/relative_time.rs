@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 mod atom;
 mod resolve;
@@ -6,7 +6,7 @@ mod resolve;
 /// A relative time, e.g. `3h`.
 ///
 /// Used as a part of the `time` component of the `DateTime` atom.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
 pub struct RelativeTime {
     pub hours: Option<usize>,
     pub minutes: Option<usize>,
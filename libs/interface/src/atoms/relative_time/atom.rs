@@ -1,12 +1,16 @@
-use crate::{Atom, RelativeTime};
+use crate::{Atom, ParseError, ParseResult, RelativeTime};
 use nom::branch::alt;
-use nom::bytes::complete::tag_no_case;
-use nom::character::complete::char;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::space0;
 use nom::multi::separated_list1;
-use nom::{IResult, Parser};
+use nom::Parser;
+
+/// How far into the future relative times can reach (that's 100 years); it's
+/// way more than anyone needs, and it keeps resolving them far from overflowing
+const MAX_HOURS: usize = 100 * 366 * 24;
 
 impl Atom for RelativeTime {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         components
             .map(|components| {
                 let mut hours = None;
@@ -37,26 +41,71 @@ enum Component {
     S(usize),
 }
 
-fn components(i: &str) -> IResult<&str, Vec<Component>> {
-    separated_list1(char(' '), component)(i)
+fn components(i: &str) -> ParseResult<Vec<Component>> {
+    let separator = alt((tag(", "), tag_no_case(" and "), tag(" ")));
+
+    separated_list1(separator, component)(i)
 }
 
-fn component(i: &str) -> IResult<&str, Component> {
-    let h = usize::parse
-        .and(tag_no_case("h"))
-        .map(|(value, _)| Component::H(value));
+fn component(i: &str) -> ParseResult<Component> {
+    let h = amount(
+        MAX_HOURS,
+        alt((
+            tag_no_case("hours"),
+            tag_no_case("hour"),
+            tag_no_case("hrs"),
+            tag_no_case("hr"),
+            tag_no_case("h"),
+        )),
+    )
+    .map(Component::H);
 
-    let m = usize::parse
-        .and(tag_no_case("m"))
-        .map(|(value, _)| Component::M(value));
+    let m = amount(
+        MAX_HOURS * 60,
+        alt((
+            tag_no_case("minutes"),
+            tag_no_case("minute"),
+            tag_no_case("mins"),
+            tag_no_case("min"),
+            tag_no_case("m"),
+        )),
+    )
+    .map(Component::M);
 
-    let s = usize::parse
-        .and(tag_no_case("s"))
-        .map(|(value, _)| Component::S(value));
+    let s = amount(
+        MAX_HOURS * 60 * 60,
+        alt((
+            tag_no_case("seconds"),
+            tag_no_case("second"),
+            tag_no_case("secs"),
+            tag_no_case("sec"),
+            tag_no_case("s"),
+        )),
+    )
+    .map(Component::S);
 
     alt((h, m, s))(i)
 }
 
+/// Parses a number followed by given unit, e.g. `3h`; numbers above `max` get
+/// rejected.
+fn amount<'a>(
+    max: usize,
+    mut unit: impl Parser<&'a str, &'a str, ParseError<'a>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, usize> {
+    move |start| {
+        let (i, amount) = usize::parse(start)?;
+        let (i, _) = space0(i)?;
+        let (i, _) = unit.parse(i)?;
+
+        if amount > max {
+            return ParseError::fail(start, "that's too far in the future");
+        }
+
+        Ok((i, amount))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,7 +123,41 @@ mod tests {
     #[test_case("12h 34m" => RelativeTime { hours: Some(12), minutes: Some(34), ..Default::default() } ; "12h 34m")]
     #[test_case("12m 34s" => RelativeTime { minutes: Some(12), seconds: Some(34), ..Default::default() } ; "12m 34s")]
     #[test_case("12h 34m 56s" => RelativeTime { hours: Some(12), minutes: Some(34), seconds: Some(56) } ; "12h 34m 56s")]
+    //
+    #[test_case("1 hour" => RelativeTime { hours: Some(1), ..Default::default() } ; "1 hour")]
+    #[test_case("2 hours" => RelativeTime { hours: Some(2), ..Default::default() } ; "2 hours")]
+    #[test_case("2 hrs" => RelativeTime { hours: Some(2), ..Default::default() } ; "2 hrs")]
+    #[test_case("2hr" => RelativeTime { hours: Some(2), ..Default::default() } ; "2hr")]
+    //
+    #[test_case("1 minute" => RelativeTime { minutes: Some(1), ..Default::default() } ; "1 minute")]
+    #[test_case("90 minutes" => RelativeTime { minutes: Some(90), ..Default::default() } ; "90 minutes")]
+    #[test_case("5 mins" => RelativeTime { minutes: Some(5), ..Default::default() } ; "5 mins")]
+    #[test_case("5min" => RelativeTime { minutes: Some(5), ..Default::default() } ; "5min")]
+    //
+    #[test_case("1 second" => RelativeTime { seconds: Some(1), ..Default::default() } ; "1 second")]
+    #[test_case("30 seconds" => RelativeTime { seconds: Some(30), ..Default::default() } ; "30 seconds")]
+    #[test_case("30 secs" => RelativeTime { seconds: Some(30), ..Default::default() } ; "30 secs")]
+    #[test_case("30sec" => RelativeTime { seconds: Some(30), ..Default::default() } ; "30sec")]
+    //
+    #[test_case("3 hours 30 minutes" => RelativeTime { hours: Some(3), minutes: Some(30), ..Default::default() } ; "3 hours 30 minutes")]
+    #[test_case("3 hours and 30 minutes" => RelativeTime { hours: Some(3), minutes: Some(30), ..Default::default() } ; "3 hours and 30 minutes")]
+    #[test_case("1 hour, 2 minutes and 3 seconds" => RelativeTime { hours: Some(1), minutes: Some(2), seconds: Some(3) } ; "1 hour, 2 minutes and 3 seconds")]
+    #[test_case("1h 2 min 3 secs" => RelativeTime { hours: Some(1), minutes: Some(2), seconds: Some(3) } ; "mixed units")]
     fn test(input: &str) -> RelativeTime {
         RelativeTime::parse_unwrap(input)
     }
+
+    #[test_case("878401h" => "878401h" ; "hours")]
+    #[test_case("52704001 minutes" => "52704001 minutes" ; "minutes")]
+    #[test_case("3162240001s" => "3162240001s" ; "seconds")]
+    #[test_case("1h 878401h" => "878401h" ; "second component")]
+    fn too_far_in_the_future(input: &str) -> &str {
+        match RelativeTime::parse(input) {
+            Err(nom::Err::Failure(ParseError {
+                input,
+                reason: Some("that's too far in the future"),
+            })) => input,
+            result => panic!("Expected failure, got: {:?}", result),
+        }
+    }
 }
@@ -1,11 +1,25 @@
 use crate::RelativeTime;
 use chrono::{Duration, NaiveDateTime};
+use std::convert::TryFrom;
 
 impl RelativeTime {
-    pub fn resolve(self, now: NaiveDateTime) -> NaiveDateTime {
-        now + Duration::hours(self.hours.unwrap_or_default() as i64)
-            + Duration::minutes(self.minutes.unwrap_or_default() as i64)
-            + Duration::seconds(self.seconds.unwrap_or_default() as i64)
+    /// Resolves this time relative to given one; returns `None` if the result
+    /// doesn't fit in `NaiveDateTime`
+    pub fn resolve(self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        let seconds = self
+            .hours
+            .unwrap_or_default()
+            .checked_mul(60 * 60)?
+            .checked_add(self.minutes.unwrap_or_default().checked_mul(60)?)?
+            .checked_add(self.seconds.unwrap_or_default())?;
+
+        // `Duration::seconds()` panics on overflow, but anything above
+        // `i32::MAX` days is way past what `NaiveDateTime` can represent anyway
+        let days = i32::try_from(seconds / (24 * 60 * 60)).ok()?;
+        let seconds = (seconds % (24 * 60 * 60)) as i64;
+
+        now.checked_add_signed(Duration::days(days as i64))?
+            .checked_add_signed(Duration::seconds(seconds))
     }
 }
 
@@ -13,6 +27,7 @@ impl RelativeTime {
 mod tests {
     use super::*;
     use chrono::{NaiveDate, NaiveTime};
+    use test_case::test_case;
 
     fn now() -> NaiveDateTime {
         NaiveDateTime::new(
@@ -32,6 +47,25 @@ mod tests {
 
         let expected = NaiveDateTime::new(now().date(), NaiveTime::from_hms(02, 25, 48));
 
-        assert_eq!(expected, actual);
+        assert_eq!(Some(expected), actual);
+    }
+
+    #[test_case(Some(usize::MAX), None, None ; "max hours")]
+    #[test_case(None, Some(usize::MAX), None ; "max minutes")]
+    #[test_case(None, None, Some(usize::MAX) ; "max seconds")]
+    #[test_case(Some(3_000_000_000), None, None ; "hours")]
+    fn returns_none_on_overflow(
+        hours: Option<usize>,
+        minutes: Option<usize>,
+        seconds: Option<usize>,
+    ) {
+        let actual = RelativeTime {
+            hours,
+            minutes,
+            seconds,
+        }
+        .resolve(now());
+
+        assert_eq!(None, actual);
     }
 }
@@ -1,6 +1,6 @@
 use crate::RelativeTime;
 use chrono::NaiveTime;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 mod atom;
 mod resolve;
@@ -8,7 +8,7 @@ mod resolve;
 /// A time, e.g. `at 12:00` or `in 3h`.
 ///
 /// Used as a part of the `time` component of the `DateTime` atom.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum Time {
     /// E.g. `at 12:00`
     Absolute(NaiveTime),
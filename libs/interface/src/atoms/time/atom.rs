@@ -1,34 +1,79 @@
-use crate::{Atom, RelativeTime, Time};
+use crate::{Atom, ParseError, ParseResult, RelativeTime, Time};
 use chrono::NaiveTime;
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
-use nom::character::complete::char;
-use nom::combinator::opt;
-use nom::{IResult, Parser};
+use nom::character::complete::{char, space0};
+use nom::combinator::{opt, value};
+use nom::sequence::preceded;
 
 impl Atom for Time {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         alt((relative, absolute))(i)
     }
 }
 
-fn absolute(i: &str) -> IResult<&str, Time> {
+fn absolute(i: &str) -> ParseResult<Time> {
     let (i, _) = opt(tag_no_case("at "))(i)?;
 
-    let hour_and_minute =
-        usize::parse
-            .and(char(':'))
-            .and(usize::parse)
-            .map(|((hour, _), minute)| {
-                Time::Absolute(NaiveTime::from_hms(hour as u32, minute as u32, 0))
-            });
+    alt((named, clock))(i)
+}
+
+fn named(i: &str) -> ParseResult<Time> {
+    let (i, _) = opt(tag_no_case("in the "))(i)?;
 
-    let hour = usize::parse.map(|hour| Time::Absolute(NaiveTime::from_hms(hour as u32, 0, 0)));
+    let (i, (hour, minute)) = alt((
+        value((0, 0), tag_no_case("midnight")),
+        value((9, 0), tag_no_case("morning")),
+        value((12, 0), tag_no_case("noon")),
+        value((14, 0), tag_no_case("afternoon")),
+        value((18, 0), tag_no_case("evening")),
+    ))(i)?;
 
-    alt((hour_and_minute, hour))(i)
+    Ok((i, Time::Absolute(NaiveTime::from_hms(hour, minute, 0))))
 }
 
-fn relative(i: &str) -> IResult<&str, Time> {
+fn clock(start: &str) -> ParseResult<Time> {
+    let (i, hour) = usize::parse(start)?;
+    let (i, minute) = opt(preceded(char(':'), usize::parse))(i)?;
+    let (i, is_pm) = opt(preceded(space0, meridiem))(i)?;
+
+    let hour = match is_pm {
+        Some(is_pm) => {
+            if hour < 1 || hour > 12 {
+                return ParseError::fail(start, "hour must be 1-12 when using am / pm");
+            }
+
+            // 12am is midnight, 12pm is noon
+            hour % 12 + if is_pm { 12 } else { 0 }
+        }
+
+        None => {
+            if hour > 23 {
+                return ParseError::fail(start, "hour must be 0-23");
+            }
+
+            hour
+        }
+    };
+
+    if minute.map_or(false, |minute| minute > 59) {
+        return ParseError::fail(start, "minute must be 0-59");
+    }
+
+    let time = NaiveTime::from_hms(hour as u32, minute.unwrap_or_default() as u32, 0);
+
+    Ok((i, Time::Absolute(time)))
+}
+
+/// Parses `am` / `pm`, returning whether it was `pm`
+fn meridiem(i: &str) -> ParseResult<bool> {
+    alt((
+        value(false, alt((tag_no_case("am"), tag_no_case("a.m.")))),
+        value(true, alt((tag_no_case("pm"), tag_no_case("p.m.")))),
+    ))(i)
+}
+
+fn relative(i: &str) -> ParseResult<Time> {
     let (i, _) = opt(tag_no_case("in "))(i)?;
     let (i, time) = RelativeTime::parse(i)?;
 
@@ -45,10 +90,50 @@ mod tests {
     //
     #[test_case("at 12" => Time::Absolute(NaiveTime::from_hms(12, 00, 00)) ; "at 12")]
     #[test_case("at 12:34" => Time::Absolute(NaiveTime::from_hms(12, 34, 00)) ; "at 12:34")]
+    //
+    #[test_case("3am" => Time::Absolute(NaiveTime::from_hms(03, 00, 00)) ; "3am")]
+    #[test_case("3pm" => Time::Absolute(NaiveTime::from_hms(15, 00, 00)) ; "3pm")]
+    #[test_case("3 PM" => Time::Absolute(NaiveTime::from_hms(15, 00, 00)) ; "3 pm uppercase")]
+    #[test_case("3 p.m." => Time::Absolute(NaiveTime::from_hms(15, 00, 00)) ; "3 p.m.")]
+    #[test_case("at 3:30pm" => Time::Absolute(NaiveTime::from_hms(15, 30, 00)) ; "at 3:30pm")]
+    #[test_case("at 11:15 am" => Time::Absolute(NaiveTime::from_hms(11, 15, 00)) ; "at 11:15 am")]
+    #[test_case("12am" => Time::Absolute(NaiveTime::from_hms(00, 00, 00)) ; "12am")]
+    #[test_case("12pm" => Time::Absolute(NaiveTime::from_hms(12, 00, 00)) ; "12pm")]
     fn absolute(input: &str) -> Time {
         Time::parse_unwrap(input)
     }
 
+    #[test_case("24" => "hour must be 0-23" ; "24")]
+    #[test_case("at 25" => "hour must be 0-23" ; "at 25")]
+    #[test_case("12:60" => "minute must be 0-59" ; "12:60")]
+    #[test_case("at 12:99" => "minute must be 0-59" ; "at 12:99")]
+    #[test_case("0am" => "hour must be 1-12 when using am / pm" ; "0am")]
+    #[test_case("13pm" => "hour must be 1-12 when using am / pm" ; "13pm")]
+    #[test_case("3:60pm" => "minute must be 0-59" ; "3:60pm")]
+    fn invalid_absolute(input: &str) -> &'static str {
+        match Time::parse(input) {
+            Err(nom::Err::Failure(ParseError {
+                reason: Some(reason),
+                ..
+            })) => reason,
+            result => panic!("Expected failure, got: {:?}", result),
+        }
+    }
+
+    #[test_case("midnight" => Time::Absolute(NaiveTime::from_hms(00, 00, 00)) ; "midnight")]
+    #[test_case("at midnight" => Time::Absolute(NaiveTime::from_hms(00, 00, 00)) ; "at midnight")]
+    #[test_case("morning" => Time::Absolute(NaiveTime::from_hms(09, 00, 00)) ; "morning")]
+    #[test_case("in the morning" => Time::Absolute(NaiveTime::from_hms(09, 00, 00)) ; "in the morning")]
+    #[test_case("noon" => Time::Absolute(NaiveTime::from_hms(12, 00, 00)) ; "noon")]
+    #[test_case("at noon" => Time::Absolute(NaiveTime::from_hms(12, 00, 00)) ; "at noon")]
+    #[test_case("afternoon" => Time::Absolute(NaiveTime::from_hms(14, 00, 00)) ; "afternoon")]
+    #[test_case("in the afternoon" => Time::Absolute(NaiveTime::from_hms(14, 00, 00)) ; "in the afternoon")]
+    #[test_case("evening" => Time::Absolute(NaiveTime::from_hms(18, 00, 00)) ; "evening")]
+    #[test_case("in the evening" => Time::Absolute(NaiveTime::from_hms(18, 00, 00)) ; "in the evening")]
+    fn named(input: &str) -> Time {
+        Time::parse_unwrap(input)
+    }
+
     #[test_case("12h" => Time::Relative(RelativeTime { hours: Some(12), ..Default::default() }) ; "12h")]
     #[test_case("12h 34m" => Time::Relative(RelativeTime { hours: Some(12), minutes: Some(34), ..Default::default() }) ; "12h 34m")]
     #[test_case("12h 34m 56s" => Time::Relative(RelativeTime { hours: Some(12), minutes: Some(34), seconds: Some(56) }) ; "12h 34m 56s")]
@@ -56,6 +141,9 @@ mod tests {
     #[test_case("in 12h" => Time::Relative(RelativeTime { hours: Some(12), ..Default::default() }) ; "in 12h")]
     #[test_case("in 12h 34m" => Time::Relative(RelativeTime { hours: Some(12), minutes: Some(34), ..Default::default() }) ; "in 12h 34m")]
     #[test_case("in 12h 34m 56s" => Time::Relative(RelativeTime { hours: Some(12), minutes: Some(34), seconds: Some(56) }) ; "in 12h 34m 56s")]
+    //
+    #[test_case("in 90 minutes" => Time::Relative(RelativeTime { minutes: Some(90), ..Default::default() }) ; "in 90 minutes")]
+    #[test_case("in 3 hours" => Time::Relative(RelativeTime { hours: Some(3), ..Default::default() }) ; "in 3 hours")]
     fn relative(input: &str) -> Time {
         Time::parse_unwrap(input)
     }
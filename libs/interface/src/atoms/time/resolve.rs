@@ -2,9 +2,9 @@ use crate::Time;
 use chrono::NaiveDateTime;
 
 impl Time {
-    pub fn resolve(self, now: NaiveDateTime) -> NaiveDateTime {
+    pub fn resolve(self, now: NaiveDateTime) -> Option<NaiveDateTime> {
         match self {
-            Self::Absolute(time) => NaiveDateTime::new(now.date(), time),
+            Self::Absolute(time) => Some(NaiveDateTime::new(now.date(), time)),
             Self::Relative(time) => time.resolve(now),
         }
     }
@@ -30,7 +30,7 @@ mod tests {
             let actual = Time::Absolute(NaiveTime::from_hms(12, 34, 56)).resolve(now());
             let expected = NaiveDateTime::new(now().date(), NaiveTime::from_hms(12, 34, 56));
 
-            assert_eq!(expected, actual);
+            assert_eq!(Some(expected), actual);
         }
     }
 
@@ -49,7 +49,7 @@ mod tests {
 
             let expected = NaiveDateTime::new(now().date(), NaiveTime::from_hms(02, 25, 48));
 
-            assert_eq!(expected, actual);
+            assert_eq!(Some(expected), actual);
         }
     }
 }
@@ -0,0 +1,49 @@
+use anyhow::*;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+mod atom;
+
+/// A timezone from the IANA database, e.g. `Europe/Warsaw`.
+///
+/// Used as a part of the `DigestSchedule` atom.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Timezone(Tz);
+
+impl Timezone {
+    /// Returns timezone's name, e.g. `Europe/Warsaw`
+    pub fn as_str(self) -> &'static str {
+        self.0.name()
+    }
+
+    pub fn tz(self) -> Tz {
+        self.0
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.parse()
+            .map(Self)
+            .map_err(|_| anyhow!("Unknown timezone: {}", s))
+    }
+}
+
+impl TryFrom<String> for Timezone {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<Timezone> for String {
+    fn from(timezone: Timezone) -> Self {
+        timezone.as_str().to_string()
+    }
+}
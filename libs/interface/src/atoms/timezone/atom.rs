@@ -0,0 +1,48 @@
+use crate::{Atom, ParseError, ParseResult, Timezone};
+use nom::bytes::complete::take_while1;
+
+impl Atom for Timezone {
+    fn parse(i: &str) -> ParseResult<Self> {
+        let (o, name) =
+            take_while1(|c: char| c.is_ascii_alphanumeric() || ['/', '_', '+', '-'].contains(&c))(
+                i,
+            )?;
+
+        match name.parse() {
+            Ok(timezone) => Ok((o, timezone)),
+            Err(_) => ParseError::fail(name, "there's no such timezone"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("UTC" ; "utc")]
+    #[test_case("Europe/Warsaw" ; "europe warsaw")]
+    #[test_case("America/New_York" ; "america new york")]
+    #[test_case("Etc/GMT+2" ; "etc gmt plus 2")]
+    fn test(input: &str) {
+        assert_eq!(input, Timezone::parse_unwrap(input).as_str());
+    }
+
+    #[test]
+    fn unknown_timezone() {
+        let actual = Timezone::parse("Europe/Atlantis");
+
+        let expected = ParseError::fail("Europe/Atlantis", "there's no such timezone");
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let timezone = Timezone::parse_unwrap("Europe/Warsaw");
+        let json = serde_json::to_string(&timezone).unwrap();
+
+        assert_eq!(r#""Europe/Warsaw""#, json);
+        assert_eq!(timezone, serde_json::from_str(&json).unwrap());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::MergeRequestPtr;
+use serde::{Deserialize, Serialize};
+
+mod atom;
+
+/// An event that should fire a reminder, e.g. `pipeline succeeds`.
+///
+/// Used as a part of the `remind ... when` command.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum Trigger {
+    /// E.g. `pipeline succeeds` - fires when merge request's pipeline
+    /// succeeds
+    PipelineSucceeded,
+
+    /// E.g. `pipeline fails` - fires when merge request's pipeline fails
+    PipelineFailed,
+
+    /// E.g. `!42 is merged` or `it's merged` - fires when given merge request
+    /// gets merged; `None` stands for the merge request where the command was
+    /// issued
+    MergeRequestMerged {
+        merge_request: Option<MergeRequestPtr>,
+    },
+}
+
+impl Trigger {
+    /// Returns name of the event that fires this trigger (see:
+    /// `Event::name()`), e.g. `pipeline.succeeded`
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            Self::PipelineSucceeded => "pipeline.succeeded",
+            Self::PipelineFailed => "pipeline.failed",
+            Self::MergeRequestMerged { .. } => "merge_request.merged",
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{Atom, MergeRequestPtr, ParseResult, Trigger};
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::combinator::{map, opt, value};
+use nom::sequence::{preceded, terminated};
+use nom::Parser;
+
+impl Atom for Trigger {
+    fn parse(i: &str) -> ParseResult<Self> {
+        alt((pipeline, merge_request_merged))(i)
+    }
+}
+
+fn pipeline(i: &str) -> ParseResult<Trigger> {
+    preceded(
+        opt(tag_no_case("the ")).and(tag_no_case("pipeline ")),
+        alt((
+            value(
+                Trigger::PipelineSucceeded,
+                alt((
+                    tag_no_case("succeeds"),
+                    tag_no_case("passes"),
+                    tag_no_case("is green"),
+                )),
+            ),
+            value(
+                Trigger::PipelineFailed,
+                alt((tag_no_case("fails"), tag_no_case("is red"))),
+            ),
+        )),
+    )(i)
+}
+
+fn merge_request_merged(i: &str) -> ParseResult<Trigger> {
+    let this = value(None, alt((tag_no_case("it"), tag_no_case("this"))));
+    let other = map(MergeRequestPtr::parse, Some);
+
+    map(
+        alt((terminated(this, merged), terminated(other, merged))),
+        |merge_request| Trigger::MergeRequestMerged { merge_request },
+    )(i)
+}
+
+fn merged(i: &str) -> ParseResult<&str> {
+    alt((
+        tag_no_case("'s merged"),
+        tag_no_case(" is merged"),
+        tag_no_case(" gets merged"),
+        tag_no_case(" merges"),
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProjectPtr;
+    use lib_gitlab::{MergeRequestIid, ProjectName};
+    use test_case::test_case;
+
+    #[test_case("pipeline succeeds" ; "succeeds")]
+    #[test_case("pipeline passes" ; "passes")]
+    #[test_case("the pipeline is green" ; "is green")]
+    #[test_case("Pipeline Succeeds" ; "mixed case")]
+    fn pipeline_succeeded(input: &str) {
+        assert_eq!(Trigger::PipelineSucceeded, Trigger::parse_unwrap(input));
+    }
+
+    #[test_case("pipeline fails" ; "fails")]
+    #[test_case("the pipeline is red" ; "is red")]
+    fn pipeline_failed(input: &str) {
+        assert_eq!(Trigger::PipelineFailed, Trigger::parse_unwrap(input));
+    }
+
+    #[test_case("it's merged" ; "its merged")]
+    #[test_case("it is merged" ; "it is merged")]
+    #[test_case("this gets merged" ; "this gets merged")]
+    #[test_case("it merges" ; "it merges")]
+    fn this_merge_request_merged(input: &str) {
+        assert_eq!(
+            Trigger::MergeRequestMerged {
+                merge_request: None
+            },
+            Trigger::parse_unwrap(input)
+        );
+    }
+
+    #[test_case("!42 is merged", None ; "iid")]
+    #[test_case("!42 merges", None ; "iid merges")]
+    #[test_case("foo!42 is merged", Some("foo") ; "project and iid")]
+    fn other_merge_request_merged(input: &str, project: Option<&str>) {
+        assert_eq!(
+            Trigger::MergeRequestMerged {
+                merge_request: Some(MergeRequestPtr::Iid {
+                    project: project.map(|project| ProjectPtr::Name {
+                        namespace: None,
+                        name: ProjectName::new(project),
+                    }),
+                    merge_request: MergeRequestIid::new(42),
+                })
+            },
+            Trigger::parse_unwrap(input)
+        );
+    }
+
+    #[test_case("pipeline" ; "pipeline without verb")]
+    #[test_case("!42 is closed" ; "unknown verb")]
+    fn rejects(input: &str) {
+        assert!(Trigger::parse(input).is_err());
+    }
+}
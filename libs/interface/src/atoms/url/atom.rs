@@ -1,17 +1,16 @@
-use crate::Atom;
+use crate::{Atom, ParseResult};
 use nom::bytes::complete::take_till1;
 use nom::combinator::map_res;
-use nom::IResult;
 use std::str::FromStr;
 use url::Url;
 
 impl Atom for Url {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         map_res(url, Url::from_str)(i)
     }
 }
 
-fn url(i: &str) -> IResult<&str, &str> {
+fn url(i: &str) -> ParseResult<&str> {
     take_till1(|i| i == ' ' || i == ')' || i == ']')(i)
 }
 
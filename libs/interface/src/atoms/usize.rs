@@ -1,10 +1,9 @@
-use crate::Atom;
+use crate::{Atom, ParseResult};
 use nom::character::complete::digit1;
 use nom::combinator::map_res;
-use nom::IResult;
 
 impl Atom for usize {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         map_res(digit1, |num: &str| num.parse())(i)
     }
 }
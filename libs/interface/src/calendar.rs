@@ -0,0 +1,219 @@
+use self::rules::*;
+use anyhow::*;
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::BTreeSet;
+use std::iter;
+
+mod ics;
+mod rules;
+
+/// How many days in a row can be non-business days before we give up looking
+/// for a business day; without this limit a calendar without any business days
+/// (e.g. due to an overly eager `.ics` file) would keep us looping forever
+const MAX_NON_BUSINESS_DAYS: usize = 366;
+
+/// Knows which days are business days - used to resolve dates such as `in 2
+/// business days` and, optionally, to move reminders that'd otherwise land on
+/// a weekend or a holiday.
+///
+/// By default, every day except Saturday and Sunday is a business day.
+#[derive(Clone, Debug, Default)]
+pub struct Calendar {
+    /// Holidays happening on specific days (e.g. loaded from an `.ics` file)
+    holidays: BTreeSet<NaiveDate>,
+
+    /// Holidays happening each year (e.g. Christmas or Easter Monday)
+    rules: Vec<HolidayRule>,
+
+    /// When enabled, dates that don't point at a specific day (e.g. `in 3d`)
+    /// get moved to the nearest business day
+    skip_non_business_days: bool,
+}
+
+impl Calendar {
+    /// Adds public holidays of given country (e.g. `PL`).
+    ///
+    /// Only nationwide holidays are supported - regional ones (and days off
+    /// "in lieu") should be provided through an `.ics` file instead.
+    pub fn with_country(mut self, country: &str) -> Result<Self> {
+        let rules = country_rules(country).ok_or_else(|| {
+            anyhow!(
+                "Unknown country `{}` (supported: {})",
+                country,
+                COUNTRIES.join(", ")
+            )
+        })?;
+
+        self.rules.extend_from_slice(rules);
+
+        Ok(self)
+    }
+
+    /// Adds holidays from given iCalendar (`.ics`) file's contents; yearly
+    /// recurring events are supported.
+    pub fn with_ics(mut self, ics: &str) -> Result<Self> {
+        let holidays = ics::parse(ics)?;
+
+        self.holidays.extend(holidays.holidays);
+        self.rules.extend(holidays.rules);
+
+        Ok(self)
+    }
+
+    /// Adds given holidays
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Enables or disables moving dates to the nearest business day (see:
+    /// `DateTime::resolve()`)
+    pub fn with_skip_non_business_days(mut self, skip: bool) -> Self {
+        self.skip_non_business_days = skip;
+        self
+    }
+
+    pub fn skips_non_business_days(&self) -> bool {
+        self.skip_non_business_days
+    }
+
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date) || self.rules.iter().any(|rule| rule.matches(date))
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.is_holiday(date)
+    }
+
+    /// Returns given date if it's a business day, or the nearest business day
+    /// after it; returns `None` if there's no business day within
+    /// `MAX_NON_BUSINESS_DAYS` (or before `NaiveDate` runs out of dates).
+    pub fn next_business_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        iter::successors(Some(date), |date| date.succ_opt())
+            .take(MAX_NON_BUSINESS_DAYS + 1)
+            .find(|date| self.is_business_day(*date))
+    }
+
+    /// Returns the `n`-th business day after given date; e.g. two business
+    /// days after Friday is Tuesday (provided there are no holidays).
+    ///
+    /// Returns `None` under the same circumstances `next_business_day()` does.
+    pub fn add_business_days(&self, mut date: NaiveDate, days: usize) -> Option<NaiveDate> {
+        for _ in 0..days {
+            date = self.next_business_day(date.succ_opt()?)?;
+        }
+
+        Some(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn date(date: &str) -> NaiveDate {
+        date.parse().unwrap()
+    }
+
+    mod add_business_days {
+        use super::*;
+
+        // 2021-01-04 is Monday
+        #[test_case("2021-01-04", 0 => "2021-01-04" ; "monday + 0")]
+        #[test_case("2021-01-04", 1 => "2021-01-05" ; "monday + 1")]
+        #[test_case("2021-01-04", 5 => "2021-01-11" ; "monday + 5")]
+        #[test_case("2021-01-08", 1 => "2021-01-11" ; "friday + 1")]
+        #[test_case("2021-01-08", 2 => "2021-01-12" ; "friday + 2")]
+        #[test_case("2021-01-09", 1 => "2021-01-11" ; "saturday + 1")]
+        #[test_case("2021-01-10", 1 => "2021-01-11" ; "sunday + 1")]
+        fn without_holidays(from: &str, days: usize) -> String {
+            Calendar::default()
+                .add_business_days(date(from), days)
+                .unwrap()
+                .to_string()
+        }
+
+        #[test_case("2021-12-23", 1 => "2021-12-28" ; "over christmas")]
+        #[test_case("2021-12-24", 1 => "2021-12-28" ; "from christmas eve")]
+        #[test_case("2021-12-22", 3 => "2021-12-29" ; "through christmas")]
+        fn with_holidays(from: &str, days: usize) -> String {
+            Calendar::default()
+                .with_holidays(vec![date("2021-12-24"), date("2021-12-27")])
+                .add_business_days(date(from), days)
+                .unwrap()
+                .to_string()
+        }
+    }
+
+    mod next_business_day {
+        use super::*;
+
+        #[test_case("2021-01-08" => "2021-01-08" ; "friday")]
+        #[test_case("2021-01-09" => "2021-01-11" ; "saturday")]
+        #[test_case("2021-01-10" => "2021-01-11" ; "sunday")]
+        #[test_case("2021-11-11" => "2021-11-12" ; "independence day")]
+        #[test_case("2021-12-25" => "2021-12-27" ; "christmas")]
+        fn test(from: &str) -> String {
+            Calendar::default()
+                .with_country("PL")
+                .unwrap()
+                .next_business_day(date(from))
+                .unwrap()
+                .to_string()
+        }
+
+        #[test]
+        fn gives_up_when_there_are_no_business_days() {
+            let from = date("2021-01-01");
+
+            let calendar = Calendar::default()
+                .with_holidays(iter::successors(Some(from), |date| date.succ_opt()).take(2 * 366));
+
+            assert_eq!(None, calendar.next_business_day(from));
+            assert_eq!(None, calendar.add_business_days(from, 1));
+        }
+
+        #[test]
+        fn gives_up_at_the_end_of_time() {
+            let calendar = Calendar::default();
+
+            assert_eq!(None, calendar.add_business_days(chrono::naive::MAX_DATE, 1));
+        }
+    }
+
+    mod with_country {
+        use super::*;
+
+        #[test_case("PL", "2021-01-06" ; "pl: epiphany")]
+        #[test_case("PL", "2021-04-05" ; "pl: easter monday")]
+        #[test_case("PL", "2021-06-03" ; "pl: corpus christi")]
+        #[test_case("PL", "2021-11-11" ; "pl: independence day")]
+        #[test_case("DE", "2021-04-02" ; "de: good friday")]
+        #[test_case("DE", "2021-05-13" ; "de: ascension day")]
+        #[test_case("DE", "2021-10-03" ; "de: german unity day")]
+        #[test_case("GB", "2021-05-03" ; "gb: early may bank holiday")]
+        #[test_case("GB", "2021-05-31" ; "gb: spring bank holiday")]
+        #[test_case("GB", "2021-08-30" ; "gb: summer bank holiday")]
+        #[test_case("US", "2021-01-18" ; "us: martin luther king jr day")]
+        #[test_case("US", "2021-05-31" ; "us: memorial day")]
+        #[test_case("US", "2021-11-25" ; "us: thanksgiving")]
+        #[test_case("us", "2022-11-24" ; "us: thanksgiving lowercase")]
+        fn recognizes_holidays(country: &str, holiday: &str) {
+            let calendar = Calendar::default().with_country(country).unwrap();
+
+            assert!(calendar.is_holiday(date(holiday)));
+            assert!(!calendar.is_holiday(date(holiday).succ()));
+        }
+
+        #[test]
+        fn fails_on_unknown_country() {
+            let err = Calendar::default().with_country("XX").unwrap_err();
+
+            assert_eq!(
+                "Unknown country `XX` (supported: DE, GB, PL, US)",
+                err.to_string()
+            );
+        }
+    }
+}
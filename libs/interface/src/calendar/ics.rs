@@ -0,0 +1,154 @@
+use super::HolidayRule;
+use anyhow::*;
+use chrono::{Datelike, NaiveDate};
+use ical::parser::ical::component::IcalEvent;
+use ical::IcalParser;
+
+#[derive(Debug, Default)]
+pub(super) struct Holidays {
+    /// Holidays happening on specific days
+    pub holidays: Vec<NaiveDate>,
+
+    /// Holidays happening each year (i.e. events with `RRULE:FREQ=YEARLY`)
+    pub rules: Vec<HolidayRule>,
+}
+
+/// Extracts holidays from given iCalendar file's contents.
+///
+/// Each event is treated as a day off, regardless of its time (if any); events
+/// spanning a few days (`DTEND`) mark all of those days.
+pub(super) fn parse(ics: &str) -> Result<Holidays> {
+    let mut holidays = Holidays::default();
+
+    for calendar in IcalParser::new(ics.as_bytes()) {
+        let calendar = calendar.map_err(|err| anyhow!("Couldn't parse calendar: {}", err))?;
+
+        for event in calendar.events {
+            parse_event(&mut holidays, &event).with_context(|| {
+                format!(
+                    "Couldn't parse event `{}`",
+                    property(&event, "SUMMARY").unwrap_or("?")
+                )
+            })?;
+        }
+    }
+
+    Ok(holidays)
+}
+
+fn parse_event(holidays: &mut Holidays, event: &IcalEvent) -> Result<()> {
+    let start = property(event, "DTSTART").context("Missing `DTSTART`")?;
+    let start = parse_date(start)?;
+
+    // `DTEND` is exclusive, so a single-day event ends on the next day
+    let end = match property(event, "DTEND") {
+        Some(end) => parse_date(end)?.max(start.succ()),
+        None => start.succ(),
+    };
+
+    let is_yearly = property(event, "RRULE").map_or(false, |rrule| {
+        rrule.split(';').any(|part| part == "FREQ=YEARLY")
+    });
+
+    let mut date = start;
+
+    while date < end {
+        if is_yearly {
+            holidays.rules.push(HolidayRule::Fixed {
+                month: date.month(),
+                day: date.day(),
+            });
+        } else {
+            holidays.holidays.push(date);
+        }
+
+        date = date.succ();
+    }
+
+    Ok(())
+}
+
+fn property<'a>(event: &'a IcalEvent, name: &str) -> Option<&'a str> {
+    event
+        .properties
+        .iter()
+        .find(|property| property.name == name)
+        .and_then(|property| property.value.as_deref())
+}
+
+/// Parses either a date (`20201224`) or a date-time (`20201224T100000Z`)
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    value
+        .get(0..8)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+        .with_context(|| format!("Invalid date: {}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Weekday;
+
+    const ICS: &str = "\
+BEGIN:VCALENDAR
+VERSION:2.0
+PRODID:-//Janet//Tests//EN
+BEGIN:VEVENT
+UID:1
+DTSTART;VALUE=DATE:20211224
+SUMMARY:Christmas Eve
+END:VEVENT
+BEGIN:VEVENT
+UID:2
+DTSTART;VALUE=DATE:20211227
+DTEND;VALUE=DATE:20211229
+SUMMARY:Company retreat
+END:VEVENT
+BEGIN:VEVENT
+UID:3
+DTSTART:20210315T090000Z
+SUMMARY:Founders' day
+RRULE:FREQ=YEARLY
+END:VEVENT
+END:VCALENDAR
+";
+
+    #[test]
+    fn test() {
+        let holidays = parse(ICS).unwrap();
+
+        assert_eq!(
+            vec![
+                NaiveDate::from_ymd(2021, 12, 24),
+                NaiveDate::from_ymd(2021, 12, 27),
+                NaiveDate::from_ymd(2021, 12, 28),
+            ],
+            holidays.holidays
+        );
+
+        assert_eq!(
+            vec![HolidayRule::Fixed { month: 3, day: 15 }],
+            holidays.rules
+        );
+    }
+
+    #[test]
+    fn fails_on_invalid_dates() {
+        let ics = ICS.replace("20211224", "2021-12-24");
+        let err = parse(&ics).unwrap_err();
+
+        assert_eq!("Couldn't parse event `Christmas Eve`", err.to_string());
+    }
+
+    #[test]
+    fn is_used_by_calendar() {
+        let calendar = crate::Calendar::default().with_ics(ICS).unwrap();
+
+        // Founders' day happens each year, so 2030-03-15 (Friday) is a holiday
+        let founders_day = NaiveDate::from_ymd(2030, 3, 15);
+
+        assert_eq!(Weekday::Fri, founders_day.weekday());
+        assert!(!calendar.is_business_day(founders_day));
+        assert!(calendar.is_business_day(founders_day.pred()));
+    }
+}
@@ -0,0 +1,161 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Countries for which we've got built-in lists of holidays
+pub(crate) const COUNTRIES: &[&str] = &["DE", "GB", "PL", "US"];
+
+/// Describes a holiday that happens each year
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum HolidayRule {
+    /// Holiday happening on the same day each year, e.g. Christmas
+    Fixed { month: u32, day: u32 },
+
+    /// Holiday happening given number of days after (Western) Easter Sunday,
+    /// e.g. Easter Monday
+    Easter { offset: i64 },
+
+    /// Holiday happening on the `n`-th given weekday of a month, e.g.
+    /// Thanksgiving; negative `n` counts from the end of the month (i.e. `-1`
+    /// means the last one)
+    NthWeekday {
+        month: u32,
+        weekday: Weekday,
+        n: i32,
+    },
+}
+
+impl HolidayRule {
+    pub(crate) fn matches(self, date: NaiveDate) -> bool {
+        match self {
+            Self::Fixed { month, day } => date.month() == month && date.day() == day,
+
+            Self::Easter { offset } => easter_sunday(date.year()) + Duration::days(offset) == date,
+
+            Self::NthWeekday { month, weekday, n } => {
+                if date.month() != month || date.weekday() != weekday {
+                    return false;
+                }
+
+                if n > 0 {
+                    (date.day0() / 7) as i32 + 1 == n
+                } else {
+                    let days_left = (1..)
+                        .take_while(|&days| (date + Duration::days(days)).month() == month)
+                        .count();
+
+                    (days_left / 7) as i32 + 1 == -n
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn country_rules(country: &str) -> Option<&'static [HolidayRule]> {
+    match country.to_uppercase().as_str() {
+        "DE" => Some(DE),
+        "GB" => Some(GB),
+        "PL" => Some(PL),
+        "US" => Some(US),
+        _ => None,
+    }
+}
+
+const DE: &[HolidayRule] = &[
+    fixed(1, 1),   // New Year's Day
+    easter(-2),    // Good Friday
+    easter(1),     // Easter Monday
+    fixed(5, 1),   // Labour Day
+    easter(39),    // Ascension Day
+    easter(50),    // Whit Monday
+    fixed(10, 3),  // German Unity Day
+    fixed(12, 25), // Christmas Day
+    fixed(12, 26), // Second Day of Christmas
+];
+
+const GB: &[HolidayRule] = &[
+    fixed(1, 1),              // New Year's Day
+    easter(-2),               // Good Friday
+    easter(1),                // Easter Monday
+    nth(1, Weekday::Mon, 5),  // Early May Bank Holiday
+    nth(-1, Weekday::Mon, 5), // Spring Bank Holiday
+    nth(-1, Weekday::Mon, 8), // Summer Bank Holiday
+    fixed(12, 25),            // Christmas Day
+    fixed(12, 26),            // Boxing Day
+];
+
+const PL: &[HolidayRule] = &[
+    fixed(1, 1),   // New Year's Day
+    fixed(1, 6),   // Epiphany
+    easter(0),     // Easter Sunday
+    easter(1),     // Easter Monday
+    fixed(5, 1),   // Labour Day
+    fixed(5, 3),   // Constitution Day
+    easter(49),    // Pentecost
+    easter(60),    // Corpus Christi
+    fixed(8, 15),  // Assumption Day
+    fixed(11, 1),  // All Saints' Day
+    fixed(11, 11), // Independence Day
+    fixed(12, 25), // Christmas Day
+    fixed(12, 26), // Second Day of Christmas
+];
+
+const US: &[HolidayRule] = &[
+    fixed(1, 1),              // New Year's Day
+    nth(3, Weekday::Mon, 1),  // Martin Luther King Jr. Day
+    nth(3, Weekday::Mon, 2),  // Washington's Birthday
+    nth(-1, Weekday::Mon, 5), // Memorial Day
+    fixed(6, 19),             // Juneteenth
+    fixed(7, 4),              // Independence Day
+    nth(1, Weekday::Mon, 9),  // Labor Day
+    nth(2, Weekday::Mon, 10), // Columbus Day
+    fixed(11, 11),            // Veterans Day
+    nth(4, Weekday::Thu, 11), // Thanksgiving Day
+    fixed(12, 25),            // Christmas Day
+];
+
+const fn fixed(month: u32, day: u32) -> HolidayRule {
+    HolidayRule::Fixed { month, day }
+}
+
+const fn easter(offset: i64) -> HolidayRule {
+    HolidayRule::Easter { offset }
+}
+
+const fn nth(n: i32, weekday: Weekday, month: u32) -> HolidayRule {
+    HolidayRule::NthWeekday { month, weekday, n }
+}
+
+/// Returns date of (Western) Easter Sunday in given year, using the Anonymous
+/// Gregorian algorithm
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2019 => "2019-04-21" ; "2019")]
+    #[test_case(2020 => "2020-04-12" ; "2020")]
+    #[test_case(2021 => "2021-04-04" ; "2021")]
+    #[test_case(2022 => "2022-04-17" ; "2022")]
+    #[test_case(2038 => "2038-04-25" ; "2038")]
+    fn computes_easter_sunday(year: i32) -> String {
+        easter_sunday(year).to_string()
+    }
+}
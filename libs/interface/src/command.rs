@@ -1,12 +1,14 @@
-pub use self::{action::*, merge_request::*};
+pub use self::{action::*, merge_request::*, on_activity::*, spec::*};
 
 mod action;
 mod merge_request;
+mod on_activity;
+mod spec;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A generic command accepted by Janet
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum Command {
     MergeRequest {
@@ -14,3 +16,86 @@ pub enum Command {
         cmd: MergeRequestCommand,
     },
 }
+
+impl Command {
+    /// Returns a short, stable name of this command's variant (e.g.
+    /// `merge_request.hi`), suitable for e.g. labelling metrics
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::MergeRequest { cmd, .. } => match cmd {
+                MergeRequestCommand::Help { .. } => "merge_request.help",
+                MergeRequestCommand::Hi => "merge_request.hi",
+                MergeRequestCommand::ManageDependency { .. } => "merge_request.manage_dependency",
+                MergeRequestCommand::ManageReminder { .. } => "merge_request.manage_reminder",
+                MergeRequestCommand::ManageNotifications { .. } => {
+                    "merge_request.manage_notifications"
+                }
+                MergeRequestCommand::ManageDigest { .. } => "merge_request.manage_digest",
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InterfaceError, MergeRequestPtr};
+    use lib_gitlab::{DiscussionId, MergeRequestIid, UserId};
+    use test_case::test_case;
+
+    fn ctxt() -> MergeRequestCommandContext {
+        MergeRequestCommandContext {
+            user: UserId::new(250),
+            merge_request: MergeRequestPtr::Iid {
+                project: None,
+                merge_request: MergeRequestIid::new(1),
+            },
+            discussion: DiscussionId::new("cafebabe"),
+        }
+    }
+
+    #[test_case("hi" ; "hi")]
+    #[test_case("depends on !123" ; "add dependency")]
+    #[test_case("-depends on foo/bar!123" ; "remove dependency")]
+    #[test_case("depends on https://gitlab.com/foo/bar/-/merge_requests/123" ; "add dependency by url")]
+    #[test_case("remind me in 3d 2h" ; "relative reminder")]
+    #[test_case("remind me tomorrow at 12: rebase it!" ; "absolute reminder with message")]
+    #[test_case("remind me if no activity in 2d" ; "inactivity reminder")]
+    #[test_case("notify me via slack" ; "notifications")]
+    #[test_case("digest me weekly on friday at 8am Europe/Warsaw" ; "digest")]
+    #[test_case("-digest me" ; "remove digest")]
+    #[test_case("help remind" ; "help")]
+    fn can_be_deserialized_back(cmd: &str) {
+        let cmd = MergeRequestCommand::parse(ctxt(), cmd).unwrap();
+        let json = serde_json::to_string(&cmd).unwrap();
+        let deserialized: Command = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, serde_json::to_string(&deserialized).unwrap());
+    }
+
+    #[test_case("remind me at 25" => ("hour must be 0-23", 13, "25".to_string()) ; "invalid hour")]
+    #[test_case("remind me 12:60" => ("minute must be 0-59", 10, "12:60".to_string()) ; "invalid minute")]
+    #[test_case("remind me 2018-02-30 at 12" => ("there's no such day in that month", 10, "2018-02-30".to_string()) ; "invalid date")]
+    #[test_case("remind me in 99999999d" => ("that's too far in the future", 13, "99999999d".to_string()) ; "too many days")]
+    #[test_case("remind me in 300000 years" => ("that's too far in the future", 13, "300000".to_string()) ; "too many years")]
+    #[test_case("remind me in 99999999 hours" => ("that's too far in the future", 13, "99999999".to_string()) ; "too many hours")]
+    fn reports_invalid_values(cmd: &str) -> (&'static str, usize, String) {
+        match MergeRequestCommand::parse(ctxt(), cmd) {
+            Err(InterfaceError::InvalidCommand {
+                reason,
+                position,
+                fragment,
+            }) => (reason, position, fragment),
+            result => panic!("Expected invalid command, got: {:?}", result),
+        }
+    }
+
+    #[test_case("foo" ; "unknown")]
+    #[test_case("remind me at noon" ; "unknown time")]
+    fn reports_unknown_commands(cmd: &str) {
+        assert!(matches!(
+            MergeRequestCommand::parse(ctxt(), cmd),
+            Err(InterfaceError::UnknownCommand)
+        ));
+    }
+}
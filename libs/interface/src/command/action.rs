@@ -1,10 +1,10 @@
 mod parse;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Some actions can be prepended with `+` or `-` (e.g. `-depends on`) - this
 /// enum allows to distinguish which action has been meant by the user.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum CommandAction {
     Add,
     Remove,
@@ -1,11 +1,10 @@
-use crate::{Atom, CommandAction};
+use crate::{Atom, CommandAction, ParseResult};
 use nom::branch::alt;
 use nom::character::complete::{anychar, char};
 use nom::combinator::{peek, value};
-use nom::IResult;
 
 impl Atom for CommandAction {
-    fn parse(i: &str) -> IResult<&str, Self> {
+    fn parse(i: &str) -> ParseResult<Self> {
         let remove = value(CommandAction::Remove, char('-'));
         let add = value(CommandAction::Add, peek(anychar));
 
@@ -1,13 +1,35 @@
 mod parse;
 
-use crate::{Command, CommandAction, DateTime, InterfaceError, InterfaceResult, MergeRequestPtr};
+use crate::{
+    Command,
+    CommandAction,
+    DateTime,
+    DigestSchedule,
+    InterfaceError,
+    InterfaceResult,
+    MergeRequestPtr,
+    NotificationChannel,
+    OnActivity,
+    Recipient,
+    Trigger,
+};
 use lib_gitlab::{DiscussionId, UserId};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A command issued from the context of a merge request
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum MergeRequestCommand {
+    /// E.g.:
+    ///
+    /// - `help`
+    /// - `help remind`
+    Help {
+        /// Name of the command to explain (see: `CommandSpec::name`); `None`
+        /// lists all of them
+        command: Option<String>,
+    },
+
     /// E.g.:
     ///
     /// - `hi`
@@ -27,13 +49,53 @@ pub enum MergeRequestCommand {
     ///
     /// - `remind me tomorrow`
     /// - `remind in 3d: rebase it!`
+    /// - `remind @someone and reviewers on friday`
+    /// - `remind me when !42 is merged or in 3d`
+    /// - `remind me if no activity in 2d`
+    /// - `remind reviewers in 2d unless someone replies`
     ManageReminder {
+        /// People to remind; when not specified, it's just the user who
+        /// issued the command
+        #[serde(default = "default_recipients")]
+        recipients: Vec<Recipient>,
         message: Option<String>,
-        remind_at: DateTime,
+
+        /// Event that should fire the reminder; when both `trigger` and
+        /// `remind_at` are present, whichever happens first wins
+        #[serde(default)]
+        trigger: Option<Trigger>,
+
+        /// Time when the reminder should fire; it's missing only for
+        /// reminders with a `trigger` and no timeout
+        remind_at: Option<DateTime>,
+
+        /// What should happen when someone writes a note on the merge
+        /// request before the reminder fires; `None` means nothing
+        #[serde(default)]
+        on_activity: Option<OnActivity>,
+    },
+
+    /// E.g.:
+    ///
+    /// - `notify me via slack`
+    /// - `notify me via gitlab`
+    ManageNotifications { channel: NotificationChannel },
+
+    /// E.g.:
+    ///
+    /// - `digest me daily at 9:00`
+    /// - `digest me weekly on monday Europe/Warsaw`
+    /// - `-digest me`
+    ManageDigest {
+        action: CommandAction,
+
+        /// When the digest should be sent; it's missing only for
+        /// `CommandAction::Remove`
+        schedule: Option<DigestSchedule>,
     },
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct MergeRequestCommandContext {
     /// User who issued the command
     pub user: UserId,
@@ -45,6 +107,10 @@ pub struct MergeRequestCommandContext {
     pub discussion: DiscussionId,
 }
 
+fn default_recipients() -> Vec<Recipient> {
+    vec![Recipient::Me]
+}
+
 impl MergeRequestCommand {
     #[tracing::instrument]
     pub fn parse(ctxt: MergeRequestCommandContext, cmd: &str) -> InterfaceResult<Command> {
@@ -52,6 +118,6 @@ impl MergeRequestCommand {
 
         parse::parse(cmd)
             .map(|(_, cmd)| Command::MergeRequest { ctxt, cmd })
-            .map_err(|_| InterfaceError::UnknownCommand)
+            .map_err(|err| InterfaceError::from_parse_error(cmd, err))
     }
 }
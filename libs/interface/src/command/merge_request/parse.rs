@@ -1,21 +1,66 @@
-use crate::{Atom, CommandAction, DateTime, MergeRequestCommand, MergeRequestPtr};
+use crate::{
+    Atom,
+    CommandAction,
+    CommandSpec,
+    DateTime,
+    DigestSchedule,
+    MergeRequestCommand,
+    MergeRequestPtr,
+    NotificationChannel,
+    OnActivity,
+    ParseError,
+    ParseResult,
+    Recipient,
+    Trigger,
+};
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_while};
-use nom::combinator::{all_consuming, opt, rest};
-use nom::{IResult, Parser};
+use nom::character::complete::space1;
+use nom::combinator::{all_consuming, opt, rest, value};
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, terminated};
+use nom::Parser;
 
-pub fn parse(cmd: &str) -> IResult<&str, MergeRequestCommand> {
-    all_consuming(alt((hi, manage_dependency, manage_reminder)))(cmd)
+pub fn parse(cmd: &str) -> ParseResult<MergeRequestCommand> {
+    all_consuming(alt((
+        help,
+        hi,
+        manage_dependency,
+        manage_reminder,
+        manage_notifications,
+        manage_digest,
+    )))(cmd)
 }
 
-fn hi(i: &str) -> IResult<&str, MergeRequestCommand> {
+fn help(i: &str) -> ParseResult<MergeRequestCommand> {
+    let (i, _) = tag_no_case("help")(i)?;
+    let (i, name) = opt(preceded(space1, rest))(i)?;
+
+    let name = match name.map(str::trim) {
+        Some(name) if !name.is_empty() => name,
+        _ => return Ok((i, MergeRequestCommand::Help { command: None })),
+    };
+
+    match CommandSpec::find(name) {
+        Some(spec) => Ok((
+            i,
+            MergeRequestCommand::Help {
+                command: Some(spec.name.to_string()),
+            },
+        )),
+
+        None => ParseError::fail(name, "there's no such command"),
+    }
+}
+
+fn hi(i: &str) -> ParseResult<MergeRequestCommand> {
     let (i, _) = alt((tag_no_case("hi"), tag_no_case("hello")))(i)?;
     let (i, _) = take_while(|c| ['.', '!', ' '].iter().any(|&c2| c2 == c))(i)?;
 
     Ok((i, MergeRequestCommand::Hi))
 }
 
-fn manage_dependency(i: &str) -> IResult<&str, MergeRequestCommand> {
+fn manage_dependency(i: &str) -> ParseResult<MergeRequestCommand> {
     CommandAction::parse
         .and(tag_no_case("depends on "))
         .and(MergeRequestPtr::parse)
@@ -28,19 +73,95 @@ fn manage_dependency(i: &str) -> IResult<&str, MergeRequestCommand> {
         .parse(i)
 }
 
-fn manage_reminder(i: &str) -> IResult<&str, MergeRequestCommand> {
-    tag_no_case("remind ")
-        .and(opt(tag_no_case("me ")))
-        .and(DateTime::parse)
-        .and(opt(tag(":").and(rest)))
-        .map(|(((_, _), remind_at), message)| {
-            let message = message.map(|(_, message)| message.trim().to_string());
+fn manage_reminder(i: &str) -> ParseResult<MergeRequestCommand> {
+    let (i, _) = tag_no_case("remind ")(i)?;
+    let (i, recipients) = opt(terminated(recipients, space1))(i)?;
+
+    let (i, (trigger, remind_at, on_activity)) = alt((
+        preceded(tag_no_case("when "), Trigger::parse)
+            .and(opt(preceded(tag_no_case(" or "), DateTime::parse)))
+            .map(|(trigger, remind_at)| (Some(trigger), remind_at, None)),
+        preceded(tag_no_case("if "), inactivity)
+            .and(DateTime::parse)
+            .map(|(on_activity, remind_at)| (None, Some(remind_at), Some(on_activity))),
+        DateTime::parse
+            .and(opt(unless_someone_replies))
+            .map(|(remind_at, on_activity)| (None, Some(remind_at), on_activity)),
+    ))(i)?;
+
+    let (i, message) = opt(preceded(tag(":"), rest))(i)?;
+
+    Ok((
+        i,
+        MergeRequestCommand::ManageReminder {
+            recipients: recipients.unwrap_or_else(|| vec![Recipient::Me]),
+            message: message.map(|message| message.trim().to_string()),
+            trigger,
+            remind_at,
+            on_activity,
+        },
+    ))
+}
+
+/// Parses a condition that follows `if`, e.g. `no activity ` or `nobody
+/// replies `
+fn inactivity(i: &str) -> ParseResult<OnActivity> {
+    alt((
+        value(OnActivity::Postpone, tag_no_case("no activity ")),
+        value(
+            OnActivity::Cancel,
+            alt((tag_no_case("nobody"), tag_no_case("no one"))).and(tag_no_case(" replies ")),
+        ),
+    ))(i)
+}
+
+/// Parses e.g. ` unless someone replies`
+fn unless_someone_replies(i: &str) -> ParseResult<OnActivity> {
+    value(
+        OnActivity::Cancel,
+        tag_no_case(" unless ")
+            .and(alt((
+                tag_no_case("someone"),
+                tag_no_case("somebody"),
+                tag_no_case("anyone"),
+            )))
+            .and(tag_no_case(" replies")),
+    )(i)
+}
+
+/// Parses a list of recipients, e.g. `@someone, @frontend-team and reviewers`
+fn recipients(i: &str) -> ParseResult<Vec<Recipient>> {
+    separated_list1(
+        alt((tag(", "), tag(","), tag_no_case(" and "))),
+        Recipient::parse,
+    )(i)
+}
 
-            MergeRequestCommand::ManageReminder { remind_at, message }
-        })
+fn manage_notifications(i: &str) -> ParseResult<MergeRequestCommand> {
+    tag_no_case("notify ")
+        .and(opt(tag_no_case("me ")))
+        .and(tag_no_case("via "))
+        .and(NotificationChannel::parse)
+        .map(|(_, channel)| MergeRequestCommand::ManageNotifications { channel })
         .parse(i)
 }
 
+fn manage_digest(i: &str) -> ParseResult<MergeRequestCommand> {
+    let (i, action) = CommandAction::parse(i)?;
+    let (i, _) = tag_no_case("digest")(i)?;
+    let (i, _) = opt(tag_no_case(" me"))(i)?;
+
+    let (i, schedule) = match action {
+        CommandAction::Add => {
+            let (i, schedule) = preceded(space1, DigestSchedule::parse)(i)?;
+            (i, Some(schedule))
+        }
+        CommandAction::Remove => (i, None),
+    };
+
+    Ok((i, MergeRequestCommand::ManageDigest { action, schedule }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,6 +178,38 @@ mod tests {
         assert_eq!(expected, actual, "Input: {}", input);
     }
 
+    mod help {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case("help", None ; "all")]
+        #[test_case("HELP ", None ; "all with trailing space")]
+        #[test_case("help remind", Some("remind") ; "remind")]
+        #[test_case("help Depends On", Some("depends on") ; "depends on")]
+        fn test(input: &str, command: Option<&str>) {
+            assert(
+                MergeRequestCommand::Help {
+                    command: command.map(ToString::to_string),
+                },
+                input,
+            );
+        }
+
+        #[test]
+        fn glued_word() {
+            assert!(matches!(parse("helpful"), Err(nom::Err::Error(_))));
+        }
+
+        #[test]
+        fn unknown_command() {
+            let actual = parse("help sandwich");
+
+            let expected = ParseError::fail("sandwich", "there's no such command");
+
+            assert_eq!(expected, actual);
+        }
+    }
+
     mod hi {
         use super::*;
 
@@ -156,10 +309,13 @@ mod tests {
         fn with_message(input: &str) {
             assert(
                 MergeRequestCommand::ManageReminder {
-                    remind_at: DateTime {
+                    recipients: vec![Recipient::Me],
+                    trigger: None,
+                    remind_at: Some(DateTime {
                         date: Some(Date::Relative(RelativeDate::Days(1))),
                         time: Some(Time::Absolute(NaiveTime::from_hms(12, 00, 00))),
-                    },
+                    }),
+                    on_activity: None,
                     message: Some("important important!".to_string()),
                 },
                 input,
@@ -171,14 +327,188 @@ mod tests {
         fn without_message(input: &str) {
             assert(
                 MergeRequestCommand::ManageReminder {
-                    remind_at: DateTime {
+                    recipients: vec![Recipient::Me],
+                    trigger: None,
+                    remind_at: Some(DateTime {
                         date: Some(Date::Relative(RelativeDate::Days(1))),
                         time: Some(Time::Absolute(NaiveTime::from_hms(12, 00, 00))),
-                    },
+                    }),
+                    on_activity: None,
+                    message: None,
+                },
+                input,
+            );
+        }
+
+        #[test_case("remind @someone tomorrow", vec![Recipient::Mention("someone".into())] ; "user")]
+        #[test_case("remind @frontend-team tomorrow", vec![Recipient::Mention("frontend-team".into())] ; "group")]
+        #[test_case("remind reviewers tomorrow", vec![Recipient::Reviewers] ; "reviewers")]
+        #[test_case("remind me and @someone tomorrow", vec![Recipient::Me, Recipient::Mention("someone".into())] ; "me and user")]
+        #[test_case("remind @a, @b and reviewers tomorrow", vec![Recipient::Mention("a".into()), Recipient::Mention("b".into()), Recipient::Reviewers] ; "many")]
+        fn with_recipients(input: &str, recipients: Vec<Recipient>) {
+            assert(
+                MergeRequestCommand::ManageReminder {
+                    recipients,
+                    trigger: None,
+                    remind_at: Some(DateTime {
+                        date: Some(Date::Relative(RelativeDate::Days(1))),
+                        time: None,
+                    }),
+                    on_activity: None,
                     message: None,
                 },
                 input,
             );
         }
     }
+
+    mod manage_triggered_reminder {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case("remind me when pipeline succeeds", Trigger::PipelineSucceeded ; "pipeline succeeds")]
+        #[test_case("remind when the pipeline fails", Trigger::PipelineFailed ; "pipeline fails")]
+        #[test_case("remind me when it's merged", Trigger::MergeRequestMerged { merge_request: None } ; "this merge request merged")]
+        fn without_timeout(input: &str, trigger: Trigger) {
+            assert(
+                MergeRequestCommand::ManageReminder {
+                    recipients: vec![Recipient::Me],
+                    message: None,
+                    trigger: Some(trigger),
+                    remind_at: None,
+                    on_activity: None,
+                },
+                input,
+            );
+        }
+
+        #[test]
+        fn with_timeout_and_message() {
+            assert(
+                MergeRequestCommand::ManageReminder {
+                    recipients: vec![Recipient::Reviewers],
+                    message: Some("rebase it!".to_string()),
+                    trigger: Some(Trigger::MergeRequestMerged {
+                        merge_request: Some(MergeRequestPtr::Iid {
+                            project: None,
+                            merge_request: MergeRequestIid::new(42),
+                        }),
+                    }),
+                    remind_at: Some(DateTime {
+                        date: Some(Date::Relative(RelativeDate::Days(3))),
+                        time: None,
+                    }),
+                    on_activity: None,
+                },
+                "remind reviewers when !42 is merged or in 3d: rebase it!",
+            );
+        }
+    }
+
+    mod manage_inactivity_reminder {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case("remind me if no activity in 2d", OnActivity::Postpone ; "no activity")]
+        #[test_case("remind me if nobody replies in 2d", OnActivity::Cancel ; "nobody replies")]
+        #[test_case("remind if no one replies in 2d", OnActivity::Cancel ; "no one replies")]
+        #[test_case("remind me in 2d unless someone replies", OnActivity::Cancel ; "unless someone replies")]
+        #[test_case("remind in 2d unless anyone replies", OnActivity::Cancel ; "unless anyone replies")]
+        fn test(input: &str, on_activity: OnActivity) {
+            assert(
+                MergeRequestCommand::ManageReminder {
+                    recipients: vec![Recipient::Me],
+                    message: None,
+                    trigger: None,
+                    remind_at: Some(DateTime {
+                        date: Some(Date::Relative(RelativeDate::Days(2))),
+                        time: None,
+                    }),
+                    on_activity: Some(on_activity),
+                },
+                input,
+            );
+        }
+
+        #[test]
+        fn with_recipients_and_message() {
+            assert(
+                MergeRequestCommand::ManageReminder {
+                    recipients: vec![Recipient::Reviewers],
+                    message: Some("please take a look".to_string()),
+                    trigger: None,
+                    remind_at: Some(DateTime {
+                        date: Some(Date::Relative(RelativeDate::Days(2))),
+                        time: None,
+                    }),
+                    on_activity: Some(OnActivity::Cancel),
+                },
+                "remind reviewers in 2d unless someone replies: please take a look",
+            );
+        }
+    }
+
+    mod manage_notifications {
+        use super::*;
+        use test_case::test_case;
+
+        #[test_case("notify me via slack", NotificationChannel::Slack ; "slack")]
+        #[test_case("notify me via Matrix", NotificationChannel::Matrix ; "matrix")]
+        #[test_case("notify me via webhook", NotificationChannel::Webhook ; "webhook")]
+        #[test_case("notify via gitlab", NotificationChannel::GitLab ; "gitlab without me")]
+        fn test(input: &str, channel: NotificationChannel) {
+            assert(MergeRequestCommand::ManageNotifications { channel }, input);
+        }
+    }
+
+    mod manage_digest {
+        use super::*;
+        use crate::DayOfWeek;
+        use chrono::NaiveTime;
+        use test_case::test_case;
+
+        #[test_case("digest me daily", None, 9 ; "daily")]
+        #[test_case("digest daily at 8", None, 8 ; "daily without me")]
+        #[test_case("digest me weekly on friday at 3pm", Some(DayOfWeek::Friday), 15 ; "weekly")]
+        fn add(input: &str, day: Option<DayOfWeek>, hour: u32) {
+            assert(
+                MergeRequestCommand::ManageDigest {
+                    action: CommandAction::Add,
+                    schedule: Some(DigestSchedule {
+                        day,
+                        time: NaiveTime::from_hms(hour, 0, 0),
+                        timezone: None,
+                    }),
+                },
+                input,
+            );
+        }
+
+        #[test]
+        fn add_with_timezone() {
+            assert(
+                MergeRequestCommand::ManageDigest {
+                    action: CommandAction::Add,
+                    schedule: Some(DigestSchedule {
+                        day: Some(DayOfWeek::Monday),
+                        time: NaiveTime::from_hms(8, 0, 0),
+                        timezone: Some("Europe/Warsaw".parse().unwrap()),
+                    }),
+                },
+                "digest me weekly at 8am Europe/Warsaw",
+            );
+        }
+
+        #[test_case("-digest me" ; "with me")]
+        #[test_case("-digest" ; "without me")]
+        fn remove(input: &str) {
+            assert(
+                MergeRequestCommand::ManageDigest {
+                    action: CommandAction::Remove,
+                    schedule: None,
+                },
+                input,
+            );
+        }
+    }
 }
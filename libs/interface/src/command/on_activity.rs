@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// What should happen to a reminder when someone writes a note on its merge
+/// request (e.g. `remind me if no activity in 2d`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum OnActivity {
+    /// Reminder is pushed back, so that it fires only after the merge request
+    /// remains quiet for the entire period
+    Postpone,
+
+    /// Reminder is cancelled once someone replies in its discussion
+    Cancel,
+}
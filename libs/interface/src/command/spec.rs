@@ -0,0 +1,202 @@
+/// Description of a command that's understood by Janet; used to generate the
+/// `help` and to suggest what user might've meant when their command couldn't
+/// be parsed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CommandSpec {
+    /// Command's name (which is also the phrase the command starts with), e.g.
+    /// `remind`
+    pub name: &'static str,
+
+    /// E.g. `remind me <when>[: <message>]`
+    pub usage: &'static str,
+
+    /// E.g. `pings you after given time passes`
+    pub description: &'static str,
+
+    /// E.g. `remind me tomorrow`
+    pub examples: &'static [&'static str],
+}
+
+/// All the commands understood by Janet, in the order they're presented in
+/// the `help`.
+///
+/// Each example gets parsed by our tests, so when you're changing the grammar,
+/// make sure to keep this list up to date.
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "hi",
+        usage: "hi",
+        description: "says hi back",
+        examples: &["hi", "hello!"],
+    },
+    CommandSpec {
+        name: "depends on",
+        usage: "[-]depends on <merge request>",
+        description: "lets you know when given merge request gets merged, closed or re-opened",
+        examples: &[
+            "depends on !123",
+            "depends on other-project!123",
+            "-depends on other-project!123",
+            "depends on https://gitlab.example.com/group/other-project/-/merge_requests/123",
+        ],
+    },
+    CommandSpec {
+        name: "remind",
+        usage: "remind [me / @user / @group / reviewers] <when / when <event> [or <when>] / if no activity <when> / <when> unless someone replies>[: <message>]",
+        description: "pings you (or given people) after given time passes or when something happens, e.g. pipeline succeeds",
+        examples: &[
+            "remind me tomorrow morning",
+            "remind me in 90 minutes",
+            "remind me in 3d 2h",
+            "remind me in 2 business days",
+            "remind me on friday at 12:30: rebase it!",
+            "remind me on dec 24 at 3pm",
+            "remind me at the end of the week",
+            "remind me next month",
+            "remind @someone tomorrow: please review",
+            "remind @frontend-team on friday",
+            "remind reviewers in 2d",
+            "remind me when pipeline succeeds",
+            "remind me when !42 is merged or in 3d",
+            "remind me if no activity in 2d",
+            "remind reviewers in 2d unless someone replies",
+        ],
+    },
+    CommandSpec {
+        name: "notify",
+        usage: "notify me via <gitlab/slack/matrix/webhook>",
+        description: "changes where reminders & dependency updates are sent to",
+        examples: &["notify me via slack", "notify me via gitlab"],
+    },
+    CommandSpec {
+        name: "digest",
+        usage: "[-]digest me <daily / weekly [on <day>]>[ at <time>][ <timezone>]",
+        description: "sends you a summary of merge requests waiting for your review, your dependencies that got merged or closed & today's reminders",
+        examples: &[
+            "digest me daily at 9:00",
+            "digest me weekly on monday at 8am Europe/Warsaw",
+            "-digest me",
+        ],
+    },
+    CommandSpec {
+        name: "help",
+        usage: "help [<command>]",
+        description: "lists supported commands or explains given one",
+        examples: &["help", "help remind"],
+    },
+];
+
+impl CommandSpec {
+    /// Returns command with given name (case-insensitive), e.g. `remind`
+    pub fn find(name: &str) -> Option<&'static Self> {
+        let name = name.trim();
+
+        COMMANDS
+            .iter()
+            .find(|spec| spec.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns command that's the closest (by edit distance) to given,
+    /// unparseable one - e.g. for `remnd me tomorow` it returns `remind`.
+    ///
+    /// Returns `None` if no command is close enough to be a reasonable
+    /// suggestion.
+    pub fn suggest(cmd: &str) -> Option<&'static Self> {
+        let cmd = cmd.trim_start_matches('-').to_lowercase();
+
+        let words: Vec<_> = cmd.split_whitespace().collect();
+
+        COMMANDS
+            .iter()
+            .filter_map(|spec| {
+                // Since some commands consist of more than one word (e.g.
+                // `depends on`), we're comparing their names with the same
+                // number of words from the beginning of user's command
+                let len = spec.name.split(' ').count().min(words.len());
+                let distance = strsim::levenshtein(spec.name, &words[..len].join(" "));
+                let max_distance = (spec.name.len() / 3).max(1);
+
+                if distance <= max_distance {
+                    Some((distance, spec))
+                } else {
+                    None
+                }
+            })
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, spec)| spec)
+    }
+
+    /// Returns Markdown-formatted usage of this command
+    pub fn help(&self) -> String {
+        let examples: Vec<_> = self
+            .examples
+            .iter()
+            .map(|example| format!("- `{}`", example))
+            .collect();
+
+        format!(
+            "`{}` - {}; e.g.:\n\n{}",
+            self.usage,
+            self.description,
+            examples.join("\n"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MergeRequestCommand, MergeRequestCommandContext, MergeRequestPtr};
+    use lib_gitlab::{DiscussionId, MergeRequestIid, UserId};
+    use test_case::test_case;
+
+    #[test]
+    fn examples_are_valid() {
+        for spec in COMMANDS {
+            for example in spec.examples {
+                let ctxt = MergeRequestCommandContext {
+                    user: UserId::new(250),
+                    merge_request: MergeRequestPtr::Iid {
+                        project: None,
+                        merge_request: MergeRequestIid::new(1),
+                    },
+                    discussion: DiscussionId::new("cafebabe"),
+                };
+
+                if let Err(err) = MergeRequestCommand::parse(ctxt, example) {
+                    panic!("Example `{}` is invalid: {}", example, err);
+                }
+            }
+        }
+    }
+
+    #[test_case("remind" => Some("remind") ; "remind")]
+    #[test_case("REMIND" => Some("remind") ; "remind uppercase")]
+    #[test_case("depends on" => Some("depends on") ; "depends on")]
+    #[test_case("foo" => None ; "foo")]
+    fn find(name: &str) -> Option<&'static str> {
+        CommandSpec::find(name).map(|spec| spec.name)
+    }
+
+    #[test_case("remnd me tomorow" => Some("remind") ; "remnd me tomorow")]
+    #[test_case("remid me in 3d" => Some("remind") ; "remid me in 3d")]
+    #[test_case("depend on !123" => Some("depends on") ; "depend on")]
+    #[test_case("-depnds on !123" => Some("depends on") ; "depnds on")]
+    #[test_case("notfy me via slack" => Some("notify") ; "notfy me via slack")]
+    #[test_case("halp" => Some("help") ; "halp")]
+    #[test_case("ho!" => None ; "ho")]
+    #[test_case("make me a sandwich" => None ; "make me a sandwich")]
+    #[test_case("" => None ; "empty")]
+    fn suggest(cmd: &str) -> Option<&'static str> {
+        CommandSpec::suggest(cmd).map(|spec| spec.name)
+    }
+
+    #[test]
+    fn help() {
+        let actual = CommandSpec::find("notify").unwrap().help();
+
+        let expected = "`notify me via <gitlab/slack/matrix/webhook>` - changes where reminders & dependency updates are sent to; e.g.:\n\n- `notify me via slack`\n- `notify me via gitlab`";
+
+        assert_eq!(expected, actual);
+    }
+}
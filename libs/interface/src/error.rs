@@ -1,3 +1,4 @@
+use nom::error::{ErrorKind, FromExternalError, ParseError as NomParseError};
 use thiserror::Error;
 
 pub type InterfaceResult<T> = Result<T, InterfaceError>;
@@ -6,4 +7,102 @@ pub type InterfaceResult<T> = Result<T, InterfaceError>;
 pub enum InterfaceError {
     #[error("Unknown command")]
     UnknownCommand,
+
+    /// Command has been recognized, but some of its values are invalid (e.g.
+    /// `remind me at 25`)
+    #[error("Invalid command: {reason} (at {position}: `{fragment}`)")]
+    InvalidCommand {
+        /// Human-readable reason, e.g. `hour must be 0-23`
+        reason: &'static str,
+
+        /// Byte offset (within the command) where the invalid value starts
+        position: usize,
+
+        /// The invalid value itself, e.g. `25`
+        fragment: String,
+    },
+}
+
+impl InterfaceError {
+    /// Converts parser's error into an interface error; `cmd` is the entire
+    /// command that was being parsed
+    pub(crate) fn from_parse_error(cmd: &str, err: nom::Err<ParseError<'_>>) -> Self {
+        let err = match err {
+            nom::Err::Failure(err) => err,
+            _ => return Self::UnknownCommand,
+        };
+
+        if let Some(reason) = err.reason {
+            let fragment = err
+                .input
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+
+            Self::InvalidCommand {
+                reason,
+                position: cmd.len() - err.input.len(),
+                fragment,
+            }
+        } else {
+            Self::UnknownCommand
+        }
+    }
+}
+
+pub type ParseResult<'a, T> = nom::IResult<&'a str, T, ParseError<'a>>;
+
+/// Error returned by our parsers; apart from the position, it can carry a
+/// human-readable reason of why the input got rejected.
+///
+/// Parsers return errors with reasons as `nom::Err::Failure`, so that other
+/// branches of `alt()` don't get tried and the reason reaches the user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError<'a> {
+    /// Remaining input, starting at the place where parsing failed
+    pub input: &'a str,
+
+    /// Reason of the failure, e.g. `hour must be 0-23`
+    pub reason: Option<&'static str>,
+}
+
+impl<'a> ParseError<'a> {
+    /// Returns a failure that stops parsing at given input, with given reason
+    pub(crate) fn fail<T>(input: &'a str, reason: &'static str) -> ParseResult<'a, T> {
+        Err(nom::Err::Failure(Self {
+            input,
+            reason: Some(reason),
+        }))
+    }
+}
+
+impl<'a> NomParseError<&'a str> for ParseError<'a> {
+    fn from_error_kind(input: &'a str, _: ErrorKind) -> Self {
+        Self {
+            input,
+            reason: None,
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn or(self, other: Self) -> Self {
+        if self.reason.is_some() {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for ParseError<'a> {
+    fn from_external_error(input: &'a str, _: ErrorKind, _: E) -> Self {
+        Self {
+            input,
+            reason: None,
+        }
+    }
 }
@@ -1,8 +1,8 @@
-use lib_gitlab::{MergeRequestIid, ProjectId};
-use serde::Serialize;
+use lib_gitlab::{DiscussionId, MergeRequestIid, ProjectId, UserId};
+use serde::{Deserialize, Serialize};
 
 /// A generic event accepted by Janet
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum Event {
     MergeRequestClosed {
@@ -19,4 +19,55 @@ pub enum Event {
         project: ProjectId,
         merge_request: MergeRequestIid,
     },
+
+    /// Someone wrote a note (that's not a command) on a merge request
+    NoteCreated {
+        project: ProjectId,
+        merge_request: MergeRequestIid,
+        discussion: DiscussionId,
+        author: UserId,
+    },
+
+    PipelineSucceeded {
+        project: ProjectId,
+        merge_request: MergeRequestIid,
+    },
+
+    PipelineFailed {
+        project: ProjectId,
+        merge_request: MergeRequestIid,
+    },
+}
+
+impl Event {
+    /// Returns a short, stable name of this event's variant (e.g.
+    /// `merge_request.closed`), suitable for e.g. labelling metrics
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::MergeRequestClosed { .. } => "merge_request.closed",
+            Event::MergeRequestMerged { .. } => "merge_request.merged",
+            Event::MergeRequestReopened { .. } => "merge_request.reopened",
+            Event::NoteCreated { .. } => "merge_request.note_created",
+            Event::PipelineSucceeded { .. } => "pipeline.succeeded",
+            Event::PipelineFailed { .. } => "pipeline.failed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_be_deserialized_back() {
+        let event = Event::MergeRequestMerged {
+            project: ProjectId::new(10),
+            merge_request: MergeRequestIid::new(1),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        let deserialized: Event = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, serde_json::to_string(&deserialized).unwrap());
+    }
 }
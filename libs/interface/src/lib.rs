@@ -1,9 +1,10 @@
 #![feature(try_blocks)]
 #![feature(type_ascription)]
 
-pub use self::{atoms::*, command::*, error::*, event::*};
+pub use self::{atoms::*, calendar::*, command::*, error::*, event::*};
 
 mod atoms;
+mod calendar;
 mod command;
 mod error;
 mod event;
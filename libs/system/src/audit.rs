@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use std::future::Future;
+use std::time::Instant;
+
+/// Runs given handler, recording its input & outcome (along with all the
+/// requests it's sent to GitLab) in the `logs` table - so that it's possible to
+/// tell what Janet did about each command & event, and why.
+crate async fn audit<T>(
+    world: &World,
+    entry: db::CreateLogEntry,
+    handler: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    let id = world.db.execute(entry).await?;
+    let started_at = Instant::now();
+    let (result, gitlab_calls) = gl::record_calls(handler).await;
+
+    let update = db::UpdateLogEntry {
+        id,
+        outcome: metrics::result_label(&result).to_string(),
+        error: result.as_ref().err().map(|err| format!("{:?}", err)),
+        duration_ms: started_at.elapsed().as_millis() as i64,
+        gitlab_calls: serde_json::to_string(&gitlab_calls)?,
+    };
+
+    // Handler has already finished, so failing to record its outcome is not
+    // a reason to fail it
+    if let Err(err) = world.db.execute(update).await {
+        tracing::error!({ id, err = ?err }, "Couldn't update log entry");
+    }
+
+    result
+}
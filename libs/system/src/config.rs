@@ -1,3 +1,77 @@
+use arc_swap::access::DynAccess;
+use lib_gitlab::ProjectId;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tokio::time::Duration;
 
 pub const REMINDER_RESOLUTION: Duration = Duration::from_secs(5);
+
+/// How often old log entries are deleted
+pub const LOG_RETENTION_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How often projects are scanned for stale merge requests
+pub const NUDGER_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// How often we check whether someone's digest is due
+pub const DIGEST_RESOLUTION: Duration = Duration::from_secs(60);
+
+/// Configuration that can change while Janet is running (i.e. when it gets
+/// reloaded on `SIGHUP`), so it should be `.load()`-ed anew each time it's
+/// needed
+pub type LiveConfig<T> = Arc<dyn DynAccess<T> + Send + Sync>;
+
+/// Configuration of the stale merge request nudger; projects without a policy
+/// never get nudged
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NudgerConfig {
+    /// Merge requests with this label never get nudged
+    #[serde(default = "default_ignore_label")]
+    pub ignore_label: String,
+
+    #[serde(default)]
+    pub projects: Vec<NudgerPolicy>,
+}
+
+impl Default for NudgerConfig {
+    fn default() -> Self {
+        Self {
+            ignore_label: default_ignore_label(),
+            projects: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NudgerPolicy {
+    /// GitLab's project id
+    pub project: ProjectId,
+
+    /// Merge request gets nudged after staying idle for given number of days
+    pub idle_days: u32,
+
+    /// Nudge gets escalated when the merge request stays idle for given
+    /// number of days after the first nudge; when missing, there's no
+    /// escalation
+    pub escalate_after_days: Option<u32>,
+
+    /// Users or groups (e.g. `tech-leads`) mentioned in the escalation, in
+    /// addition to the merge request's assignees & reviewers
+    #[serde(default)]
+    pub escalate_to: Vec<String>,
+}
+
+fn default_ignore_label() -> String {
+    "janet::ignore".to_string()
+}
+
+/// Configuration of the digests (see: the `digest me` command)
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DigestConfig {
+    /// GitLab project (e.g. a dedicated `janet-digest` one) where digests are
+    /// created as issues for users who don't get notified through any chat;
+    /// when missing, such users don't get digests at all
+    pub project: Option<ProjectId>,
+}
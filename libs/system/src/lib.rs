@@ -1,23 +1,52 @@
 #![feature(crate_visibility_modifier)]
 
 pub use self::config::*;
-pub(self) use self::{packet::*, world::*};
+pub use self::notifiers::{
+    MatrixNotifierConfig,
+    NotifiersConfig,
+    SlackNotifierConfig,
+    WebhookNotifierConfig,
+};
+pub use self::state::SystemHealth;
+pub(self) use self::{notifiers::Notifiers, packet::*, state::State, world::*};
 
 use anyhow::*;
-use lib_database::Database;
+use lib_database::{Database, LogsConfig};
 use lib_gitlab::GitLabClient;
-use lib_interface::{Command, Event};
+use lib_interface::{Calendar, Command, Event};
 use std::future::Future;
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
 
+mod audit;
 mod config;
+mod metrics;
+mod notifiers;
 mod packet;
 mod prelude;
+mod state;
 mod tasks;
 mod utils;
 mod world;
 
+/// Determines which of the system's tasks get started
+#[derive(Clone)]
+pub enum SystemMode {
+    /// Regular mode, in which the system handles commands & events, sends
+    /// reminders & digests, nudges stale merge requests and prunes old log
+    /// entries
+    Serve {
+        logs: LogsConfig,
+        nudger: LiveConfig<NudgerConfig>,
+        digest: LiveConfig<DigestConfig>,
+    },
+
+    /// Mode in which the system only handles the commands & events it's given,
+    /// without doing any background work on its own; used for replaying
+    /// entries from the log
+    Replay,
+}
+
 #[derive(Clone, Debug)]
 pub struct System {
     /// When enabled, all the `process_` methods will wait for the command /
@@ -31,6 +60,9 @@ pub struct System {
 
     /// Transmitter allowing to send events to the system
     evt_tx: EventTx,
+
+    /// State shared with the background tasks
+    state: Arc<State>,
 }
 
 impl System {
@@ -38,8 +70,19 @@ impl System {
         sync: bool,
         db: Database,
         gitlab: Arc<GitLabClient>,
+        notifiers: NotifiersConfig,
+        calendar: Calendar,
+        mode: SystemMode,
     ) -> (Arc<Self>, impl Future<Output = Result<()>>) {
-        let world = World { db, gitlab };
+        let state = Arc::new(State::default());
+
+        let world = World {
+            calendar,
+            db,
+            notifiers: Notifiers::new(notifiers, gitlab.clone()),
+            gitlab,
+            state: state.clone(),
+        };
 
         let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
         let (evt_tx, evt_rx) = mpsc::unbounded_channel();
@@ -47,10 +90,11 @@ impl System {
         let this = Arc::new(Self {
             cmd_tx,
             evt_tx,
+            state,
             sync,
         });
 
-        let task = tasks::spawn(world, cmd_rx, evt_rx);
+        let task = tasks::spawn(world, cmd_rx, evt_rx, mode);
 
         (this, task)
     }
@@ -59,14 +103,21 @@ impl System {
     ///
     /// When `sync` is enabled, waits for the command to complete processing;
     /// otherwise returns immediately.
-    pub async fn process_command(&self, cmd: Command) {
+    /// `correlation_id` gets stored in the audit trail (see: the `logs` table),
+    /// so that entries can be matched with e.g. GitLab's webhook deliveries.
+    #[tracing::instrument(skip(self))]
+    pub async fn process_command(&self, cmd: Command, correlation_id: Option<String>) {
         let (tx, rx) = oneshot::channel();
 
         let packet = Packet {
             item: cmd,
+            correlation_id,
             on_handled: tx,
+            span: tracing::Span::current(),
         };
 
+        self.state.on_command_queued();
+
         self.cmd_tx
             .send(packet)
             .expect("Lost connection with the system");
@@ -80,14 +131,21 @@ impl System {
     ///
     /// When `sync` is enabled, waits for the event to complete processing;
     /// otherwise returns immediately.
-    pub async fn process_event(&self, evt: Event) {
+    /// `correlation_id` gets stored in the audit trail (see: the `logs` table),
+    /// so that entries can be matched with e.g. GitLab's webhook deliveries.
+    #[tracing::instrument(skip(self))]
+    pub async fn process_event(&self, evt: Event, correlation_id: Option<String>) {
         let (tx, rx) = oneshot::channel();
 
         let packet = Packet {
             item: evt,
+            correlation_id,
             on_handled: tx,
+            span: tracing::Span::current(),
         };
 
+        self.state.on_event_queued();
+
         self.evt_tx
             .send(packet)
             .expect("Lost connection with the system");
@@ -96,4 +154,148 @@ impl System {
             rx.await.expect("Lost connection with the system");
         }
     }
+
+    /// Returns a snapshot of system's state, used for health checks
+    pub fn health(&self) -> SystemHealth {
+        self.state.health()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_gitlab::{MergeRequestIid, ProjectId};
+    use std::fmt;
+    use std::sync::Mutex;
+    use tracing::field::{Field, Visit};
+    use tracing::span;
+    use tracing::Subscriber;
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+    use tracing_subscriber::registry::LookupSpan;
+
+    /// Remembers all the events, together with the spans they've been emitted
+    /// within
+    #[derive(Clone, Default)]
+    struct Recorder {
+        events: Arc<Mutex<Vec<RecordedEvent>>>,
+    }
+
+    #[derive(Debug)]
+    struct RecordedEvent {
+        message: Option<String>,
+
+        /// Names of the spans (from the innermost one), together with their
+        /// `correlation_id` fields
+        spans: Vec<(String, Option<String>)>,
+    }
+
+    struct CorrelationId(Option<String>);
+
+    /// Extracts (`Debug`-formatted) value of a single field
+    struct FieldVisitor<'a> {
+        name: &'static str,
+        value: &'a mut Option<String>,
+    }
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == self.name {
+                *self.value = Some(format!("{:?}", value));
+            }
+        }
+    }
+
+    impl<S> Layer<S> for Recorder
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+            let mut correlation_id = None;
+
+            attrs.record(&mut FieldVisitor {
+                name: "correlation_id",
+                value: &mut correlation_id,
+            });
+
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(CorrelationId(correlation_id));
+            }
+        }
+
+        fn on_event(&self, event: &tracing::Event<'_>, ctx: Context<'_, S>) {
+            let mut message = None;
+
+            event.record(&mut FieldVisitor {
+                name: "message",
+                value: &mut message,
+            });
+
+            let mut spans: Vec<_> = ctx
+                .scope()
+                .map(|span| {
+                    let correlation_id = span
+                        .extensions()
+                        .get::<CorrelationId>()
+                        .and_then(|correlation_id| correlation_id.0.clone());
+
+                    (span.name().to_string(), correlation_id)
+                })
+                .collect();
+
+            spans.reverse();
+
+            self.events
+                .lock()
+                .unwrap()
+                .push(RecordedEvent { message, spans });
+        }
+    }
+
+    // Uses the single-threaded scheduler, since the recorder is installed just
+    // for the current thread
+    #[tokio::test]
+    async fn handlers_are_run_within_senders_span() {
+        let recorder = Recorder::default();
+
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+        let (_server, gitlab) = GitLabClient::mock().await;
+
+        let (system, task) = System::init(
+            true,
+            Database::mock().await,
+            Arc::new(gitlab),
+            Default::default(),
+            Default::default(),
+            SystemMode::Replay,
+        );
+
+        tokio::spawn(task);
+
+        system
+            .process_event(
+                Event::MergeRequestClosed {
+                    project: ProjectId::new(10),
+                    merge_request: MergeRequestIid::new(1),
+                },
+                Some("delivery-123".to_string()),
+            )
+            .await;
+
+        let events = recorder.events.lock().unwrap();
+
+        let event = events
+            .iter()
+            .find(|event| event.message.as_deref() == Some("Handling event"))
+            .expect("Handler didn't emit any events");
+
+        let sender_span = event
+            .spans
+            .iter()
+            .find(|(name, _)| name == "process_event")
+            .unwrap_or_else(|| panic!("Handler isn't linked with sender's span: {:?}", event));
+
+        assert_eq!(Some("Some(\"delivery-123\")"), sender_span.1.as_deref());
+    }
 }
@@ -0,0 +1,98 @@
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_int_counter,
+    register_int_counter_vec,
+    register_int_gauge,
+    register_int_gauge_vec,
+    IntCounter,
+    IntCounterVec,
+    IntGauge,
+    IntGaugeVec,
+};
+
+crate static COMMANDS_HANDLED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "janet_commands_handled_total",
+        "Number of commands handled, by command and result",
+        &["command", "result"]
+    )
+    .unwrap()
+});
+
+crate static DIGESTS_SENT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "janet_digests_sent_total",
+        "Number of digests sent, by channel and result",
+        &["channel", "result"]
+    )
+    .unwrap()
+});
+
+crate static EVENTS_HANDLED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "janet_events_handled_total",
+        "Number of events handled, by event and result",
+        &["event", "result"]
+    )
+    .unwrap()
+});
+
+crate static LOGS_PRUNED: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "janet_logs_pruned_total",
+        "Number of log entries deleted due to the retention policy"
+    )
+    .unwrap()
+});
+
+crate static NOTIFICATIONS_SENT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "janet_notifications_sent_total",
+        "Number of notifications sent through chat notifiers, by channel and result",
+        &["channel", "result"]
+    )
+    .unwrap()
+});
+
+crate static NUDGES_SENT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "janet_nudges_sent_total",
+        "Number of stale merge requests nudged, by nudge's level",
+        &["level"]
+    )
+    .unwrap()
+});
+
+crate static QUEUE_DEPTH: Lazy<IntGaugeVec> = Lazy::new(|| {
+    register_int_gauge_vec!(
+        "janet_queue_depth",
+        "Number of commands / events waiting to be picked up by the system",
+        &["queue"]
+    )
+    .unwrap()
+});
+
+crate static REMINDERS_PENDING: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "janet_reminders_pending",
+        "Number of reminders that haven't been sent yet"
+    )
+    .unwrap()
+});
+
+crate static REMINDERS_OVERDUE: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "janet_reminders_overdue",
+        "Number of reminders that should've been already sent"
+    )
+    .unwrap()
+});
+
+/// Returns a label describing given handler's result
+crate fn result_label<T, E>(result: &Result<T, E>) -> &'static str {
+    if result.is_ok() {
+        "ok"
+    } else {
+        "err"
+    }
+}
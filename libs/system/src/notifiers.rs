@@ -0,0 +1,237 @@
+pub use self::config::*;
+
+mod config;
+mod gitlab_note;
+mod matrix;
+mod slack;
+mod webhook;
+
+use self::{gitlab_note::*, matrix::*, slack::*, webhook::*};
+use crate::prelude::*;
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// How long we wait for chat services to accept a notification
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// A message addressed to a specific user, regarding a specific merge request
+/// (e.g. "reminding: rebase it!")
+#[derive(Clone, Debug)]
+crate struct Notification {
+    /// User who should get notified
+    pub user: gl::User,
+
+    /// Merge request the notification is about
+    pub merge_request: gl::MergeRequest,
+
+    /// Discussion the notification is related to; used by the GitLab-note
+    /// notifier, which replies there
+    pub discussion: gl::DiscussionId,
+
+    /// The message itself, without any mentions
+    pub message: String,
+}
+
+/// A sink that delivers notifications to users (e.g. through Slack)
+#[async_trait]
+crate trait Notifier: Send + Sync {
+    async fn notify(&self, notification: &Notification) -> Result<()>;
+
+    /// Sends a message that's not related to any particular merge request
+    /// (e.g. a digest)
+    async fn send(&self, user: &gl::User, message: &str) -> Result<()>;
+}
+
+/// All the notifiers configured for this instance of Janet
+crate struct Notifiers {
+    gitlab_note: GitLabNoteNotifier,
+    webhook: Option<WebhookNotifier>,
+    slack: Option<SlackNotifier>,
+    matrix: Option<MatrixNotifier>,
+}
+
+impl Notifiers {
+    crate fn new(config: NotifiersConfig, gitlab: Arc<gl::GitLabClient>) -> Self {
+        let http = reqwest::Client::new();
+
+        Self {
+            gitlab_note: GitLabNoteNotifier::new(gitlab),
+            webhook: config
+                .webhook
+                .map(|config| WebhookNotifier::new(http.clone(), config)),
+            slack: config
+                .slack
+                .map(|config| SlackNotifier::new(http.clone(), config)),
+            matrix: config
+                .matrix
+                .map(|config| MatrixNotifier::new(http, config)),
+        }
+    }
+
+    /// Returns notifier for given channel, if it's been configured
+    crate fn get(&self, channel: int::NotificationChannel) -> Option<&dyn Notifier> {
+        match channel {
+            int::NotificationChannel::GitLab => Some(&self.gitlab_note),
+            int::NotificationChannel::Webhook => self.webhook.as_ref().map(|n| n as &dyn Notifier),
+            int::NotificationChannel::Slack => self.slack.as_ref().map(|n| n as &dyn Notifier),
+            int::NotificationChannel::Matrix => self.matrix.as_ref().map(|n| n as &dyn Notifier),
+        }
+    }
+}
+
+/// Sends notification through the channel preferred by given user.
+///
+/// When user hasn't chosen any channel (or the one they've chosen doesn't work
+/// at the moment), falls back to creating a note on GitLab - so that the
+/// notification doesn't get lost.
+#[tracing::instrument(skip(world, notification))]
+crate async fn notify(
+    world: &World,
+    user_id: db::Id<db::User>,
+    notification: Notification,
+) -> Result<()> {
+    if notify_via_chat(world, user_id, &notification).await? {
+        return Ok(());
+    }
+
+    world.notifiers.gitlab_note.notify(&notification).await
+}
+
+/// Sends notification through the chat preferred by given user; returns
+/// `false` when user hasn't chosen any chat (or the one they've chosen doesn't
+/// work at the moment), in which case it's up to the caller to deliver the
+/// notification through GitLab.
+#[tracing::instrument(skip(world, notification))]
+crate async fn notify_via_chat(
+    world: &World,
+    user_id: db::Id<db::User>,
+    notification: &Notification,
+) -> Result<bool> {
+    let preference = world
+        .db
+        .get_opt(db::FindNotificationPreferences::user_id(user_id))
+        .await?;
+
+    let channel = preference
+        .and_then(|preference| preference.channel.parse().ok())
+        .unwrap_or(int::NotificationChannel::GitLab);
+
+    if channel == int::NotificationChannel::GitLab {
+        return Ok(false);
+    }
+
+    if let Some(notifier) = world.notifiers.get(channel) {
+        let result = notifier.notify(notification).await;
+
+        metrics::NOTIFICATIONS_SENT
+            .with_label_values(&[channel.as_str(), metrics::result_label(&result)])
+            .inc();
+
+        match result {
+            Ok(()) => Ok(true),
+
+            Err(err) => {
+                tracing::warn!(
+                    { channel = channel.as_str(), err = ?err },
+                    "Couldn't send notification, falling back to GitLab note"
+                );
+
+                Ok(false)
+            }
+        }
+    } else {
+        tracing::warn!(
+            { channel = channel.as_str() },
+            "Notifier is not configured, falling back to GitLab note"
+        );
+
+        Ok(false)
+    }
+}
+
+/// Creates a single GitLab note mentioning all given users; used when the same
+/// message goes to many users at once, so that the discussion doesn't get a
+/// separate note for each of them.
+#[tracing::instrument(skip(world, merge_request))]
+crate async fn notify_via_gitlab_note(
+    world: &World,
+    users: &[gl::User],
+    merge_request: &gl::MergeRequest,
+    discussion: &gl::DiscussionId,
+    message: &str,
+) -> Result<()> {
+    world
+        .notifiers
+        .gitlab_note
+        .notify_many(users, merge_request, discussion, message)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn when_chosen_channel_fails_falls_back_to_gitlab_note() {
+        let (gitlab_server, gitlab) = gl::GitLabClient::mock().await;
+        let gitlab = Arc::new(gitlab);
+
+        // There's no mock for this path, so the server responds with `404`
+        let config = NotifiersConfig {
+            slack: Some(SlackNotifierConfig {
+                webhook_url: gitlab_server.url().join("/slack").unwrap(),
+                users: Default::default(),
+            }),
+            ..Default::default()
+        };
+
+        let world = World {
+            calendar: Default::default(),
+            db: db::Database::mock().await,
+            gitlab: gitlab.clone(),
+            notifiers: Notifiers::new(config, gitlab),
+            state: Default::default(),
+        };
+
+        let user_id = world
+            .db
+            .execute(db::CreateUser {
+                ext_id: gl::UserId::new(250),
+            })
+            .await
+            .unwrap();
+
+        world
+            .db
+            .execute(db::SetNotificationPreference {
+                user_id,
+                channel: "slack".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let discussion = gl::DiscussionId::new("cafebabe");
+
+        gitlab_server
+            .expect_merge_request_note_created(
+                gl::ProjectId::new(10),
+                gl::MergeRequestIid::new(1),
+                &discussion,
+                "@someone reminding: rebase it!",
+            )
+            .await;
+
+        notify(
+            &world,
+            user_id,
+            Notification {
+                user: gl::mock::user_250(),
+                merge_request: gl::mock::merge_request_100(),
+                discussion,
+                message: "reminding: rebase it!".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use url::Url;
+
+/// Configuration of the chat notifiers; each one is optional, and users can
+/// pick only among the configured ones (GitLab notes are always available)
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotifiersConfig {
+    pub webhook: Option<WebhookNotifierConfig>,
+    pub slack: Option<SlackNotifierConfig>,
+    pub matrix: Option<MatrixNotifierConfig>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookNotifierConfig {
+    /// Address notifications are `POST`-ed to, as JSON
+    pub url: Url,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SlackNotifierConfig {
+    /// Slack's incoming webhook (`https://hooks.slack.com/services/...`)
+    pub webhook_url: Url,
+
+    /// Slack's member ids (e.g. `U012AB3CD`), keyed by GitLab usernames; users
+    /// missing here are mentioned by their GitLab usernames, which Slack
+    /// doesn't turn into pings
+    #[serde(default)]
+    pub users: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MatrixNotifierConfig {
+    /// Address of the homeserver, e.g. `https://matrix.org`
+    pub homeserver_url: Url,
+
+    /// Access token of the account Janet posts messages as
+    pub access_token: String,
+
+    /// Room notifications are posted into, e.g. `!someroom:matrix.org`
+    pub room_id: String,
+
+    /// Matrix's user ids (e.g. `@someone:matrix.org`), keyed by GitLab
+    /// usernames; users missing here are mentioned by their GitLab usernames,
+    /// which Matrix doesn't turn into pings
+    #[serde(default)]
+    pub users: BTreeMap<String, String>,
+}
@@ -0,0 +1,56 @@
+use super::{Notification, Notifier};
+use crate::prelude::*;
+use async_trait::async_trait;
+use std::slice;
+
+/// Notifies users by creating notes (i.e. comments) on GitLab, mentioning
+/// them; that's Janet's default way of notifying
+crate struct GitLabNoteNotifier {
+    gitlab: Arc<gl::GitLabClient>,
+}
+
+impl GitLabNoteNotifier {
+    crate fn new(gitlab: Arc<gl::GitLabClient>) -> Self {
+        Self { gitlab }
+    }
+
+    /// Creates a single note mentioning all given users
+    crate async fn notify_many(
+        &self,
+        users: &[gl::User],
+        merge_request: &gl::MergeRequest,
+        discussion: &gl::DiscussionId,
+        message: &str,
+    ) -> Result<()> {
+        let mentions: Vec<_> = users
+            .iter()
+            .map(|user| format!("@{}", user.username))
+            .collect();
+
+        self.gitlab
+            .create_merge_request_note(
+                merge_request.project_id,
+                merge_request.iid,
+                discussion,
+                format!("{} {}", mentions.join(" "), message),
+            )
+            .await
+    }
+}
+
+#[async_trait]
+impl Notifier for GitLabNoteNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<()> {
+        self.notify_many(
+            slice::from_ref(&notification.user),
+            &notification.merge_request,
+            &notification.discussion,
+            &notification.message,
+        )
+        .await
+    }
+
+    async fn send(&self, _: &gl::User, _: &str) -> Result<()> {
+        bail!("GitLab notes can be created only on merge requests")
+    }
+}
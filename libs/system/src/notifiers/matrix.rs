@@ -0,0 +1,106 @@
+use super::{MatrixNotifierConfig, Notification, Notifier, NOTIFICATION_TIMEOUT};
+use crate::prelude::*;
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Serialize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Notifies users by posting messages into a Matrix room, through the
+/// client-server API.
+///
+/// All the notifications land in the same room, mentioning their recipients -
+/// which is also why this notifier refuses to send private messages, such as
+/// digests.
+crate struct MatrixNotifier {
+    http: reqwest::Client,
+    config: MatrixNotifierConfig,
+
+    /// Matrix deduplicates messages by their transaction ids, so each message
+    /// has to get a unique one
+    next_txn_id: AtomicUsize,
+}
+
+#[derive(Serialize)]
+struct Request {
+    msgtype: &'static str,
+    body: String,
+}
+
+impl MatrixNotifier {
+    crate fn new(http: reqwest::Client, config: MatrixNotifierConfig) -> Self {
+        Self {
+            http,
+            config,
+            next_txn_id: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns message in the plain-text format, e.g.:
+    ///
+    /// `@someone:matrix.org: reminding: rebase it! (https://gitlab.com/...)`
+    fn body(&self, notification: &Notification) -> String {
+        format!(
+            "{}: {} ({})",
+            self.mention(&notification.user),
+            notification.message,
+            notification.merge_request.web_url,
+        )
+    }
+
+    /// Returns user's mention; only users with known Matrix ids actually get
+    /// pinged
+    fn mention(&self, user: &gl::User) -> String {
+        match self.config.users.get(&user.username) {
+            Some(user_id) => user_id.to_string(),
+            None => format!("@{}", user.username),
+        }
+    }
+
+    fn txn_id(&self) -> String {
+        format!(
+            "janet-{}-{}",
+            Utc::now().timestamp_millis(),
+            self.next_txn_id.fetch_add(1, Ordering::SeqCst)
+        )
+    }
+
+    async fn post(&self, body: String) -> Result<()> {
+        let mut url = self.config.homeserver_url.clone();
+
+        url.path_segments_mut()
+            .map_err(|_| anyhow!("Homeserver's url cannot be a base"))?
+            .pop_if_empty()
+            .extend(&["_matrix", "client", "r0", "rooms"])
+            .push(&self.config.room_id)
+            .extend(&["send", "m.room.message"])
+            .push(&self.txn_id());
+
+        let request = Request {
+            msgtype: "m.text",
+            body,
+        };
+
+        self.http
+            .put(url)
+            .bearer_auth(&self.config.access_token)
+            .timeout(NOTIFICATION_TIMEOUT)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()
+            .context("Matrix rejected the notification")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Notifier for MatrixNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<()> {
+        self.post(self.body(notification)).await
+    }
+
+    async fn send(&self, _: &gl::User, _: &str) -> Result<()> {
+        bail!("Matrix notifier posts into a shared room, so it can't deliver private messages")
+    }
+}
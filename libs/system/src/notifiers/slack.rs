@@ -0,0 +1,73 @@
+use super::{Notification, Notifier, SlackNotifierConfig, NOTIFICATION_TIMEOUT};
+use crate::prelude::*;
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// Notifies users by posting messages through Slack's incoming webhook.
+///
+/// Incoming webhooks are bound to a single channel, so all the notifications
+/// land in there, mentioning their recipients - which is also why this notifier
+/// refuses to send private messages, such as digests.
+crate struct SlackNotifier {
+    http: reqwest::Client,
+    config: SlackNotifierConfig,
+}
+
+#[derive(Serialize)]
+struct Request {
+    text: String,
+}
+
+impl SlackNotifier {
+    crate fn new(http: reqwest::Client, config: SlackNotifierConfig) -> Self {
+        Self { http, config }
+    }
+
+    /// Returns message in Slack's `mrkdwn` format, e.g.:
+    ///
+    /// `<@U012AB3CD>: reminding: rebase it! (<https://gitlab.com/...|!123>)`
+    fn text(&self, notification: &Notification) -> String {
+        format!(
+            "{}: {} (<{}|!{}>)",
+            self.mention(&notification.user),
+            notification.message,
+            notification.merge_request.web_url,
+            notification.merge_request.iid.inner(),
+        )
+    }
+
+    /// Returns user's mention; only users with known member ids actually get
+    /// pinged
+    fn mention(&self, user: &gl::User) -> String {
+        match self.config.users.get(&user.username) {
+            Some(member_id) => format!("<@{}>", member_id),
+            None => format!("@{}", user.username),
+        }
+    }
+
+    async fn post(&self, text: String) -> Result<()> {
+        let request = Request { text };
+
+        self.http
+            .post(self.config.webhook_url.clone())
+            .timeout(NOTIFICATION_TIMEOUT)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()
+            .context("Slack rejected the notification")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Notifier for SlackNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<()> {
+        self.post(self.text(notification)).await
+    }
+
+    async fn send(&self, _: &gl::User, _: &str) -> Result<()> {
+        bail!("Slack's webhook posts into a shared channel, so it can't deliver private messages")
+    }
+}
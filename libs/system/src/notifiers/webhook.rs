@@ -0,0 +1,62 @@
+use super::{Notification, Notifier, WebhookNotifierConfig, NOTIFICATION_TIMEOUT};
+use crate::prelude::*;
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// Notifies users by `POST`-ing JSONs to a configured address, so that
+/// notifications can be forwarded wherever one likes
+crate struct WebhookNotifier {
+    http: reqwest::Client,
+    config: WebhookNotifierConfig,
+}
+
+#[derive(Serialize)]
+struct Request<'a> {
+    user: &'a gl::User,
+
+    /// Missing for messages that aren't related to any merge request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_request: Option<&'a gl::MergeRequest>,
+
+    message: &'a str,
+}
+
+impl WebhookNotifier {
+    crate fn new(http: reqwest::Client, config: WebhookNotifierConfig) -> Self {
+        Self { http, config }
+    }
+
+    async fn post(&self, request: &Request<'_>) -> Result<()> {
+        self.http
+            .post(self.config.url.clone())
+            .timeout(NOTIFICATION_TIMEOUT)
+            .json(request)
+            .send()
+            .await?
+            .error_for_status()
+            .context("Webhook rejected the notification")?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<()> {
+        self.post(&Request {
+            user: &notification.user,
+            merge_request: Some(&notification.merge_request),
+            message: &notification.message,
+        })
+        .await
+    }
+
+    async fn send(&self, user: &gl::User, message: &str) -> Result<()> {
+        self.post(&Request {
+            user,
+            merge_request: None,
+            message,
+        })
+        .await
+    }
+}
@@ -10,7 +10,16 @@ pub type EventRx = mpsc::UnboundedReceiver<Packet<Event>>;
 
 pub struct Packet<T> {
     pub item: T,
+
+    /// Identifier linking the packet with its origin (e.g. GitLab's webhook
+    /// delivery); stored in the audit trail
+    pub correlation_id: Option<String>,
+
     pub on_handled: oneshot::Sender<()>,
+
+    /// Span the packet was sent from; handlers are run inside of it, so
+    /// that traces don't get broken at the channel's boundary
+    pub span: tracing::Span,
 }
 
 impl<T: fmt::Debug> fmt::Debug for Packet<T> {
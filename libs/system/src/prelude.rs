@@ -1,6 +1,9 @@
+crate use crate::audit::audit;
 crate use crate::config::*;
-crate use crate::utils::{sync_merge_request, sync_merge_request_ptr, sync_user};
-crate use crate::{CommandRx, EventRx, Packet, World};
+crate use crate::metrics;
+crate use crate::notifiers::{notify, notify_via_chat, notify_via_gitlab_note, Notification};
+crate use crate::utils::{sync_merge_request, sync_merge_request_ptr, sync_project, sync_user};
+crate use crate::{CommandRx, EventRx, Packet, SystemMode, World};
 crate use anyhow::*;
 crate use lib_database as db;
 crate use lib_gitlab as gl;
@@ -0,0 +1,65 @@
+use crate::metrics;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Runtime state shared between `System` and its background tasks; mostly
+/// useful for health checks.
+#[derive(Debug, Default)]
+pub struct State {
+    commands_queue_depth: AtomicUsize,
+    events_queue_depth: AtomicUsize,
+    reminders_heartbeat: Mutex<Option<Instant>>,
+}
+
+/// A point-in-time snapshot of system's state
+#[derive(Clone, Debug)]
+pub struct SystemHealth {
+    /// Number of commands waiting to be picked up
+    pub commands_queue_depth: usize,
+
+    /// Number of events waiting to be picked up
+    pub events_queue_depth: usize,
+
+    /// How long ago the reminder-tracking loop has finished its last
+    /// iteration; `None` if it hasn't finished any yet
+    pub reminders_heartbeat: Option<Duration>,
+}
+
+impl State {
+    pub fn on_command_queued(&self) {
+        self.commands_queue_depth.fetch_add(1, Ordering::SeqCst);
+        metrics::QUEUE_DEPTH.with_label_values(&["commands"]).inc();
+    }
+
+    pub fn on_command_dequeued(&self) {
+        self.commands_queue_depth.fetch_sub(1, Ordering::SeqCst);
+        metrics::QUEUE_DEPTH.with_label_values(&["commands"]).dec();
+    }
+
+    pub fn on_event_queued(&self) {
+        self.events_queue_depth.fetch_add(1, Ordering::SeqCst);
+        metrics::QUEUE_DEPTH.with_label_values(&["events"]).inc();
+    }
+
+    pub fn on_event_dequeued(&self) {
+        self.events_queue_depth.fetch_sub(1, Ordering::SeqCst);
+        metrics::QUEUE_DEPTH.with_label_values(&["events"]).dec();
+    }
+
+    pub fn on_reminders_tracked(&self) {
+        *self.reminders_heartbeat.lock().unwrap() = Some(Instant::now());
+    }
+
+    pub fn health(&self) -> SystemHealth {
+        SystemHealth {
+            commands_queue_depth: self.commands_queue_depth.load(Ordering::SeqCst),
+            events_queue_depth: self.events_queue_depth.load(Ordering::SeqCst),
+            reminders_heartbeat: self
+                .reminders_heartbeat
+                .lock()
+                .unwrap()
+                .map(|heartbeat| heartbeat.elapsed()),
+        }
+    }
+}
@@ -1,21 +1,39 @@
 mod handle_commands;
 mod handle_events;
+mod nudge_merge_requests;
+mod prune_logs;
+mod send_digests;
 mod track_reminders;
 
 use crate::prelude::*;
 use tokio::try_join;
 
 /// Spawns all Janet's background tasks responsible for handling commands &
-/// events.
+/// events (and for housekeeping).
 ///
 /// Returns a `Future` that must be `.await`ed for Janet to work.
-pub async fn spawn(world: World, cmds: CommandRx, evts: EventRx) -> Result<()> {
+pub async fn spawn(world: World, cmds: CommandRx, evts: EventRx, mode: SystemMode) -> Result<()> {
     let world = Arc::new(world);
 
-    try_join!(
-        handle_commands::start(world.clone(), cmds),
-        handle_events::start(world.clone(), evts),
-        track_reminders::start(world),
-    )
-    .map(drop)
+    match mode {
+        SystemMode::Serve {
+            logs,
+            nudger,
+            digest,
+        } => try_join!(
+            handle_commands::start(world.clone(), cmds),
+            handle_events::start(world.clone(), evts),
+            track_reminders::start(world.clone()),
+            nudge_merge_requests::start(world.clone(), nudger),
+            send_digests::start(world.clone(), digest),
+            prune_logs::start(world, logs),
+        )
+        .map(drop),
+
+        SystemMode::Replay => try_join!(
+            handle_commands::start(world.clone(), cmds),
+            handle_events::start(world, evts),
+        )
+        .map(drop),
+    }
 }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use tracing_futures::Instrument;
 
 mod merge_request;
 
@@ -6,7 +7,14 @@ mod merge_request;
 /// them
 pub async fn start(world: Arc<World>, mut commands: CommandRx) -> Result<()> {
     while let Some(command) = commands.next().await {
-        task::spawn(handle_command(world.clone(), command));
+        world.state.on_command_dequeued();
+
+        // `handle_command()` creates its span when it's polled for the first
+        // time, so it's the future (and not just the call) that has to be run
+        // inside the sender's span for both to get linked together
+        let span = command.span.clone();
+
+        task::spawn(handle_command(world.clone(), command).instrument(span));
     }
 
     bail!("Lost connection to the `commands` stream")
@@ -14,7 +22,14 @@ pub async fn start(world: Arc<World>, mut commands: CommandRx) -> Result<()> {
 
 #[tracing::instrument(skip(world))]
 async fn handle_command(world: Arc<World>, packet: Packet<int::Command>) {
-    match try_handle_command(world, packet.item).await {
+    let name = packet.item.name();
+    let result = try_handle_command(world, packet.item, packet.correlation_id).await;
+
+    metrics::COMMANDS_HANDLED
+        .with_label_values(&[name, metrics::result_label(&result)])
+        .inc();
+
+    match result {
         Ok(_) => {
             tracing::info!("Command handled");
         }
@@ -28,18 +43,25 @@ async fn handle_command(world: Arc<World>, packet: Packet<int::Command>) {
 }
 
 #[tracing::instrument(skip(world, cmd))]
-async fn try_handle_command(world: Arc<World>, cmd: int::Command) -> Result<()> {
+async fn try_handle_command(
+    world: Arc<World>,
+    cmd: int::Command,
+    correlation_id: Option<String>,
+) -> Result<()> {
     tracing::debug!("Handling command");
 
-    world
-        .db
-        .execute(db::CreateLogEntry {
-            event: "command".to_string(),
-            payload: serde_json::to_string(&cmd)?,
-        })
-        .await?;
+    let entry = db::CreateLogEntry {
+        event: "command".to_string(),
+        payload: serde_json::to_string(&cmd)?,
+        correlation_id,
+    };
 
-    match cmd {
-        int::Command::MergeRequest { ctxt, cmd } => merge_request::handle(&world, ctxt, cmd).await,
-    }
+    audit(&world, entry, async {
+        match cmd {
+            int::Command::MergeRequest { ctxt, cmd } => {
+                merge_request::handle(&world, ctxt, cmd).await
+            }
+        }
+    })
+    .await
 }
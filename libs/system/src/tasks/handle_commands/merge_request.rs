@@ -1,8 +1,11 @@
 use crate::prelude::*;
 use thiserror::Error;
 
+mod help;
 mod hi;
 mod manage_dependency;
+mod manage_digest;
+mod manage_notifications;
 mod manage_reminder;
 
 pub type HandlerResult<T> = Result<T, HandlerError>;
@@ -12,6 +15,29 @@ pub enum HandlerError {
     #[error("sorry, I couldn't find this merge request - could you please ensure it exists and re-create your comment?")]
     MergeRequestNotFound,
 
+    #[error(
+        "sorry, this merge request has already been merged, so this reminder would never fire"
+    )]
+    MergeRequestAlreadyMerged,
+
+    #[error("sorry, this merge request has been closed, so this reminder would never fire")]
+    MergeRequestClosed,
+
+    #[error("sorry, this merge request doesn't have any reviewers - could you please assign some and re-create your comment?")]
+    MergeRequestHasNoReviewers,
+
+    #[error("sorry, I couldn't find anyone called @{0} - could you please ensure they exist and re-create your comment?")]
+    RecipientNotFound(String),
+
+    #[error("sorry, @{0} isn't a member of this project, so I can't remind them about this merge request")]
+    RecipientIsNotProjectMember(String),
+
+    #[error("sorry, this date is too far in the future - could you please pick an earlier one and re-create your comment?")]
+    DateOutOfRange,
+
+    #[error("sorry, notifications via {0} haven't been configured - could you please contact the administrator?")]
+    NotificationChannelNotConfigured(&'static str),
+
     #[error("well, this is embarrassing - there was an issue processing your request:\n```\n{0:?}\n```\nCould you please contact the administrator?")]
     Unexpected(#[from] Error),
 }
@@ -22,14 +48,39 @@ pub async fn handle(
     cmd: int::MergeRequestCommand,
 ) -> Result<()> {
     let result = match cmd {
+        int::MergeRequestCommand::Help { command } => help::handle(&world, &ctxt, command).await,
+
         int::MergeRequestCommand::Hi => hi::handle(&world, &ctxt).await,
 
         int::MergeRequestCommand::ManageDependency { action, dependency } => {
             manage_dependency::handle(&world, &ctxt, action, dependency).await
         }
 
-        int::MergeRequestCommand::ManageReminder { remind_at, message } => {
-            manage_reminder::handle(&world, &ctxt, remind_at, message).await
+        int::MergeRequestCommand::ManageReminder {
+            recipients,
+            message,
+            trigger,
+            remind_at,
+            on_activity,
+        } => {
+            manage_reminder::handle(
+                &world,
+                &ctxt,
+                recipients,
+                trigger,
+                remind_at,
+                on_activity,
+                message,
+            )
+            .await
+        }
+
+        int::MergeRequestCommand::ManageNotifications { channel } => {
+            manage_notifications::handle(&world, &ctxt, channel).await
+        }
+
+        int::MergeRequestCommand::ManageDigest { action, schedule } => {
+            manage_digest::handle(&world, &ctxt, action, schedule).await
         }
     };
 
@@ -0,0 +1,38 @@
+use super::HandlerResult;
+use crate::prelude::*;
+
+/// Handles the `help` command
+pub async fn handle(
+    world: &World,
+    ctxt: &int::MergeRequestCommandContext,
+    command: Option<String>,
+) -> HandlerResult<()> {
+    let gl_user = world.gitlab.user(ctxt.user).await?;
+
+    let (gl_project_id, gl_merge_request_iid) = ctxt
+        .merge_request
+        .resolve(&world.gitlab, &Default::default())
+        .await?;
+
+    let help = match command.as_deref().and_then(int::CommandSpec::find) {
+        Some(spec) => spec.help(),
+
+        None => {
+            let commands: Vec<_> = int::COMMANDS.iter().map(|spec| spec.help()).collect();
+
+            format!("here's what I can do:\n\n{}", commands.join("\n\n"))
+        }
+    };
+
+    world
+        .gitlab
+        .create_merge_request_note(
+            gl_project_id,
+            gl_merge_request_iid,
+            &ctxt.discussion,
+            format!("@{} {}", gl_user.username, help),
+        )
+        .await?;
+
+    Ok(())
+}
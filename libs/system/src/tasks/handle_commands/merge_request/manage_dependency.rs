@@ -74,17 +74,6 @@ impl<'a> Handler<'a> {
             return Err(HandlerError::MergeRequestNotFound);
         }
 
-        let dependency = self
-            .world
-            .db
-            .get_opt(db::FindMergeRequestDependencies {
-                user_id: Some(self.user_id),
-                ext_discussion_id: Some(&self.ctxt.discussion),
-                src_merge_request_id: Some(self.merge_request_id),
-                ..Default::default()
-            })
-            .await?;
-
         let (_, _, dst_merge_request_id) = sync_merge_request(
             self.world,
             self.gl_dst_project_id,
@@ -92,34 +81,52 @@ impl<'a> Handler<'a> {
         )
         .await?;
 
-        if action.is_add() {
-            self.run_add(dependency, dst_merge_request_id).await
-        } else {
-            self.run_remove(dependency).await
-        }
+        // Checking whether the dependency exists and then creating / deleting it
+        // has to happen atomically - otherwise two concurrent comments could
+        // both see that the dependency is missing and create it twice
+        self.world
+            .db
+            .transaction(|db| async move {
+                let dependency = db
+                    .get_opt(db::FindMergeRequestDependencies {
+                        user_id: Some(self.user_id),
+                        ext_discussion_id: Some(&self.ctxt.discussion),
+                        src_merge_request_id: Some(self.merge_request_id),
+                        ..Default::default()
+                    })
+                    .await?;
+
+                if action.is_add() {
+                    self.run_add(&db, dependency, dst_merge_request_id).await
+                } else {
+                    self.run_remove(&db, dependency).await
+                }
+            })
+            .await?;
+
+        Ok(())
     }
 
     /// Handles the `depends on` command
     async fn run_add(
         &self,
+        db: &db::Database,
         dependency: Option<db::MergeRequestDependency>,
         dst_merge_request_id: db::Id<db::MergeRequest>,
-    ) -> HandlerResult<()> {
+    ) -> Result<()> {
         // It might happen that we already know about this dependency - say, when
         // someone adds the same `depends on !123` comment twice.
         //
         // In order to make the UI less confusing, when that happens, we're just
         // silently ignoring the second request.
         if dependency.is_none() {
-            self.world
-                .db
-                .execute(db::CreateMergeRequestDependency {
-                    user_id: self.user_id,
-                    ext_discussion_id: self.ctxt.discussion.clone(),
-                    src_merge_request_id: self.merge_request_id,
-                    dst_merge_request_id,
-                })
-                .await?;
+            db.execute(db::CreateMergeRequestDependency {
+                user_id: self.user_id,
+                ext_discussion_id: self.ctxt.discussion.clone(),
+                src_merge_request_id: self.merge_request_id,
+                dst_merge_request_id,
+            })
+            .await?;
         }
 
         Ok(())
@@ -128,17 +135,16 @@ impl<'a> Handler<'a> {
     /// Handles the `-depends on` command
     async fn run_remove(
         &self,
+        db: &db::Database,
         dependency: Option<db::MergeRequestDependency>,
-    ) -> HandlerResult<()> {
+    ) -> Result<()> {
         // It might happen that we've already removed this dependency - say, when
         // someone adds the same `-depends on !123` comment twice.
         //
         // In order to make the UI less confusing, when that happens, we're just
         // silently ignoring the second request.
         if let Some(dependency) = dependency {
-            self.world
-                .db
-                .execute(db::DeleteMergeRequestDependency { id: dependency.id })
+            db.execute(db::DeleteMergeRequestDependency { id: dependency.id })
                 .await?;
         }
 
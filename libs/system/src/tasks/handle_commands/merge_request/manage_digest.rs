@@ -0,0 +1,54 @@
+use super::HandlerResult;
+use crate::prelude::*;
+use chrono::Utc;
+
+/// Handles the `digest me` & `-digest me` commands
+pub async fn handle(
+    world: &World,
+    ctxt: &int::MergeRequestCommandContext,
+    action: int::CommandAction,
+    schedule: Option<int::DigestSchedule>,
+) -> HandlerResult<()> {
+    let (gl_user, user_id) = sync_user(world, ctxt.user).await?;
+
+    let (gl_project_id, gl_merge_request_iid) = ctxt
+        .merge_request
+        .resolve(&world.gitlab, &Default::default())
+        .await?;
+
+    match schedule {
+        Some(schedule) if action.is_add() => {
+            world
+                .db
+                .execute(db::SetDigestSubscription {
+                    user_id,
+                    day_of_week: schedule.day.map(|day| day.number_from_monday() as i64),
+                    send_at: schedule.time.format("%H:%M").to_string(),
+                    timezone: schedule
+                        .timezone
+                        .map(|timezone| timezone.as_str().to_string()),
+                    next_digest_at: schedule.next_after(Utc::now()),
+                })
+                .await?;
+        }
+
+        _ => {
+            world
+                .db
+                .execute(db::DeleteDigestSubscription { user_id })
+                .await?;
+        }
+    }
+
+    world
+        .gitlab
+        .create_merge_request_note(
+            gl_project_id,
+            gl_merge_request_iid,
+            &ctxt.discussion,
+            format!("@{} :+1:", gl_user.username),
+        )
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use super::{HandlerError, HandlerResult};
+use crate::prelude::*;
+
+/// Handles the `notify me via` command
+pub async fn handle(
+    world: &World,
+    ctxt: &int::MergeRequestCommandContext,
+    channel: int::NotificationChannel,
+) -> HandlerResult<()> {
+    if world.notifiers.get(channel).is_none() {
+        return Err(HandlerError::NotificationChannelNotConfigured(
+            channel.as_str(),
+        ));
+    }
+
+    let (gl_user, user_id) = sync_user(world, ctxt.user).await?;
+
+    let (gl_project_id, gl_merge_request_iid) = ctxt
+        .merge_request
+        .resolve(&world.gitlab, &Default::default())
+        .await?;
+
+    world
+        .db
+        .execute(db::SetNotificationPreference {
+            user_id,
+            channel: channel.as_str().to_string(),
+        })
+        .await?;
+
+    world
+        .gitlab
+        .create_merge_request_note(
+            gl_project_id,
+            gl_merge_request_iid,
+            &ctxt.discussion,
+            format!("@{} :+1:", gl_user.username),
+        )
+        .await?;
+
+    Ok(())
+}
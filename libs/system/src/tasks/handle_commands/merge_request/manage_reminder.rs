@@ -1,29 +1,95 @@
-use super::HandlerResult;
+use super::{HandlerError, HandlerResult};
 use crate::prelude::*;
-use chrono::Local;
+use chrono::{Local, Utc};
 
-/// Handles the `remind me` command
+/// Handles the `remind` command
 pub async fn handle(
     world: &World,
     ctxt: &int::MergeRequestCommandContext,
-    remind_at: int::DateTime,
+    recipients: Vec<int::Recipient>,
+    trigger: Option<int::Trigger>,
+    remind_at: Option<int::DateTime>,
+    on_activity: Option<int::OnActivity>,
     message: Option<String>,
 ) -> HandlerResult<()> {
-    let remind_at = remind_at.resolve_utc(Local::now())?;
+    let remind_at = remind_at
+        .map(|remind_at| {
+            remind_at
+                .resolve_utc(Local::now(), &world.calendar)
+                .ok_or(HandlerError::DateOutOfRange)
+        })
+        .transpose()?;
+
+    // For postponed reminders we have to remember the original delay, so that
+    // each note can push the reminder back by the same amount of time
+    let inactivity_secs = match (on_activity, remind_at) {
+        (Some(int::OnActivity::Postpone), Some(remind_at)) => {
+            Some((remind_at - Utc::now()).num_seconds().max(0))
+        }
+
+        _ => None,
+    };
+
+    let on_activity = on_activity.map(|on_activity| {
+        match on_activity {
+            int::OnActivity::Postpone => "postpone",
+            int::OnActivity::Cancel => "cancel",
+        }
+        .to_string()
+    });
 
     let (gl_user, user_id) = sync_user(world, ctxt.user).await?;
 
     let (gl_project, gl_merge_request, merge_request_id) =
         sync_merge_request_ptr(world, &ctxt.merge_request, &Default::default()).await?;
 
+    let gl_recipients =
+        resolve_recipients(world, &gl_user, &gl_project, &gl_merge_request, recipients).await?;
+
+    let (trigger_event, trigger_merge_request_id) = if let Some(trigger) = trigger {
+        let trigger_merge_request_id = resolve_trigger(
+            world,
+            &gl_project,
+            &gl_merge_request,
+            merge_request_id,
+            &trigger,
+        )
+        .await?;
+
+        (
+            Some(trigger.event_name().to_string()),
+            Some(trigger_merge_request_id),
+        )
+    } else {
+        (None, None)
+    };
+
+    let mut recipients = Vec::new();
+
+    for gl_recipient in gl_recipients {
+        let recipient_id = world
+            .db
+            .execute(db::CreateUser {
+                ext_id: gl_recipient.id,
+            })
+            .await?;
+
+        recipients.push(recipient_id);
+    }
+
     world
         .db
         .execute(db::CreateReminder {
             user_id,
+            recipients,
             merge_request_id,
             ext_discussion_id: ctxt.discussion.clone(),
             message,
             remind_at,
+            trigger_event,
+            trigger_merge_request_id,
+            on_activity,
+            inactivity_secs,
         })
         .await?;
 
@@ -41,3 +107,153 @@ pub async fn handle(
 
     Ok(())
 }
+
+/// Returns merge request the trigger refers to (e.g. `!42` for `when !42 is
+/// merged`); for triggers that don't point at any specific merge request, it's
+/// the one where the command was issued.
+///
+/// Since merge requests get merged only once, `when !42 is merged` is refused
+/// if `!42` has been already merged (or closed) - otherwise such reminder
+/// would never fire.
+async fn resolve_trigger(
+    world: &World,
+    gl_project: &gl::Project,
+    gl_merge_request: &gl::MergeRequest,
+    merge_request_id: db::Id<db::MergeRequest>,
+    trigger: &int::Trigger,
+) -> HandlerResult<db::Id<db::MergeRequest>> {
+    let dependency = match trigger {
+        int::Trigger::MergeRequestMerged {
+            merge_request: Some(dependency),
+        } => dependency,
+
+        int::Trigger::MergeRequestMerged {
+            merge_request: None,
+        } => {
+            ensure_can_be_merged(gl_merge_request)?;
+            return Ok(merge_request_id);
+        }
+
+        _ => return Ok(merge_request_id),
+    };
+
+    let context = int::PtrContext {
+        namespace_id: Some(gl_project.namespace.id),
+        project_id: Some(gl_project.id),
+    };
+
+    let (gl_dst_project_id, gl_dst_merge_request_iid) = dependency
+        .resolve(&world.gitlab, &context)
+        .await
+        .map_err(|_| HandlerError::MergeRequestNotFound)?;
+
+    // Pointer might've been resolved, but still point at a merge request that
+    // doesn't exist (e.g. `project!123`), so we have to double-check it
+    let gl_dst_merge_request = world
+        .gitlab
+        .merge_request(gl_dst_project_id, gl_dst_merge_request_iid)
+        .await
+        .map_err(|_| HandlerError::MergeRequestNotFound)?;
+
+    ensure_can_be_merged(&gl_dst_merge_request)?;
+
+    let (_, _, dst_merge_request_id) =
+        sync_merge_request(world, gl_dst_project_id, gl_dst_merge_request_iid).await?;
+
+    Ok(dst_merge_request_id)
+}
+
+fn ensure_can_be_merged(gl_merge_request: &gl::MergeRequest) -> HandlerResult<()> {
+    match gl_merge_request.state.as_str() {
+        "merged" => Err(HandlerError::MergeRequestAlreadyMerged),
+        "closed" => Err(HandlerError::MergeRequestClosed),
+        _ => Ok(()),
+    }
+}
+
+/// Transforms recipients, as written by the user (e.g. `@frontend-team`),
+/// into the actual GitLab users who should get reminded.
+///
+/// Since reminders are sent into the merge request's discussion, everyone
+/// (except for the command's author) must be a member of the merge request's
+/// project - otherwise they wouldn't even be able to see it; members of the
+/// mentioned groups who don't belong to the project are skipped.
+async fn resolve_recipients(
+    world: &World,
+    gl_user: &gl::User,
+    gl_project: &gl::Project,
+    gl_merge_request: &gl::MergeRequest,
+    recipients: Vec<int::Recipient>,
+) -> HandlerResult<Vec<gl::User>> {
+    let gl_project_members = if recipients
+        .iter()
+        .any(|recipient| matches!(recipient, int::Recipient::Mention(_)))
+    {
+        world.gitlab.project_members(gl_project.id).await?
+    } else {
+        Default::default()
+    };
+
+    let is_project_member = |gl_user: &gl::User| {
+        gl_project_members
+            .iter()
+            .any(|gl_member| gl_member.id == gl_user.id)
+    };
+
+    let mut gl_recipients: Vec<gl::User> = Vec::new();
+
+    for recipient in recipients {
+        let gl_users = match recipient {
+            int::Recipient::Me => vec![gl_user.clone()],
+
+            int::Recipient::Reviewers => {
+                if gl_merge_request.reviewers.is_empty() {
+                    return Err(HandlerError::MergeRequestHasNoReviewers);
+                }
+
+                gl_merge_request.reviewers.clone()
+            }
+
+            int::Recipient::Mention(name) => {
+                if let Some(gl_mentioned_user) = world.gitlab.user_by_username(&name).await? {
+                    if !is_project_member(&gl_mentioned_user) {
+                        return Err(HandlerError::RecipientIsNotProjectMember(name));
+                    }
+
+                    vec![gl_mentioned_user]
+                } else {
+                    let gl_group_members = match world.gitlab.group_members(&name).await {
+                        Ok(gl_group_members) => gl_group_members,
+
+                        Err(err) => {
+                            tracing::debug!({ err = ?err }, "Couldn't find recipient");
+                            return Err(HandlerError::RecipientNotFound(name));
+                        }
+                    };
+
+                    let gl_group_members: Vec<_> = gl_group_members
+                        .into_iter()
+                        .filter(|gl_member| is_project_member(gl_member))
+                        .collect();
+
+                    if gl_group_members.is_empty() {
+                        return Err(HandlerError::RecipientIsNotProjectMember(name));
+                    }
+
+                    gl_group_members
+                }
+            }
+        };
+
+        for gl_user in gl_users {
+            if !gl_recipients
+                .iter()
+                .any(|gl_recipient| gl_recipient.id == gl_user.id)
+            {
+                gl_recipients.push(gl_user);
+            }
+        }
+    }
+
+    Ok(gl_recipients)
+}
@@ -1,11 +1,19 @@
 use crate::prelude::*;
+use tracing_futures::Instrument;
 
+mod fire_reminders;
 mod merge_request_state_changed;
+mod note_created;
 
 /// Starts an eternal loop that watches for incoming events and processes them
 pub async fn start(world: Arc<World>, mut events: EventRx) -> Result<()> {
     while let Some(event) = events.next().await {
-        tokio::spawn(handle_event(world.clone(), event));
+        world.state.on_event_dequeued();
+
+        // See: `handle_commands::start()`
+        let span = event.span.clone();
+
+        task::spawn(handle_event(world.clone(), event).instrument(span));
     }
 
     bail!("Lost connection to the `events` stream")
@@ -13,7 +21,14 @@ pub async fn start(world: Arc<World>, mut events: EventRx) -> Result<()> {
 
 #[tracing::instrument(skip(world))]
 async fn handle_event(world: Arc<World>, packet: Packet<int::Event>) {
-    match try_handle_event(world, packet.item).await {
+    let name = packet.item.name();
+    let result = try_handle_event(world, packet.item, packet.correlation_id).await;
+
+    metrics::EVENTS_HANDLED
+        .with_label_values(&[name, metrics::result_label(&result)])
+        .inc();
+
+    match result {
         Ok(_) => {
             tracing::info!("Event handled");
         }
@@ -27,39 +42,62 @@ async fn handle_event(world: Arc<World>, packet: Packet<int::Event>) {
 }
 
 #[tracing::instrument(skip(world, event))]
-async fn try_handle_event(world: Arc<World>, event: int::Event) -> Result<()> {
+async fn try_handle_event(
+    world: Arc<World>,
+    event: int::Event,
+    correlation_id: Option<String>,
+) -> Result<()> {
     tracing::debug!("Handling event");
 
-    world
-        .db
-        .execute(db::CreateLogEntry {
-            event: "event".to_string(),
-            payload: serde_json::to_string(&event)?,
-        })
-        .await?;
-
-    match event {
-        int::Event::MergeRequestClosed {
-            project,
-            merge_request,
-        } => {
-            merge_request_state_changed::handle(&world, project, merge_request, "closed").await?;
-        }
+    let entry = db::CreateLogEntry {
+        event: "event".to_string(),
+        payload: serde_json::to_string(&event)?,
+        correlation_id,
+    };
 
-        int::Event::MergeRequestMerged {
-            project,
-            merge_request,
-        } => {
-            merge_request_state_changed::handle(&world, project, merge_request, "merged").await?;
-        }
+    audit(&world, entry, async {
+        fire_reminders::handle(&world, &event).await?;
 
-        int::Event::MergeRequestReopened {
-            project,
-            merge_request,
-        } => {
-            merge_request_state_changed::handle(&world, project, merge_request, "reopened").await?;
+        match event {
+            int::Event::MergeRequestClosed {
+                project,
+                merge_request,
+            } => {
+                merge_request_state_changed::handle(&world, project, merge_request, "closed")
+                    .await?;
+            }
+
+            int::Event::MergeRequestMerged {
+                project,
+                merge_request,
+            } => {
+                merge_request_state_changed::handle(&world, project, merge_request, "merged")
+                    .await?;
+            }
+
+            int::Event::MergeRequestReopened {
+                project,
+                merge_request,
+            } => {
+                merge_request_state_changed::handle(&world, project, merge_request, "reopened")
+                    .await?;
+            }
+
+            int::Event::NoteCreated {
+                project,
+                merge_request,
+                discussion,
+                author,
+            } => {
+                note_created::handle(&world, project, merge_request, &discussion, author).await?;
+            }
+
+            // Pipelines matter only for reminders, which have been already
+            // taken care of
+            int::Event::PipelineSucceeded { .. } | int::Event::PipelineFailed { .. } => {}
         }
-    }
 
-    Ok(())
+        Ok(())
+    })
+    .await
 }
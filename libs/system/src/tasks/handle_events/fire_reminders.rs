@@ -0,0 +1,86 @@
+use crate::prelude::*;
+use crate::tasks::track_reminders::close_reminder;
+
+/// Closes all the reminders waiting for given event (e.g. for a pipeline to
+/// succeed).
+///
+/// Reminders that wait for both an event and a specific time (e.g. `when !42
+/// is merged or in 3d`) get closed by whichever happens first, so by the time
+/// the second one happens the reminder is already gone.
+pub async fn handle(world: &World, event: &int::Event) -> Result<()> {
+    let (project, merge_request) = match *event {
+        int::Event::MergeRequestMerged {
+            project,
+            merge_request,
+        }
+        | int::Event::PipelineSucceeded {
+            project,
+            merge_request,
+        }
+        | int::Event::PipelineFailed {
+            project,
+            merge_request,
+        } => (project, merge_request),
+
+        // No reminder can wait for those
+        int::Event::MergeRequestClosed { .. }
+        | int::Event::MergeRequestReopened { .. }
+        | int::Event::NoteCreated { .. } => {
+            return Ok(());
+        }
+    };
+
+    // Similarly as with dependencies, we receive events about all the merge
+    // requests - most of which we've never heard of, so there's nothing to do
+    let merge_request = world
+        .db
+        .get_opt(db::FindMergeRequests {
+            ext_iid: Some(merge_request),
+            ext_project_id: Some(project),
+            ..Default::default()
+        })
+        .await?;
+
+    let merge_request = if let Some(merge_request) = merge_request {
+        merge_request
+    } else {
+        return Ok(());
+    };
+
+    let reminders = world
+        .db
+        .get_all(db::FindReminders::triggered_by(
+            event.name(),
+            merge_request.id,
+        ))
+        .await?;
+
+    if reminders.is_empty() {
+        return Ok(());
+    }
+
+    let cause = match event {
+        int::Event::PipelineSucceeded { .. } => "the pipeline has succeeded".to_string(),
+        int::Event::PipelineFailed { .. } => "the pipeline has failed".to_string(),
+
+        _ => {
+            let gl_merge_request = world
+                .gitlab
+                .merge_request(project, merge_request.ext_iid())
+                .await?;
+
+            format!("{} has been merged", gl_merge_request.web_url)
+        }
+    };
+
+    for reminder in reminders {
+        let id = reminder.id;
+
+        if let Err(err) = close_reminder(world, reminder, Some(&cause)).await {
+            // One failed reminder shouldn't prevent us from sending the rest
+            tracing::error!({ id = ?id, err = ?err }, "Couldn't close reminder");
+        }
+    }
+
+    Ok(())
+}
@@ -116,25 +116,32 @@ async fn try_notify_about_merge_request_dependency(
 
     let gl_user = world.gitlab.user(user.ext_id()).await?;
 
+    let gl_src_merge_request = world
+        .gitlab
+        .merge_request(src_project.ext_id(), src_merge_request.ext_iid())
+        .await?;
+
     let gl_dst_merge_request = world
         .gitlab
         .merge_request(dst_project.ext_id(), dst_merge_request.ext_iid())
         .await?;
 
-    let note = format!(
-        "@{} related merge request {} has been {}",
-        gl_user.username, gl_dst_merge_request.web_url, verb,
+    let message = format!(
+        "related merge request {} has been {}",
+        gl_dst_merge_request.web_url, verb,
     );
 
-    world
-        .gitlab
-        .create_merge_request_note(
-            src_project.ext_id(),
-            src_merge_request.ext_iid(),
-            &dep.ext_discussion_id(),
-            note,
-        )
-        .await?;
+    notify(
+        world,
+        dep.user_id,
+        Notification {
+            user: gl_user,
+            merge_request: gl_src_merge_request,
+            discussion: dep.ext_discussion_id(),
+            message,
+        },
+    )
+    .await?;
 
     Ok(())
 }
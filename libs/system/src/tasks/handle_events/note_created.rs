@@ -0,0 +1,74 @@
+use crate::prelude::*;
+use chrono::{Duration, Utc};
+
+/// Handles a note written on a merge request: postpones / cancels reminders
+/// that were waiting for the merge request to go quiet (e.g. `remind me if no
+/// activity in 2d`).
+pub async fn handle(
+    world: &World,
+    project: gl::ProjectId,
+    merge_request: gl::MergeRequestIid,
+    discussion: &gl::DiscussionId,
+    author: gl::UserId,
+) -> Result<()> {
+    // Most of the notes are written on merge requests we've never heard of -
+    // there's no reminder waiting for those, so there's nothing to do
+    let merge_request = world
+        .db
+        .get_opt(db::FindMergeRequests {
+            ext_iid: Some(merge_request),
+            ext_project_id: Some(project),
+            ..Default::default()
+        })
+        .await?;
+
+    let merge_request = if let Some(merge_request) = merge_request {
+        merge_request
+    } else {
+        return Ok(());
+    };
+
+    let now = Utc::now();
+
+    let reminders = world
+        .db
+        .get_all(db::FindReminders::merge_request_id(merge_request.id))
+        .await?;
+
+    if reminders.is_empty() {
+        return Ok(());
+    }
+
+    let author = world.db.get_opt(db::FindUsers::ext_id(author)).await?;
+
+    for reminder in reminders {
+        match reminder.on_activity.as_deref() {
+            Some("postpone") => {
+                let inactivity = Duration::seconds(reminder.inactivity_secs.unwrap_or_default());
+
+                world
+                    .db
+                    .execute(db::PostponeReminder {
+                        id: reminder.id,
+                        remind_at: now + inactivity,
+                    })
+                    .await?;
+            }
+
+            // Reminder's author talking to themselves doesn't count as a reply
+            Some("cancel")
+                if reminder.ext_discussion_id() == *discussion
+                    && author.as_ref().map(|author| author.id) != Some(reminder.user_id) =>
+            {
+                world
+                    .db
+                    .execute(db::DeleteReminder { id: reminder.id })
+                    .await?;
+            }
+
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,483 @@
+use crate::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+use tokio::time::delay_for;
+
+/// Our own nudge bumps merge request's `updated_at`, so activity that happens
+/// right after the nudge is not considered someone picking the merge request
+/// up
+const NUDGE_SLACK_SECS: i64 = 60;
+
+/// Starts an eternal loop that looks for merge requests no one has touched for
+/// a while and nudges their assignees & reviewers
+pub async fn start(world: Arc<World>, config: LiveConfig<NudgerConfig>) -> Result<()> {
+    loop {
+        // Policies can get changed while we're running, so instead of keeping
+        // the ones we've been started with, we're re-reading them every pass
+        let config = NudgerConfig::clone(&config.load());
+
+        if config.projects.is_empty() {
+            tracing::debug!("Nudger is disabled - no project has a policy");
+        }
+
+        for policy in &config.projects {
+            if let Err(err) = nudge_project(&world, &config, policy).await {
+                // One misconfigured project shouldn't prevent us from nudging
+                // the rest
+                tracing::error!(
+                    { project = ?policy.project, err = ?err },
+                    "Couldn't nudge project's merge requests"
+                );
+            }
+        }
+
+        delay_for(NUDGER_INTERVAL).await;
+    }
+}
+
+async fn nudge_project(world: &World, config: &NudgerConfig, policy: &NudgerPolicy) -> Result<()> {
+    let now = Utc::now();
+    let gl_merge_requests = world.gitlab.open_merge_requests(policy.project).await?;
+
+    for gl_merge_request in gl_merge_requests {
+        if gl_merge_request
+            .labels
+            .iter()
+            .any(|label| label == &config.ignore_label)
+        {
+            continue;
+        }
+
+        let updated_at = if let Some(updated_at) = gl_merge_request.updated_at {
+            updated_at
+        } else {
+            continue;
+        };
+
+        let level =
+            match next_nudge_level(world, policy, &gl_merge_request, updated_at, now).await? {
+                Some(level) => level,
+                None => continue,
+            };
+
+        let entry = db::CreateLogEntry {
+            event: "nudge".to_string(),
+            payload: serde_json::json!({
+                "project": gl_merge_request.project_id,
+                "merge_request": gl_merge_request.iid,
+                "level": level,
+            })
+            .to_string(),
+            correlation_id: None,
+        };
+
+        let id = gl_merge_request.id;
+
+        let result = audit(
+            world,
+            entry,
+            nudge_merge_request(world, policy, gl_merge_request, level),
+        )
+        .await;
+
+        if let Err(err) = result {
+            tracing::error!({ id = ?id, err = ?err }, "Couldn't nudge merge request");
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns which nudge (if any) given merge request is due: `1` for the first
+/// one, `2` for the escalation.
+///
+/// When someone has touched the merge request since our last nudge, the nudges
+/// are forgotten, so that the merge request can get nudged again once it goes
+/// quiet.
+async fn next_nudge_level(
+    world: &World,
+    policy: &NudgerPolicy,
+    gl_merge_request: &gl::MergeRequest,
+    updated_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<Option<i64>> {
+    let merge_request = world
+        .db
+        .get_opt(db::FindMergeRequests::ext_id(gl_merge_request.id))
+        .await?;
+
+    let last_nudge = if let Some(merge_request) = &merge_request {
+        world
+            .db
+            .get_all(db::FindNudges::merge_request_id(merge_request.id))
+            .await?
+            .pop()
+    } else {
+        None
+    };
+
+    let last_nudge = match (last_nudge, &merge_request) {
+        (Some(last_nudge), Some(merge_request))
+            if updated_at > last_nudge.nudged_at + Duration::seconds(NUDGE_SLACK_SECS) =>
+        {
+            world
+                .db
+                .execute(db::DeleteNudges {
+                    merge_request_id: merge_request.id,
+                })
+                .await?;
+
+            None
+        }
+
+        (last_nudge, _) => last_nudge,
+    };
+
+    let level = match last_nudge {
+        None if now - updated_at >= Duration::days(policy.idle_days as i64) => Some(1),
+
+        Some(last_nudge) if last_nudge.level == 1 => policy
+            .escalate_after_days
+            .filter(|&days| now - last_nudge.nudged_at >= Duration::days(days as i64))
+            .map(|_| 2),
+
+        _ => None,
+    };
+
+    Ok(level)
+}
+
+async fn nudge_merge_request(
+    world: &World,
+    policy: &NudgerPolicy,
+    gl_merge_request: gl::MergeRequest,
+    level: i64,
+) -> Result<()> {
+    tracing::info!({ id = ?gl_merge_request.id, level }, "Nudging merge request");
+
+    // We record the nudge before actually sending it: if sending fails, the
+    // merge request misses one nudge, which is better than getting nudged over
+    // and over again each time we fail to record it
+    let (_, project_id) = sync_project(world, gl_merge_request.project_id).await?;
+
+    let merge_request_id = world
+        .db
+        .execute(db::CreateMergeRequest {
+            project_id,
+            ext_id: gl_merge_request.id,
+            ext_iid: gl_merge_request.iid,
+            ext_state: gl_merge_request.state.clone(),
+        })
+        .await?;
+
+    world
+        .db
+        .execute(db::CreateNudge {
+            merge_request_id,
+            level,
+            nudged_at: Utc::now(),
+        })
+        .await?;
+
+    let mut mentions: Vec<&str> = Vec::new();
+
+    for gl_user in gl_merge_request
+        .assignees
+        .iter()
+        .chain(&gl_merge_request.reviewers)
+    {
+        if !mentions.contains(&gl_user.username.as_str()) {
+            mentions.push(&gl_user.username);
+        }
+    }
+
+    // When there's no one assigned, the author is the one who knows best whom
+    // to ask for a review
+    if mentions.is_empty() {
+        if let Some(gl_author) = &gl_merge_request.author {
+            mentions.push(&gl_author.username);
+        }
+    }
+
+    if level > 1 {
+        for name in &policy.escalate_to {
+            if !mentions.contains(&name.as_str()) {
+                mentions.push(name);
+            }
+        }
+    }
+
+    let mentions: String = mentions
+        .into_iter()
+        .map(|mention| format!("@{} ", mention))
+        .collect();
+
+    let note = if level > 1 {
+        format!(
+            "{}this merge request is still waiting for someone to pick it up - could you please take a look?",
+            mentions
+        )
+    } else {
+        format!(
+            "{}this merge request hasn't seen any activity for {} days - could you please take a look?",
+            mentions, policy.idle_days
+        )
+    };
+
+    world
+        .gitlab
+        .create_merge_request_comment(gl_merge_request.project_id, gl_merge_request.iid, note)
+        .await?;
+
+    metrics::NUDGES_SENT
+        .with_label_values(&[&level.to_string()])
+        .inc();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDLE_NOTE: &str =
+        "this merge request hasn't seen any activity for 3 days - could you please take a look?";
+
+    const ESCALATION_NOTE: &str =
+        "this merge request is still waiting for someone to pick it up - could you please take a look?";
+
+    async fn world() -> (gl::mock::GitLabMockServer, World) {
+        let (gitlab_server, world) = World::mock().await;
+
+        gitlab_server.expect_project(&gl::mock::project_10()).await;
+
+        (gitlab_server, world)
+    }
+
+    fn config() -> NudgerConfig {
+        NudgerConfig {
+            projects: vec![policy()],
+            ..Default::default()
+        }
+    }
+
+    fn policy() -> NudgerPolicy {
+        NudgerPolicy {
+            project: gl::ProjectId::new(10),
+            idle_days: 3,
+            escalate_after_days: Some(2),
+            escalate_to: vec!["tech-leads".to_string()],
+        }
+    }
+
+    /// Returns merge request assigned to @someone, last updated given number
+    /// of days ago
+    fn merge_request(updated_days_ago: i64) -> gl::MergeRequest {
+        gl::MergeRequest {
+            assignees: vec![gl::mock::user_250()],
+            updated_at: Some(Utc::now() - Duration::days(updated_days_ago)),
+            ..gl::mock::merge_request_100()
+        }
+    }
+
+    /// Pretends given merge request has been nudged given number of days ago
+    async fn create_nudge(world: &World, level: i64, nudged_days_ago: i64) {
+        let project_id = world
+            .db
+            .execute(db::CreateProject {
+                ext_id: gl::ProjectId::new(10),
+            })
+            .await
+            .unwrap();
+
+        let merge_request_id = world
+            .db
+            .execute(db::CreateMergeRequest {
+                project_id,
+                ext_id: gl::MergeRequestId::new(100),
+                ext_iid: gl::MergeRequestIid::new(1),
+                ext_state: "opened".to_string(),
+            })
+            .await
+            .unwrap();
+
+        world
+            .db
+            .execute(db::CreateNudge {
+                merge_request_id,
+                level,
+                nudged_at: Utc::now() - Duration::days(nudged_days_ago),
+            })
+            .await
+            .unwrap();
+    }
+
+    async fn nudge_levels(world: &World) -> Vec<i64> {
+        world
+            .db
+            .get_all(db::FindNudges::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|nudge| nudge.level)
+            .collect()
+    }
+
+    mod given_idle_merge_request {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn nudges_its_assignees_once() {
+            let (gitlab_server, world) = world().await;
+
+            gitlab_server
+                .expect_open_merge_requests(gl::ProjectId::new(10), &[merge_request(5)])
+                .await;
+
+            // `expect_*()` verifies the comment gets created exactly once
+            gitlab_server
+                .expect_merge_request_comment_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    format!("@someone {}", IDLE_NOTE),
+                )
+                .await;
+
+            for _ in 0..2 {
+                nudge_project(&world, &config(), &policy()).await.unwrap();
+            }
+
+            assert_eq!(vec![1], nudge_levels(&world).await);
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn without_assignees_nudges_its_author() {
+            let (gitlab_server, world) = world().await;
+
+            let merge_request = gl::MergeRequest {
+                author: Some(gl::mock::user_251()),
+                assignees: Default::default(),
+                ..merge_request(5)
+            };
+
+            gitlab_server
+                .expect_open_merge_requests(gl::ProjectId::new(10), &[merge_request])
+                .await;
+
+            gitlab_server
+                .expect_merge_request_comment_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    format!("@someone-else {}", IDLE_NOTE),
+                )
+                .await;
+
+            nudge_project(&world, &config(), &policy()).await.unwrap();
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn with_ignore_label_does_nothing() {
+            let (gitlab_server, world) = world().await;
+
+            let merge_request = gl::MergeRequest {
+                labels: vec!["janet::ignore".to_string()],
+                ..merge_request(5)
+            };
+
+            gitlab_server
+                .expect_open_merge_requests(gl::ProjectId::new(10), &[merge_request])
+                .await;
+
+            nudge_project(&world, &config(), &policy()).await.unwrap();
+
+            assert!(nudge_levels(&world).await.is_empty());
+        }
+    }
+
+    mod given_recently_updated_merge_request {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn does_nothing() {
+            let (gitlab_server, world) = world().await;
+
+            gitlab_server
+                .expect_open_merge_requests(gl::ProjectId::new(10), &[merge_request(1)])
+                .await;
+
+            nudge_project(&world, &config(), &policy()).await.unwrap();
+
+            assert!(nudge_levels(&world).await.is_empty());
+        }
+    }
+
+    mod given_nudged_merge_request {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn that_stays_idle_escalates() {
+            let (gitlab_server, world) = world().await;
+
+            create_nudge(&world, 1, 5).await;
+
+            // Our own nudge is what touched the merge request for the last
+            // time
+            gitlab_server
+                .expect_open_merge_requests(gl::ProjectId::new(10), &[merge_request(5)])
+                .await;
+
+            gitlab_server
+                .expect_merge_request_comment_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    format!("@someone @tech-leads {}", ESCALATION_NOTE),
+                )
+                .await;
+
+            for _ in 0..2 {
+                nudge_project(&world, &config(), &policy()).await.unwrap();
+            }
+
+            assert_eq!(vec![1, 2], nudge_levels(&world).await);
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn that_stays_idle_but_was_nudged_recently_does_nothing() {
+            let (gitlab_server, world) = world().await;
+
+            create_nudge(&world, 1, 1).await;
+
+            gitlab_server
+                .expect_open_merge_requests(gl::ProjectId::new(10), &[merge_request(5)])
+                .await;
+
+            nudge_project(&world, &config(), &policy()).await.unwrap();
+
+            assert_eq!(vec![1], nudge_levels(&world).await);
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn that_got_touched_since_then_starts_over() {
+            let (gitlab_server, world) = world().await;
+
+            create_nudge(&world, 1, 5).await;
+
+            // Someone has picked the merge request up after our nudge, but then
+            // it went quiet again
+            gitlab_server
+                .expect_open_merge_requests(gl::ProjectId::new(10), &[merge_request(4)])
+                .await;
+
+            gitlab_server
+                .expect_merge_request_comment_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    format!("@someone {}", IDLE_NOTE),
+                )
+                .await;
+
+            nudge_project(&world, &config(), &policy()).await.unwrap();
+
+            assert_eq!(vec![1], nudge_levels(&world).await);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::prelude::*;
+use chrono::{Duration, Utc};
+use tokio::time::delay_for;
+
+/// Starts an eternal loop that deletes log entries according to the retention
+/// policy
+pub async fn start(world: Arc<World>, config: db::LogsConfig) -> Result<()> {
+    if !config.is_enabled() {
+        tracing::debug!("Log retention is disabled - logs will be kept forever");
+        return Ok(());
+    }
+
+    loop {
+        let result = world
+            .db
+            .execute(db::DeleteLogEntries {
+                created_before: config
+                    .max_age_days
+                    .map(|days| Utc::now() - Duration::days(days as i64)),
+                keep_newest: config.max_rows,
+            })
+            .await;
+
+        match result {
+            Ok(deleted) => {
+                metrics::LOGS_PRUNED.inc_by(deleted);
+
+                if deleted > 0 {
+                    tracing::info!({ deleted }, "Pruned logs");
+                }
+            }
+
+            Err(err) => {
+                // Failing to delete logs is not fatal, so - instead of shutting
+                // down the entire system - we'll just try again later
+                tracing::error!({ err = ?err }, "Couldn't prune logs");
+            }
+        }
+
+        delay_for(LOG_RETENTION_INTERVAL).await;
+    }
+}
@@ -0,0 +1,616 @@
+use crate::notifiers::Notifier;
+use crate::prelude::*;
+use chrono::{DateTime, Local, NaiveTime, Utc};
+use std::fmt::Write;
+use tokio::time::delay_for;
+
+/// Starts an eternal loop that watches for due digests and sends them to
+/// related users
+pub async fn start(world: Arc<World>, config: LiveConfig<DigestConfig>) -> Result<()> {
+    loop {
+        let now = Utc::now();
+        let config = DigestConfig::clone(&config.load());
+
+        let subscriptions = world
+            .db
+            .get_all(db::FindDigestSubscriptions::due_by(now))
+            .await;
+
+        match subscriptions {
+            Ok(subscriptions) => {
+                for subscription in subscriptions {
+                    let user_id = subscription.user_id;
+
+                    if let Err(err) = send_digest(&world, &config, subscription, now).await {
+                        tracing::error!({ user_id = ?user_id, err = ?err }, "Couldn't send digest");
+                    }
+                }
+            }
+
+            Err(err) => {
+                // The database might be just temporarily unavailable, so -
+                // instead of shutting down the entire system - we'll just try
+                // again later
+                tracing::error!({ err = ?err }, "Couldn't find due digests");
+            }
+        }
+
+        delay_for(DIGEST_RESOLUTION).await;
+    }
+}
+
+async fn send_digest(
+    world: &World,
+    config: &DigestConfig,
+    subscription: db::DigestSubscription,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let user_id = subscription.user_id;
+    let next_digest_at = schedule(&subscription)?.next_after(now);
+
+    let entry = db::CreateLogEntry {
+        event: "digest".to_string(),
+        payload: serde_json::to_string(&subscription)?,
+        correlation_id: None,
+    };
+
+    let result = audit(
+        world,
+        entry,
+        try_send_digest(world, config, &subscription, next_digest_at, now),
+    )
+    .await;
+
+    // Even if the digest couldn't be sent, we're moving on to the next one -
+    // otherwise a single failure (e.g. a user who's been removed from GitLab)
+    // would make us retry it every `DIGEST_RESOLUTION` forever
+    world
+        .db
+        .execute(db::RescheduleDigestSubscription {
+            user_id,
+            next_digest_at,
+        })
+        .await?;
+
+    result
+}
+
+/// Transforms subscription back into the schedule user has written, so that
+/// we can tell when the next digest should be sent
+fn schedule(subscription: &db::DigestSubscription) -> Result<int::DigestSchedule> {
+    let day = subscription
+        .day_of_week
+        .map(|day| {
+            int::DayOfWeek::from_number_from_monday(day as u32)
+                .ok_or_else(|| anyhow!("Invalid day of week: {}", day))
+        })
+        .transpose()?;
+
+    let time = NaiveTime::parse_from_str(&subscription.send_at, "%H:%M")
+        .with_context(|| format!("Invalid time: {}", subscription.send_at))?;
+
+    let timezone = subscription
+        .timezone
+        .as_deref()
+        .map(str::parse)
+        .transpose()?;
+
+    Ok(int::DigestSchedule {
+        day,
+        time,
+        timezone,
+    })
+}
+
+async fn try_send_digest(
+    world: &World,
+    config: &DigestConfig,
+    subscription: &db::DigestSubscription,
+    next_digest_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let user_id = subscription.user_id;
+    let user = world.db.get_one(db::FindUsers::id(user_id)).await?;
+    let gl_user = world.gitlab.user(user.ext_id()).await?;
+
+    let digest = match compose_digest(world, &gl_user, subscription, next_digest_at).await? {
+        Some(digest) => digest,
+
+        None => {
+            tracing::debug!({ user_id = ?user_id }, "Nothing to digest, skipping");
+            return Ok(());
+        }
+    };
+
+    let ext_issue = deliver_digest(world, config, subscription, &gl_user, digest).await?;
+
+    world
+        .db
+        .execute(db::MarkDigestSent {
+            user_id,
+            sent_at: now,
+            ext_issue,
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Returns digest's message; `None` when there's nothing worth sending
+async fn compose_digest(
+    world: &World,
+    gl_user: &gl::User,
+    subscription: &db::DigestSubscription,
+    next_digest_at: DateTime<Utc>,
+) -> Result<Option<String>> {
+    let mut digest = String::new();
+
+    let gl_reviewed_merge_requests = world.gitlab.reviewed_merge_requests(gl_user.id).await?;
+
+    if !gl_reviewed_merge_requests.is_empty() {
+        writeln!(digest, "\n**Waiting for your review:**\n")?;
+
+        for gl_merge_request in gl_reviewed_merge_requests {
+            writeln!(digest, "- {}", gl_merge_request.web_url)?;
+        }
+    }
+
+    let dependencies = world
+        .db
+        .get_all(db::FindMergeRequestDependencies {
+            user_id: Some(subscription.user_id),
+            ..Default::default()
+        })
+        .await?;
+
+    let mut changed_dependencies = Vec::new();
+
+    for dependency in dependencies {
+        let gl_dst_merge_request =
+            find_gl_merge_request(world, dependency.dst_merge_request_id).await?;
+
+        if gl_dst_merge_request.state != "opened"
+            && changed_since(&gl_dst_merge_request, subscription.last_digest_at)
+        {
+            let gl_src_merge_request =
+                find_gl_merge_request(world, dependency.src_merge_request_id).await?;
+
+            changed_dependencies.push((gl_src_merge_request, gl_dst_merge_request));
+        }
+    }
+
+    if !changed_dependencies.is_empty() {
+        writeln!(digest, "\n**Dependencies that got merged or closed:**\n")?;
+
+        for (gl_src_merge_request, gl_dst_merge_request) in changed_dependencies {
+            writeln!(
+                digest,
+                "- {} ({}), which {} depends on",
+                gl_dst_merge_request.web_url,
+                gl_dst_merge_request.state,
+                gl_src_merge_request.web_url
+            )?;
+        }
+    }
+
+    let timezone = schedule(subscription)?.timezone;
+
+    // Reminders are listed up to the next digest, so that e.g. a weekly digest
+    // covers the entire week
+    let reminders = world
+        .db
+        .get_all(db::FindReminders {
+            recipient_id: Some(subscription.user_id),
+            overdue_by: Some(next_digest_at),
+            ..Default::default()
+        })
+        .await?;
+
+    if !reminders.is_empty() {
+        writeln!(digest, "\n**Upcoming reminders:**\n")?;
+
+        for reminder in reminders {
+            let gl_merge_request = find_gl_merge_request(world, reminder.merge_request_id).await?;
+
+            let remind_at = reminder
+                .remind_at
+                .map(|remind_at| format_time(remind_at, timezone))
+                .unwrap_or_default();
+
+            match &reminder.message {
+                Some(message) => writeln!(
+                    digest,
+                    "- {}: {} ({})",
+                    remind_at, message, gl_merge_request.web_url
+                )?,
+
+                None => writeln!(digest, "- {}: {}", remind_at, gl_merge_request.web_url)?,
+            }
+        }
+    }
+
+    if digest.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(format!("here's your digest:\n{}", digest)))
+    }
+}
+
+/// Checks whether given (merged or closed) merge request has changed its state
+/// since the previous digest - so that each change gets reported just once.
+///
+/// When we don't know when the merge request has changed its state, it's
+/// reported only in the very first digest.
+fn changed_since(
+    gl_merge_request: &gl::MergeRequest,
+    last_digest_at: Option<DateTime<Utc>>,
+) -> bool {
+    let last_digest_at = if let Some(last_digest_at) = last_digest_at {
+        last_digest_at
+    } else {
+        return true;
+    };
+
+    gl_merge_request
+        .merged_at
+        .or(gl_merge_request.closed_at)
+        .map_or(false, |changed_at| changed_at > last_digest_at)
+}
+
+/// Formats given time in subscription's timezone (or, if there's none, in
+/// Janet's local timezone - the same one subscription's `send_at` refers to)
+fn format_time(time: DateTime<Utc>, timezone: Option<int::Timezone>) -> String {
+    match timezone {
+        Some(timezone) => time
+            .with_timezone(&timezone.tz())
+            .format("%Y-%m-%d %H:%M %Z")
+            .to_string(),
+
+        None => time
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+    }
+}
+
+async fn find_gl_merge_request(
+    world: &World,
+    merge_request_id: db::Id<db::MergeRequest>,
+) -> Result<gl::MergeRequest> {
+    let merge_request = world
+        .db
+        .get_one(db::FindMergeRequests::id(merge_request_id))
+        .await?;
+
+    let project = world
+        .db
+        .get_one(db::FindProjects::id(merge_request.project_id))
+        .await?;
+
+    world
+        .gitlab
+        .merge_request(project.ext_id(), merge_request.ext_iid())
+        .await
+}
+
+/// Sends digest through the chat preferred by given user or, when user hasn't
+/// chosen any (or it can't deliver private messages, or doesn't work at the
+/// moment), creates an issue in the configured project.
+///
+/// Since each digest supersedes the previous one, the issue created for the
+/// previous digest (if any) gets closed; returns the issue created for this
+/// digest (if any), so that it can be closed later.
+async fn deliver_digest(
+    world: &World,
+    config: &DigestConfig,
+    subscription: &db::DigestSubscription,
+    gl_user: &gl::User,
+    digest: String,
+) -> Result<Option<(gl::ProjectId, gl::IssueIid)>> {
+    let ext_issue =
+        send_digest_via_preferred_channel(world, config, subscription, gl_user, digest).await?;
+
+    if let Some((project, issue)) = subscription.ext_issue() {
+        if let Err(err) = world.gitlab.close_issue(project, issue).await {
+            tracing::warn!({ err = ?err }, "Couldn't close previous digest's issue");
+        }
+    }
+
+    Ok(ext_issue)
+}
+
+async fn send_digest_via_preferred_channel(
+    world: &World,
+    config: &DigestConfig,
+    subscription: &db::DigestSubscription,
+    gl_user: &gl::User,
+    digest: String,
+) -> Result<Option<(gl::ProjectId, gl::IssueIid)>> {
+    let preference = world
+        .db
+        .get_opt(db::FindNotificationPreferences::user_id(
+            subscription.user_id,
+        ))
+        .await?;
+
+    let channel = preference
+        .and_then(|preference| preference.channel.parse().ok())
+        .unwrap_or(int::NotificationChannel::GitLab);
+
+    if channel != int::NotificationChannel::GitLab {
+        if let Some(notifier) = world.notifiers.get(channel) {
+            let result = notifier.send(gl_user, &digest).await;
+
+            metrics::DIGESTS_SENT
+                .with_label_values(&[channel.as_str(), metrics::result_label(&result)])
+                .inc();
+
+            match result {
+                Ok(()) => return Ok(None),
+
+                Err(err) => {
+                    tracing::warn!(
+                        { channel = channel.as_str(), err = ?err },
+                        "Couldn't send digest, falling back to GitLab issue"
+                    );
+                }
+            }
+        }
+    }
+
+    let project = config.project.ok_or_else(|| {
+        anyhow!("User doesn't use any chat and no project for digests has been configured")
+    })?;
+
+    let result = world
+        .gitlab
+        .create_issue(
+            project,
+            gl_user.id,
+            format!("Digest for @{}", gl_user.username),
+            format!("@{} {}", gl_user.username, digest),
+        )
+        .await;
+
+    metrics::DIGESTS_SENT
+        .with_label_values(&[
+            int::NotificationChannel::GitLab.as_str(),
+            metrics::result_label(&result),
+        ])
+        .inc();
+
+    Ok(result?.map(|issue| (project, issue)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    async fn create_user(world: &World) -> db::Id<db::User> {
+        world
+            .db
+            .execute(db::CreateUser {
+                ext_id: gl::UserId::new(250),
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn create_merge_request(
+        world: &World,
+        gl_merge_request: &gl::MergeRequest,
+    ) -> db::Id<db::MergeRequest> {
+        let project_id = world
+            .db
+            .execute(db::CreateProject {
+                ext_id: gl_merge_request.project_id,
+            })
+            .await
+            .unwrap();
+
+        world
+            .db
+            .execute(db::CreateMergeRequest {
+                project_id,
+                ext_id: gl_merge_request.id,
+                ext_iid: gl_merge_request.iid,
+                ext_state: "opened".to_string(),
+            })
+            .await
+            .unwrap()
+    }
+
+    /// Subscribes user to daily digests and pretends they've got one a day ago,
+    /// through given issue
+    async fn subscribe(
+        world: &World,
+        user_id: db::Id<db::User>,
+        timezone: Option<&str>,
+        ext_issue: Option<(gl::ProjectId, gl::IssueIid)>,
+    ) -> db::DigestSubscription {
+        world
+            .db
+            .execute(db::SetDigestSubscription {
+                user_id,
+                day_of_week: None,
+                send_at: "09:00".to_string(),
+                timezone: timezone.map(ToString::to_string),
+                next_digest_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        world
+            .db
+            .execute(db::MarkDigestSent {
+                user_id,
+                sent_at: Utc::now() - Duration::days(1),
+                ext_issue,
+            })
+            .await
+            .unwrap();
+
+        world
+            .db
+            .get_one(db::FindDigestSubscriptions::user_id(user_id))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn digest_reports_only_what_has_changed_since_the_previous_one() {
+        let (gitlab_server, world) = World::mock().await;
+        let user_id = create_user(&world).await;
+        let subscription = subscribe(&world, user_id, Some("Europe/Warsaw"), None).await;
+
+        let gl_src_merge_request = gl::mock::merge_request_100();
+
+        // Got merged after the previous digest, so it should be reported
+        let gl_dst_merge_request_1 = gl::MergeRequest {
+            state: "merged".to_string(),
+            merged_at: Some(Utc::now() - Duration::hours(1)),
+            ..gl::mock::merge_request_101()
+        };
+
+        // Got closed before the previous digest, so it's been already reported
+        let gl_dst_merge_request_2 = gl::MergeRequest {
+            id: gl::MergeRequestId::new(102),
+            iid: gl::MergeRequestIid::new(3),
+            web_url: "http://gitlab.com/merge_requests/102".to_string(),
+            state: "closed".to_string(),
+            closed_at: Some(Utc::now() - Duration::days(2)),
+            ..gl::mock::merge_request_101()
+        };
+
+        let src_merge_request_id = create_merge_request(&world, &gl_src_merge_request).await;
+
+        for gl_dst_merge_request in &[&gl_dst_merge_request_1, &gl_dst_merge_request_2] {
+            let dst_merge_request_id = create_merge_request(&world, gl_dst_merge_request).await;
+
+            world
+                .db
+                .execute(db::CreateMergeRequestDependency {
+                    user_id,
+                    ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                    src_merge_request_id,
+                    dst_merge_request_id,
+                })
+                .await
+                .unwrap();
+
+            gitlab_server
+                .expect_merge_request(gl_dst_merge_request)
+                .await;
+        }
+
+        world
+            .db
+            .execute(db::CreateReminder {
+                user_id,
+                recipients: vec![user_id],
+                merge_request_id: src_merge_request_id,
+                ext_discussion_id: gl::DiscussionId::new("cafebabe"),
+                message: Some("take a look".to_string()),
+                remind_at: Some(Utc.ymd(2021, 4, 25).and_hms(10, 0, 0)),
+                trigger_event: None,
+                trigger_merge_request_id: None,
+                on_activity: None,
+                inactivity_secs: None,
+            })
+            .await
+            .unwrap();
+
+        gitlab_server
+            .expect_reviewed_merge_requests(gl::UserId::new(250), &[])
+            .await;
+
+        gitlab_server
+            .expect_merge_request(&gl_src_merge_request)
+            .await;
+
+        let actual = compose_digest(
+            &world,
+            &gl::mock::user_250(),
+            &subscription,
+            Utc::now() + Duration::days(1),
+        )
+        .await
+        .unwrap();
+
+        let expected = [
+            "here's your digest:",
+            "",
+            "**Dependencies that got merged or closed:**",
+            "",
+            "- http://gitlab.com/merge_requests/101 (merged), which http://gitlab.com/merge_requests/100 depends on",
+            "",
+            "**Upcoming reminders:**",
+            "",
+            "- 2021-04-25 12:00 CEST: take a look (http://gitlab.com/merge_requests/100)",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(Some(expected), actual);
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn digest_replaces_previous_issue() {
+        let (gitlab_server, world) = World::mock().await;
+        let user_id = create_user(&world).await;
+
+        let subscription = subscribe(
+            &world,
+            user_id,
+            None,
+            Some((gl::ProjectId::new(10), gl::IssueIid::new(6))),
+        )
+        .await;
+
+        let config = DigestConfig {
+            project: Some(gl::ProjectId::new(10)),
+        };
+
+        gitlab_server.expect_user(&gl::mock::user_250()).await;
+
+        gitlab_server
+            .expect_reviewed_merge_requests(gl::UserId::new(250), &[gl::mock::merge_request_100()])
+            .await;
+
+        gitlab_server
+            .expect_issue_created(
+                gl::ProjectId::new(10),
+                gl::UserId::new(250),
+                "Digest for @someone",
+                gl::IssueIid::new(7),
+            )
+            .await;
+
+        gitlab_server
+            .expect_issue_closed(gl::ProjectId::new(10), gl::IssueIid::new(6))
+            .await;
+
+        // Whole seconds, so that the comparison below doesn't depend on the
+        // precision database stores timestamps with
+        let now = Utc.timestamp(Utc::now().timestamp(), 0);
+
+        send_digest(&world, &config, subscription, now)
+            .await
+            .unwrap();
+
+        let subscription = world
+            .db
+            .get_one(db::FindDigestSubscriptions::user_id(user_id))
+            .await
+            .unwrap();
+
+        assert_eq!(Some(now), subscription.last_digest_at);
+        assert!(subscription.next_digest_at > now);
+
+        assert_eq!(
+            Some((gl::ProjectId::new(10), gl::IssueIid::new(7))),
+            subscription.ext_issue()
+        );
+    }
+}
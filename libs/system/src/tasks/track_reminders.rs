@@ -1,6 +1,6 @@
 mod close_reminder;
 
-use self::close_reminder::close_reminder;
+crate use self::close_reminder::close_reminder;
 use crate::prelude::*;
 use chrono::Utc;
 use tokio::time::delay_for;
@@ -9,20 +9,27 @@ use tokio::time::delay_for;
 /// related users
 pub async fn start(world: Arc<World>) -> Result<()> {
     loop {
+        let pending_reminders = world.db.get_one(db::CountReminders::default()).await?;
+
         let overdue_reminders = world
             .db
             .get_all(db::FindReminders::overdue_by(Utc::now()))
             .await?;
 
+        metrics::REMINDERS_PENDING.set(pending_reminders);
+        metrics::REMINDERS_OVERDUE.set(overdue_reminders.len() as i64);
+
         for reminder in overdue_reminders {
             let id = reminder.id;
 
-            if let Err(err) = close_reminder(&world, reminder).await {
+            if let Err(err) = close_reminder(&world, reminder, None).await {
                 // TODO if target discussion does not exist, get rid of the reminder
                 tracing::error!({ id = ?id, err = ?err }, "Couldn't close reminder");
             }
         }
 
+        world.state.on_reminders_tracked();
+
         // We could piggy-back on Tokio's `DelayQueue`, but polling is good enough in
         // practice (and way simpler to implement!)
         delay_for(REMINDER_RESOLUTION).await;
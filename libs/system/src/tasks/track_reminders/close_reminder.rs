@@ -1,11 +1,38 @@
 use crate::prelude::*;
 
-/// Notifies reminder's user that given reminder has expired and then removes
-/// reminder from the database.
+/// Notifies reminder's recipients that given reminder has expired and then
+/// removes reminder from the database.
+///
+/// Recipients notified through chats are removed from the reminder as soon as
+/// they get notified, so that if anything fails later (in which case the
+/// reminder remains in the database and gets retried), they don't get notified
+/// again.
+///
+/// `cause` describes the event that fired the reminder (e.g. `the pipeline has
+/// succeeded`); it's `None` for reminders that fire because their time has
+/// come.
 #[tracing::instrument(skip(world))]
-pub async fn close_reminder(world: &World, reminder: db::Reminder) -> Result<()> {
+pub async fn close_reminder(
+    world: &World,
+    reminder: db::Reminder,
+    cause: Option<&str>,
+) -> Result<()> {
     tracing::info!("Closing reminder");
 
+    let entry = db::CreateLogEntry {
+        event: "reminder".to_string(),
+        payload: serde_json::to_string(&reminder)?,
+        correlation_id: Some(reminder.id.to_string()),
+    };
+
+    audit(world, entry, try_close_reminder(world, reminder, cause)).await
+}
+
+async fn try_close_reminder(
+    world: &World,
+    reminder: db::Reminder,
+    cause: Option<&str>,
+) -> Result<()> {
     let merge_request = world
         .db
         .get_one(db::FindMergeRequests::id(reminder.merge_request_id))
@@ -16,30 +43,71 @@ pub async fn close_reminder(world: &World, reminder: db::Reminder) -> Result<()>
         .get_one(db::FindProjects::id(merge_request.project_id))
         .await?;
 
-    let user = world
+    let gl_merge_request = world
+        .gitlab
+        .merge_request(project.ext_id(), merge_request.ext_iid())
+        .await?;
+
+    let message = match (&reminder.message, cause) {
+        (Some(msg), Some(cause)) => format!("reminding ({}): {}", cause, msg),
+        (Some(msg), None) => format!("reminding: {}", msg),
+        (None, Some(cause)) => format!("ping, ping - {}!", cause),
+        (None, None) => "ping, ping!".to_string(),
+    };
+
+    let recipients = world
         .db
-        .get_one(db::FindUsers::id(reminder.user_id))
+        .get_all(db::FindReminderRecipients::reminder(reminder.id))
         .await?;
 
-    let gl_user = world.gitlab.user(user.ext_id()).await?;
+    let discussion = reminder.ext_discussion_id();
 
-    let note = reminder
-        .message
-        .as_ref()
-        .map(|msg| format!("reminding: {}", msg))
-        .unwrap_or_else(|| "ping, ping!".to_string());
+    // Recipients who stick to GitLab (or whose chat doesn't work at the moment)
+    // get mentioned together, in a single note
+    let mut gl_mentioned_users = Vec::new();
 
-    let note = format!("@{} {}", gl_user.username, note);
+    for recipient in recipients {
+        let user = world
+            .db
+            .get_one(db::FindUsers::id(recipient.user_id))
+            .await?;
 
-    world
-        .gitlab
-        .create_merge_request_note(
-            project.ext_id(),
-            merge_request.ext_iid(),
-            &reminder.ext_discussion_id(),
-            note,
+        let gl_user = world.gitlab.user(user.ext_id()).await?;
+
+        let notification = Notification {
+            user: gl_user,
+            merge_request: gl_merge_request.clone(),
+            discussion: discussion.clone(),
+            message: message.clone(),
+        };
+
+        if notify_via_chat(world, recipient.user_id, &notification).await? {
+            // If anything fails later, the reminder will be retried - so let's
+            // make sure this recipient doesn't get notified for the second time
+            world
+                .db
+                .execute(db::DeleteReminderRecipient {
+                    reminder_id: reminder.id,
+                    user_id: recipient.user_id,
+                })
+                .await?;
+        } else {
+            gl_mentioned_users.push(notification.user);
+        }
+    }
+
+    if !gl_mentioned_users.is_empty() {
+        gl_mentioned_users.sort_by(|a, b| a.username.cmp(&b.username));
+
+        notify_via_gitlab_note(
+            world,
+            &gl_mentioned_users,
+            &gl_merge_request,
+            &discussion,
+            &message,
         )
         .await?;
+    }
 
     world
         .db
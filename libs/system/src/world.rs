@@ -1,5 +1,7 @@
+use crate::{Notifiers, State};
 use lib_database::Database;
 use lib_gitlab::GitLabClient;
+use lib_interface::Calendar;
 use std::sync::Arc;
 
 /// Encapsulates all the dependencies that can be used inside the system (i.e.
@@ -8,6 +10,29 @@ use std::sync::Arc;
 /// This struct allows us to minimize the amount of parameters we'd normally
 /// have to pass to each function.
 pub struct World {
+    pub calendar: Calendar,
     pub db: Database,
     pub gitlab: Arc<GitLabClient>,
+    pub notifiers: Notifiers,
+    pub state: Arc<State>,
+}
+
+#[cfg(test)]
+impl World {
+    /// Creates a world backed by an in-memory database and a mocked GitLab,
+    /// with no chats configured
+    crate async fn mock() -> (lib_gitlab::mock::GitLabMockServer, Self) {
+        let (gitlab_server, gitlab) = GitLabClient::mock().await;
+        let gitlab = Arc::new(gitlab);
+
+        let world = Self {
+            calendar: Default::default(),
+            db: Database::mock().await,
+            gitlab: gitlab.clone(),
+            notifiers: Notifiers::new(Default::default(), gitlab),
+            state: Default::default(),
+        };
+
+        (gitlab_server, world)
+    }
 }
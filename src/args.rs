@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+use lib_database::{Id, Reminder};
 use std::path::PathBuf;
 use structopt::StructOpt;
 
@@ -12,4 +14,141 @@ pub struct Args {
     /// and tests
     #[structopt(long)]
     pub sync: bool,
+
+    #[structopt(subcommand)]
+    pub cmd: Option<Command>,
+}
+
+#[derive(StructOpt)]
+pub enum Command {
+    /// Starts Janet (default)
+    Serve,
+
+    /// Validates configuration, checks whether GitLab & database are
+    /// reachable, and prints the effective configuration (with secrets
+    /// redacted)
+    CheckConfig,
+
+    /// Migrates database up (default) or down
+    Migrate {
+        #[structopt(subcommand)]
+        cmd: Option<MigrateCommand>,
+    },
+
+    /// Manages reminders
+    Reminders {
+        #[structopt(subcommand)]
+        cmd: RemindersCommand,
+    },
+
+    /// Manages merge request dependencies
+    Dependencies {
+        #[structopt(subcommand)]
+        cmd: DependenciesCommand,
+    },
+
+    /// Inspects logs (i.e. commands & events Janet has handled)
+    Logs {
+        #[structopt(subcommand)]
+        cmd: LogsCommand,
+    },
+
+    /// Re-dispatches commands & events stored in the logs, as if they've just
+    /// arrived; useful for debugging and for recovering from failures
+    Replay {
+        /// Id of the log entry to replay; can be provided many times
+        #[structopt(long)]
+        id: Vec<i64>,
+
+        /// Replay entries created at or after given date (e.g.
+        /// `2021-05-01T12:00:00Z`)
+        #[structopt(long)]
+        from: Option<DateTime<Utc>>,
+
+        /// Replay entries created before given date
+        #[structopt(long)]
+        to: Option<DateTime<Utc>>,
+
+        /// Don't modify the database nor GitLab - just print what would've
+        /// happened
+        #[structopt(long)]
+        dry_run: bool,
+    },
+
+    /// Exports & imports the entire database
+    Db {
+        #[structopt(subcommand)]
+        cmd: DbCommand,
+    },
+}
+
+#[derive(StructOpt)]
+pub enum MigrateCommand {
+    /// Migrates database to the latest (or given) version
+    Up {
+        /// Print which migrations would be applied, without applying them
+        #[structopt(long)]
+        dry_run: bool,
+
+        /// Version to migrate to; defaults to the latest one
+        #[structopt(long)]
+        to: Option<usize>,
+    },
+
+    /// Rolls database back to given version
+    Down {
+        /// Print which migrations would be reverted, without reverting them
+        #[structopt(long)]
+        dry_run: bool,
+
+        /// Version to roll back to
+        #[structopt(long)]
+        to: usize,
+    },
+}
+
+#[derive(StructOpt)]
+pub enum RemindersCommand {
+    /// Lists all pending reminders
+    List,
+
+    /// Deletes reminder with given id
+    Delete { id: Id<Reminder> },
+}
+
+#[derive(StructOpt)]
+pub enum DependenciesCommand {
+    /// Lists all merge request dependencies
+    List,
+}
+
+#[derive(StructOpt)]
+pub enum LogsCommand {
+    /// Prints the most recent log entries
+    Tail {
+        /// Number of entries to print
+        #[structopt(short, default_value = "10")]
+        n: usize,
+
+        /// Keep waiting for new entries
+        #[structopt(short, long)]
+        follow: bool,
+
+        /// Print only entries of given kind (e.g. `command` or `event`)
+        #[structopt(long)]
+        event: Option<String>,
+    },
+}
+
+#[derive(StructOpt)]
+pub enum DbCommand {
+    /// Exports database into a JSON file
+    Export {
+        /// Path to the output file; when missing, prints dump to stdout
+        path: Option<PathBuf>,
+    },
+
+    /// Imports database from a JSON file created through `db export`; works
+    /// only on empty databases
+    Import { path: PathBuf },
 }
@@ -0,0 +1,30 @@
+pub use self::config::*;
+
+mod config;
+
+use anyhow::*;
+use lib_interface::Calendar;
+use tokio::fs;
+
+/// Builds calendar (used e.g. to resolve reminders such as `in 2 business
+/// days`) out of given configuration
+pub async fn init(config: &CalendarConfig) -> Result<Calendar> {
+    let mut calendar =
+        Calendar::default().with_skip_non_business_days(config.skip_non_business_days);
+
+    if let Some(country) = &config.country {
+        calendar = calendar.with_country(country)?;
+    }
+
+    if let Some(holidays) = &config.holidays {
+        let ics = fs::read_to_string(holidays)
+            .await
+            .with_context(|| format!("Couldn't open `{}`", holidays.display()))?;
+
+        calendar = calendar
+            .with_ics(&ics)
+            .with_context(|| format!("Couldn't load holidays from `{}`", holidays.display()))?;
+    }
+
+    Ok(calendar)
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CalendarConfig {
+    /// Country whose public holidays aren't business days, e.g. `PL`
+    pub country: Option<String>,
+
+    /// iCalendar (`.ics`) file with additional holidays (e.g. company-wide
+    /// days off)
+    pub holidays: Option<PathBuf>,
+
+    /// When enabled, reminders that'd land on a weekend or a holiday (e.g.
+    /// `in 3d` said on Friday) get moved to the next business day; reminders
+    /// for specific days (e.g. `on saturday`) are left intact
+    #[serde(default)]
+    pub skip_non_business_days: bool,
+}
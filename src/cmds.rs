@@ -0,0 +1,59 @@
+pub use self::{
+    check_config::*,
+    db::*,
+    dependencies::*,
+    logs::*,
+    migrate::*,
+    reminders::*,
+    replay::*,
+    serve::*,
+};
+
+mod check_config;
+mod db;
+mod dependencies;
+mod logs;
+mod migrate;
+mod reminders;
+mod replay;
+mod serve;
+
+use crate::config::Config;
+use anyhow::*;
+use lib_database::{Database, FindProjects, MergeRequest};
+
+/// Connects to the database, ensuring it's been already migrated to the
+/// version this build of Janet understands.
+///
+/// As opposed to `serve`, other commands don't migrate the database on their
+/// own - that's what `janet migrate` is for.
+async fn connect(config: &Config) -> Result<Database> {
+    let db = Database::connect(config.database.clone())
+        .await
+        .context("Couldn't connect to the database")?;
+
+    let version = db.version().await?;
+
+    if version != Database::latest_version() {
+        bail!(
+            "Database is at version {}, but this build of Janet requires version {} - please run `janet migrate` first",
+            version,
+            Database::latest_version(),
+        );
+    }
+
+    Ok(db)
+}
+
+/// Returns a human-readable reference to given merge request, e.g.
+/// `project#10!123`
+async fn describe_merge_request(db: &Database, merge_request: &MergeRequest) -> Result<String> {
+    let project = db
+        .get_one(FindProjects::id(merge_request.project_id))
+        .await?;
+
+    Ok(format!(
+        "project#{}!{}",
+        project.ext_id, merge_request.ext_iid
+    ))
+}
@@ -0,0 +1,87 @@
+use crate::calendar;
+use crate::config::Config;
+use anyhow::*;
+use lib_database::Database;
+use lib_gitlab::GitLabClient;
+
+/// Checks whether given configuration is usable (i.e. whether GitLab & the
+/// database are reachable, and the calendar can be loaded) and prints it, with
+/// secrets redacted.
+///
+/// Since logger's not initialized at this point, this command talks to the
+/// user through stdout.
+pub async fn check_config(config: Config) -> Result<()> {
+    println!("{}", config.to_redacted_string()?);
+
+    let mut ok = true;
+
+    match check_gitlab(&config).await {
+        Ok(username) => {
+            println!("[ok] GitLab is reachable, token belongs to: @{}", username);
+        }
+
+        Err(err) => {
+            println!("[err] GitLab is not reachable: {:?}", err);
+            ok = false;
+        }
+    }
+
+    match check_database(&config).await {
+        Ok(version) if version < Database::latest_version() => {
+            println!(
+                "[ok] Database is accessible, it will be migrated from version {} to {} on start",
+                version,
+                Database::latest_version()
+            );
+        }
+
+        Ok(version) => {
+            println!("[ok] Database is accessible, it's at version {}", version);
+        }
+
+        Err(err) => {
+            println!("[err] Database is not accessible: {:?}", err);
+            ok = false;
+        }
+    }
+
+    match calendar::init(&config.calendar).await {
+        Ok(_) => {
+            println!("[ok] Calendar is valid");
+        }
+
+        Err(err) => {
+            println!("[err] Calendar is not valid: {:?}", err);
+            ok = false;
+        }
+    }
+
+    if ok {
+        Ok(())
+    } else {
+        bail!("Configuration is not valid")
+    }
+}
+
+async fn check_gitlab(config: &Config) -> Result<String> {
+    let gitlab = GitLabClient::new(config.gitlab.client.clone())?;
+
+    Ok(gitlab.current_user().await?.username)
+}
+
+/// Returns database's current version; fails for databases that are newer
+/// than this build of Janet
+async fn check_database(config: &Config) -> Result<usize> {
+    let db = Database::connect(config.database.clone()).await?;
+    let version = db.version().await?;
+
+    if version > Database::latest_version() {
+        bail!(
+            "Database is at version {}, but this build of Janet supports only up to {}",
+            version,
+            Database::latest_version()
+        );
+    }
+
+    Ok(version)
+}
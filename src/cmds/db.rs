@@ -0,0 +1,40 @@
+use super::connect;
+use crate::config::Config;
+use anyhow::*;
+use lib_database::Dump;
+use std::path::PathBuf;
+use tokio::fs;
+
+/// Exports the entire database as JSON, either into given file or to stdout
+pub async fn export_db(config: Config, path: Option<PathBuf>) -> Result<()> {
+    let db = connect(&config).await?;
+    let dump = db.export().await?;
+    let dump = serde_json::to_string_pretty(&dump)?;
+
+    if let Some(path) = path {
+        fs::write(&path, dump)
+            .await
+            .with_context(|| format!("Couldn't write dump to: {}", path.display()))?;
+    } else {
+        println!("{}", dump);
+    }
+
+    Ok(())
+}
+
+/// Imports database from given file, created through `db export`
+pub async fn import_db(config: Config, path: PathBuf) -> Result<()> {
+    let db = connect(&config).await?;
+
+    let dump = fs::read_to_string(&path)
+        .await
+        .with_context(|| format!("Couldn't read dump from: {}", path.display()))?;
+
+    let dump: Dump = serde_json::from_str(&dump).context("Couldn't parse dump")?;
+
+    db.import(dump).await?;
+
+    println!("Database imported");
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use super::{connect, describe_merge_request};
+use crate::config::Config;
+use anyhow::*;
+use lib_database::{FindMergeRequestDependencies, FindMergeRequests, FindUsers};
+
+/// Prints all merge request dependencies
+pub async fn list_dependencies(config: Config) -> Result<()> {
+    let db = connect(&config).await?;
+
+    let dependencies = db.get_all(FindMergeRequestDependencies::default()).await?;
+
+    if dependencies.is_empty() {
+        println!("There are no merge request dependencies");
+        return Ok(());
+    }
+
+    for dependency in dependencies {
+        let user = db.get_one(FindUsers::id(dependency.user_id)).await?;
+
+        let src_merge_request = db
+            .get_one(FindMergeRequests::id(dependency.src_merge_request_id))
+            .await?;
+
+        let dst_merge_request = db
+            .get_one(FindMergeRequests::id(dependency.dst_merge_request_id))
+            .await?;
+
+        println!(
+            "{} | user#{} | {} depends on {}",
+            dependency.id,
+            user.ext_id,
+            describe_merge_request(&db, &src_merge_request).await?,
+            describe_merge_request(&db, &dst_merge_request).await?,
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use super::connect;
+use crate::config::Config;
+use anyhow::*;
+use lib_database::{FindLogEntries, LogEntry};
+use lib_gitlab::GitLabCall;
+use tokio::time::{delay_for, Duration};
+
+/// How often `--follow` checks for new entries
+const FOLLOW_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Prints `n` most recent log entries and - if `follow` is set - keeps on
+/// printing new ones as they arrive
+pub async fn tail_logs(
+    config: Config,
+    n: usize,
+    follow: bool,
+    event: Option<String>,
+) -> Result<()> {
+    let db = connect(&config).await?;
+
+    let mut logs = db
+        .get_all(FindLogEntries {
+            event: event.clone(),
+            ..FindLogEntries::newest(n)
+        })
+        .await?;
+
+    logs.reverse();
+
+    // Entries of other kinds (or all of them, when `n` is zero) are not
+    // printed, but we still don't want for `--follow` to pick them up
+    let mut last_id = db
+        .get_opt(FindLogEntries::newest(1))
+        .await?
+        .map(|log| log.id);
+
+    for log in &logs {
+        print_log(log);
+    }
+
+    while follow {
+        delay_for(FOLLOW_INTERVAL).await;
+
+        let logs = db
+            .get_all(FindLogEntries {
+                event: event.clone(),
+                after_id: last_id,
+                ..Default::default()
+            })
+            .await?;
+
+        for log in &logs {
+            print_log(log);
+        }
+
+        if let Some(log) = logs.last() {
+            last_id = Some(log.id);
+        }
+    }
+
+    Ok(())
+}
+
+pub(super) fn print_log(log: &LogEntry) {
+    let outcome = match (&log.outcome, log.duration_ms) {
+        (Some(outcome), Some(duration_ms)) => format!("{} in {} ms", outcome, duration_ms),
+        (Some(outcome), None) => outcome.to_string(),
+        (None, _) => "pending".to_string(),
+    };
+
+    println!(
+        "{} | {} | {} | {} | {}",
+        log.created_at,
+        log.correlation_id.as_deref().unwrap_or("-"),
+        log.event,
+        outcome,
+        log.payload
+    );
+
+    let gitlab_calls: Vec<GitLabCall> = log
+        .gitlab_calls
+        .as_deref()
+        .and_then(|calls| serde_json::from_str(calls).ok())
+        .unwrap_or_default();
+
+    for call in gitlab_calls {
+        println!(
+            "    -> {} {} ({}): {} in {} ms",
+            call.method, call.path, call.endpoint, call.status, call.duration_ms
+        );
+    }
+
+    if let Some(error) = &log.error {
+        for line in error.lines() {
+            println!("    !! {}", line);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::config::Config;
+use anyhow::*;
+use lib_database::Database;
+
+/// Migrates database to given version (or the latest one, if none was
+/// specified).
+pub async fn migrate_up(config: Config, dry_run: bool, to: Option<usize>) -> Result<()> {
+    let db = connect(config).await?;
+    let current_version = db.version().await?;
+    let target_version = to.unwrap_or_else(Database::latest_version);
+
+    ensure_known(current_version)?;
+    ensure_known(target_version)?;
+
+    if current_version == target_version {
+        println!("Database is already at version {}", current_version);
+        return Ok(());
+    }
+
+    if current_version > target_version {
+        bail!(
+            "Database is at version {}, which is newer than {} - use `janet migrate down` to roll it back",
+            current_version,
+            target_version
+        );
+    }
+
+    if dry_run {
+        println!(
+            "Database would get migrated from version {} to {}, by applying:",
+            current_version, target_version
+        );
+
+        for version in (current_version + 1)..=target_version {
+            println!("- migration #{}", version);
+        }
+
+        return Ok(());
+    }
+
+    db.migrate(target_version).await?;
+
+    println!(
+        "Database migrated from version {} to {}",
+        current_version, target_version
+    );
+
+    Ok(())
+}
+
+/// Rolls database back to given version
+pub async fn migrate_down(config: Config, dry_run: bool, to: usize) -> Result<()> {
+    let db = connect(config).await?;
+    let current_version = db.version().await?;
+    let target_version = to;
+
+    ensure_known(current_version)?;
+    ensure_known(target_version)?;
+
+    if current_version == target_version {
+        println!("Database is already at version {}", current_version);
+        return Ok(());
+    }
+
+    if current_version < target_version {
+        bail!(
+            "Database is at version {}, which is older than {} - use `janet migrate up` to migrate it",
+            current_version,
+            target_version
+        );
+    }
+
+    if dry_run {
+        println!(
+            "Database would get rolled back from version {} to {}, by reverting:",
+            current_version, target_version
+        );
+
+        for version in ((target_version + 1)..=current_version).rev() {
+            println!("- migration #{}", version);
+        }
+
+        return Ok(());
+    }
+
+    db.rollback(target_version).await?;
+
+    println!(
+        "Database rolled back from version {} to {}",
+        current_version, target_version
+    );
+
+    Ok(())
+}
+
+async fn connect(config: Config) -> Result<Database> {
+    Database::connect(config.database)
+        .await
+        .context("Couldn't connect to the database")
+}
+
+fn ensure_known(version: usize) -> Result<()> {
+    if version > Database::latest_version() {
+        bail!(
+            "Version {} is unknown to this build of Janet (the latest one is {})",
+            version,
+            Database::latest_version()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use super::{connect, describe_merge_request};
+use crate::config::Config;
+use anyhow::*;
+use lib_database::{
+    DeleteReminder,
+    FindMergeRequests,
+    FindReminderRecipients,
+    FindReminders,
+    FindUsers,
+    Id,
+    Reminder,
+};
+
+/// Prints all pending reminders
+pub async fn list_reminders(config: Config) -> Result<()> {
+    let db = connect(&config).await?;
+    let reminders = db.get_all(FindReminders::default()).await?;
+
+    if reminders.is_empty() {
+        println!("There are no pending reminders");
+        return Ok(());
+    }
+
+    for reminder in reminders {
+        let user = db.get_one(FindUsers::id(reminder.user_id)).await?;
+
+        let mut recipients = Vec::new();
+
+        for recipient in db
+            .get_all(FindReminderRecipients::reminder(reminder.id))
+            .await?
+        {
+            let recipient = db.get_one(FindUsers::id(recipient.user_id)).await?;
+
+            recipients.push(format!("user#{}", recipient.ext_id));
+        }
+
+        let merge_request = db
+            .get_one(FindMergeRequests::id(reminder.merge_request_id))
+            .await?;
+
+        let when = match (&reminder.trigger_event, reminder.remind_at) {
+            (Some(event), Some(remind_at)) => format!("on {} or at {}", event, remind_at),
+            (Some(event), None) => format!("on {}", event),
+            (None, Some(remind_at)) => format!("at {}", remind_at),
+            (None, None) => "-".to_string(),
+        };
+
+        println!(
+            "{} | {} | user#{} -> {} | {} | {}",
+            reminder.id,
+            when,
+            user.ext_id,
+            recipients.join(", "),
+            describe_merge_request(&db, &merge_request).await?,
+            reminder.message.as_deref().unwrap_or("-"),
+        );
+    }
+
+    Ok(())
+}
+
+/// Deletes reminder with given id
+pub async fn delete_reminder(config: Config, id: Id<Reminder>) -> Result<()> {
+    let db = connect(&config).await?;
+
+    db.get_one(FindReminders::id(id))
+        .await
+        .with_context(|| format!("Couldn't find reminder: {}", id))?;
+
+    db.execute(DeleteReminder { id }).await?;
+
+    println!("Reminder {} deleted", id);
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use super::connect;
+use super::logs::print_log;
+use crate::calendar;
+use crate::config::Config;
+use anyhow::*;
+use chrono::{DateTime, Utc};
+use lib_database::{Database, DatabaseConfig, FindLogEntries, LogEntry};
+use lib_gitlab::GitLabClient;
+use lib_system::{NotifiersConfig, System, SystemMode};
+use std::sync::Arc;
+
+/// Re-dispatches commands & events stored in the log (selected either by
+/// their ids or by the time range they were created in) through the system,
+/// as if they've just arrived.
+///
+/// In the dry-run mode, everything happens on an in-memory copy of the
+/// database, and notes that would be posted to GitLab are printed instead.
+pub async fn replay(
+    config: Config,
+    ids: Vec<i64>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    dry_run: bool,
+) -> Result<()> {
+    if ids.is_empty() && from.is_none() && to.is_none() {
+        bail!("Please specify which entries to replay, either through `--id` or `--from` / `--to`");
+    }
+
+    let db = connect(&config).await?;
+    let logs = find_logs(&db, ids, from, to).await?;
+
+    let (db, gitlab, notifiers) = if dry_run {
+        // Replaying might modify the database (e.g. by creating reminders),
+        // so in the dry-run mode let's work on a copy of it
+        let copy = Database::new(DatabaseConfig::sqlite(":memory:"))
+            .await
+            .context("Couldn't initialize in-memory database")?;
+
+        copy.import(db.export().await?)
+            .await
+            .context("Couldn't copy database")?;
+
+        let gitlab = GitLabClient::init(config.gitlab.client.clone())
+            .await
+            .context("Couldn't initialize GitLab client")?
+            .dry_run();
+
+        // Chat notifiers don't support the dry-run mode, so let's disable them
+        // - notifications will fall back to (recorded) GitLab notes then
+        (copy, gitlab, NotifiersConfig::default())
+    } else {
+        let gitlab = GitLabClient::init(config.gitlab.client.clone())
+            .await
+            .context("Couldn't initialize GitLab client")?;
+
+        (db, gitlab, config.notifiers.clone())
+    };
+
+    let calendar = calendar::init(&config.calendar)
+        .await
+        .context("Couldn't initialize calendar")?;
+
+    let gitlab = Arc::new(gitlab);
+    let (system, system_task) = System::init(
+        true,
+        db.clone(),
+        gitlab.clone(),
+        notifiers,
+        calendar,
+        SystemMode::Replay,
+    );
+
+    tokio::select! {
+        result = system_task => {
+            result?;
+            bail!("System stopped unexpectedly");
+        }
+
+        result = replay_logs(&db, &gitlab, &system, logs) => result,
+    }
+}
+
+async fn find_logs(
+    db: &Database,
+    ids: Vec<i64>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> Result<Vec<LogEntry>> {
+    if ids.is_empty() {
+        return db
+            .get_all(FindLogEntries {
+                created_after: from,
+                created_before: to,
+                ..Default::default()
+            })
+            .await;
+    }
+
+    let mut logs = Vec::new();
+
+    for id in ids {
+        let log = db
+            .get_opt(FindLogEntries {
+                id: Some(id),
+                ..Default::default()
+            })
+            .await?
+            .with_context(|| format!("Log entry #{} doesn't exist", id))?;
+
+        logs.push(log);
+    }
+
+    Ok(logs)
+}
+
+async fn replay_logs(
+    db: &Database,
+    gitlab: &GitLabClient,
+    system: &System,
+    logs: Vec<LogEntry>,
+) -> Result<()> {
+    for log in logs {
+        let correlation_id = format!("replay:{}", log.id);
+
+        match log.event.as_str() {
+            "command" => {
+                let cmd = serde_json::from_str(&log.payload)
+                    .with_context(|| format!("Couldn't parse command #{}", log.id))?;
+
+                println!("Replaying command #{}: {}", log.id, log.payload);
+                system
+                    .process_command(cmd, Some(correlation_id.clone()))
+                    .await;
+            }
+
+            "event" => {
+                let evt = serde_json::from_str(&log.payload)
+                    .with_context(|| format!("Couldn't parse event #{}", log.id))?;
+
+                println!("Replaying event #{}: {}", log.id, log.payload);
+                system
+                    .process_event(evt, Some(correlation_id.clone()))
+                    .await;
+            }
+
+            event => {
+                println!(
+                    "Skipping #{}: entries of kind `{}` cannot be replayed",
+                    log.id, event
+                );
+                continue;
+            }
+        }
+
+        let replayed = db
+            .get_opt(FindLogEntries {
+                correlation_id: Some(correlation_id),
+                ..FindLogEntries::newest(1)
+            })
+            .await?;
+
+        if let Some(replayed) = replayed {
+            print_log(&replayed);
+        }
+
+        for request in gitlab.take_dry_run_requests() {
+            let note = request
+                .body
+                .as_ref()
+                .and_then(|body| body.get("body"))
+                .and_then(|body| body.as_str());
+
+            match note {
+                Some(note) => println!("    ~> would post note to {}: {}", request.path, note),
+                None => println!("    ~> would send {} {}", request.method, request.path),
+            }
+        }
+    }
+
+    Ok(())
+}
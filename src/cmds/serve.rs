@@ -0,0 +1,101 @@
+use crate::config::{Config, ConfigReloader};
+use crate::{calendar, gitlab, health, http, log};
+use anyhow::*;
+use arc_swap::access::Map;
+use arc_swap::ArcSwap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::try_join;
+
+const LOGO: &str = r#"
+       __                 __ 
+      / /___ _____  ___  / /_
+ __  / / __ `/ __ \/ _ \/ __/
+/ /_/ / /_/ / / / /  __/ /_  
+\____/\__,_/_/ /_/\___/\__/  
+
+"#;
+
+pub async fn serve(config_path: PathBuf, config: Config, sync: bool) -> Result<()> {
+    let log = Arc::new(log::init(config.log.clone()).context("Couldn't initialize log")?);
+
+    for line in LOGO.lines().skip(1) {
+        tracing::info!("{}", line);
+    }
+
+    let db = {
+        tracing::info!("Initializing database");
+
+        lib_database::Database::new(config.database.clone())
+            .await
+            .context("Couldn't initialize database")?
+    };
+
+    let gitlab = {
+        tracing::info!("Initializing GitLab client");
+
+        Arc::new(
+            lib_gitlab::GitLabClient::init(config.gitlab.client.clone())
+                .await
+                .context("Couldn't initialize GitLab client")?,
+        )
+    };
+
+    let calendar = {
+        tracing::info!("Initializing calendar");
+
+        calendar::init(&config.calendar)
+            .await
+            .context("Couldn't initialize calendar")?
+    };
+
+    let http_config = config.http.clone();
+    let config = Arc::new(ArcSwap::from_pointee(config));
+
+    let (system, system_task) = {
+        tracing::info!("Initializing system");
+        lib_system::System::init(
+            sync,
+            db.clone(),
+            gitlab.clone(),
+            config.load().notifiers.clone(),
+            calendar,
+            lib_system::SystemMode::Serve {
+                logs: config.load().database.logs.clone(),
+                nudger: Arc::new(Map::new(config.clone(), |config: &Config| &config.nudger)),
+                digest: Arc::new(Map::new(config.clone(), |config: &Config| &config.digest)),
+            },
+        )
+    };
+
+    let health_checker = Arc::new(health::HealthChecker::new(
+        db,
+        gitlab.clone(),
+        system.clone(),
+    ));
+
+    let gitlab_webhook_handler = Arc::new(gitlab::GitLabWebhookHandler::new(
+        config.clone(),
+        gitlab.clone(),
+        system.clone(),
+    ));
+
+    let http_task = {
+        tracing::info!("Initializing HTTP server");
+        http::init(http_config, health_checker, gitlab_webhook_handler)
+    };
+
+    let reloader_task = ConfigReloader::new(config_path, config, log).start();
+
+    match try_join!(system_task, http_task, reloader_task) {
+        Ok(_) => {
+            tracing::info!("Shutting down correctly");
+            Ok(())
+        }
+
+        Err(err) => {
+            tracing::error!("Shutting down because of a system error: {:?}", err);
+            Err(err)
+        }
+    }
+}
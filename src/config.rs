@@ -1,13 +1,53 @@
+pub use self::reloader::*;
+
+mod reloader;
+
+use crate::calendar::CalendarConfig;
 use crate::gitlab::GitLabConfig;
 use crate::http::HttpConfig;
 use crate::log::LogConfig;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use lib_database::DatabaseConfig;
-use serde::Deserialize;
+use lib_system::{DigestConfig, NotifiersConfig, NudgerConfig};
+use serde::{Deserialize, Serialize};
+use std::env;
 use std::path::Path;
 use tokio::fs;
+use toml::Value;
+
+/// Prefix of environment variables that override configuration; e.g.
+/// `JANET_GITLAB__URL` overrides `gitlab.url`
+const ENV_PREFIX: &str = "JANET_";
+
+/// Separator between sections in environment variables' names
+const ENV_SEPARATOR: &str = "__";
+
+/// Top-level sections of the configuration; environment variables pointing
+/// anywhere else are ignored
+const SECTIONS: &[&str] = &[
+    "bot",
+    "database",
+    "http",
+    "log",
+    "gitlab",
+    "notifiers",
+    "calendar",
+    "nudger",
+    "digest",
+];
+
+/// Options that contain secrets - they can be loaded from files (e.g.
+/// `personal_access_token_file = "/run/secrets/token"`) and are redacted when
+/// printing the configuration
+const SECRETS: &[&[&str]] = &[
+    &["database", "url"],
+    &["gitlab", "personal_access_token"],
+    &["gitlab", "webhook_secret"],
+    &["notifiers", "slack", "webhook_url"],
+    &["notifiers", "matrix", "access_token"],
+];
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
     pub bot: BotConfig,
@@ -15,20 +55,324 @@ pub struct Config {
     pub http: HttpConfig,
     pub log: LogConfig,
     pub gitlab: GitLabConfig,
+
+    #[serde(default)]
+    pub notifiers: NotifiersConfig,
+
+    #[serde(default)]
+    pub calendar: CalendarConfig,
+
+    #[serde(default)]
+    pub nudger: NudgerConfig,
+
+    #[serde(default)]
+    pub digest: DigestConfig,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct BotConfig {
     pub name: String,
 }
 
 impl Config {
+    /// Loads configuration from given file, and then applies overrides from
+    /// the `JANET_*` environment variables and the `*_file` secrets.
     pub async fn load(file: &Path) -> Result<Config> {
         let file = fs::read_to_string(file)
             .await
             .context("Couldn't open file")?;
 
-        toml::from_str(&file).context("Couldn't parse file contents")
+        let mut config: Value = toml::from_str(&file).context("Couldn't parse file contents")?;
+
+        apply_env(&mut config, env::vars()).context("Couldn't apply environment variables")?;
+
+        load_secrets(&mut config)
+            .await
+            .context("Couldn't load secrets")?;
+
+        config.try_into().context("Couldn't parse configuration")
+    }
+
+    /// Returns this configuration as TOML, with all the secrets redacted
+    pub fn to_redacted_string(&self) -> Result<String> {
+        let mut config = Value::try_from(self)?;
+
+        for path in SECRETS {
+            if let Some(value) = lookup_mut(&mut config, path) {
+                *value = Value::String("<redacted>".into());
+            }
+        }
+
+        Ok(toml::to_string_pretty(&config)?)
+    }
+}
+
+/// Overrides configuration with given environment variables.
+///
+/// E.g. `JANET_LOG__FORMAT=json` sets `log.format` to `"json"`. Only variables
+/// that point into one of the `SECTIONS` are taken into account, so that
+/// unrelated ones (e.g. `JANET_HOME`) don't break the configuration.
+///
+/// Types of existing options are preserved; new options are parsed as TOML
+/// values when possible (so that e.g. `JANET_NUDGER__PROJECTS=[...]` yields an
+/// array) and fall back to strings otherwise - which means that a string that
+/// looks like a number has to be quoted: `JANET_BOT__NAME='"123"'`.
+fn apply_env(config: &mut Value, vars: impl Iterator<Item = (String, String)>) -> Result<()> {
+    for (key, value) in vars {
+        let key = if let Some(key) = key.strip_prefix(ENV_PREFIX) {
+            key.to_lowercase()
+        } else {
+            continue;
+        };
+
+        let path: Vec<_> = key.split(ENV_SEPARATOR).collect();
+
+        if path.len() < 2 || !SECTIONS.contains(&path[0]) {
+            continue;
+        }
+
+        if path.iter().any(|section| section.is_empty()) {
+            bail!("Invalid variable: {}{}", ENV_PREFIX, key.to_uppercase());
+        }
+
+        let (name, sections) = path.split_last().unwrap();
+        let mut table = &mut *config;
+
+        for section in sections {
+            table = table
+                .as_table_mut()
+                .with_context(|| format!("`{}` is not a section", section))?
+                .entry(section.to_string())
+                .or_insert_with(|| Value::Table(Default::default()));
+        }
+
+        let table = table
+            .as_table_mut()
+            .with_context(|| format!("Couldn't override `{}`", path.join(".")))?;
+
+        let value: Result<_> = try {
+            match table.get(*name) {
+                Some(Value::Integer(_)) => Value::Integer(value.parse()?),
+                Some(Value::Float(_)) => Value::Float(value.parse()?),
+                Some(Value::Boolean(_)) => Value::Boolean(value.parse()?),
+                Some(Value::String(_)) => Value::String(value),
+                _ => parse_value(value),
+            }
+        };
+
+        let value = value.with_context(|| format!("Couldn't override `{}`", path.join(".")))?;
+
+        table.insert(name.to_string(), value);
+    }
+
+    Ok(())
+}
+
+/// Parses given string as a TOML value (e.g. `123` or `["a", "b"]`), falling
+/// back to a plain string
+fn parse_value(value: String) -> Value {
+    let parsed: Option<Value> = toml::from_str::<Value>(&format!("value = {}", value))
+        .ok()
+        .and_then(|mut table| table.as_table_mut()?.remove("value"));
+
+    parsed.unwrap_or(Value::String(value))
+}
+
+/// Replaces `*_file` options with the contents of files they point at.
+async fn load_secrets(config: &mut Value) -> Result<()> {
+    for path in SECRETS {
+        let (name, sections) = path.split_last().unwrap();
+
+        let table = if let Some(table) = lookup_mut(config, sections).and_then(Value::as_table_mut)
+        {
+            table
+        } else {
+            continue;
+        };
+
+        let file = if let Some(file) = table.remove(&format!("{}_file", name)) {
+            file
+        } else {
+            continue;
+        };
+
+        let file = file
+            .as_str()
+            .with_context(|| format!("`{}_file` must be a string", path.join(".")))?;
+
+        let secret = fs::read_to_string(file)
+            .await
+            .with_context(|| format!("Couldn't read `{}` from: {}", path.join("."), file))?;
+
+        table.insert(name.to_string(), Value::String(secret.trim_end().into()));
+    }
+
+    Ok(())
+}
+
+fn lookup_mut<'a>(value: &'a mut Value, path: &[&str]) -> Option<&'a mut Value> {
+    path.iter()
+        .try_fold(value, |value, section| value.get_mut(*section))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_config() -> Value {
+        toml::from_str(
+            r#"
+            [bot]
+            name = "janet"
+
+            [http]
+            addr = "0.0.0.0:10000"
+
+            [database]
+            max_connections = 4
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn vars(vars: &[(&str, &str)]) -> impl Iterator<Item = (String, String)> {
+        vars.iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    mod apply_env {
+        use super::*;
+
+        #[test]
+        fn overrides_existing_options_preserving_their_types() {
+            let mut config = base_config();
+
+            apply_env(
+                &mut config,
+                vars(&[
+                    ("JANET_BOT__NAME", "123"),
+                    ("JANET_DATABASE__MAX_CONNECTIONS", "8"),
+                ]),
+            )
+            .unwrap();
+
+            assert_eq!(Value::String("123".into()), config["bot"]["name"]);
+            assert_eq!(Value::Integer(8), config["database"]["max_connections"]);
+        }
+
+        #[test]
+        fn infers_types_of_new_options() {
+            let mut config = base_config();
+
+            apply_env(
+                &mut config,
+                vars(&[
+                    ("JANET_GITLAB__URL", "https://gitlab.example.com"),
+                    ("JANET_CALENDAR__SKIP_NON_BUSINESS_DAYS", "true"),
+                    ("JANET_DIGEST__PROJECT", "123"),
+                    ("JANET_NUDGER__IGNORE_LABEL", r#""456""#),
+                ]),
+            )
+            .unwrap();
+
+            assert_eq!(
+                Value::String("https://gitlab.example.com".into()),
+                config["gitlab"]["url"]
+            );
+
+            assert_eq!(
+                Value::Boolean(true),
+                config["calendar"]["skip_non_business_days"]
+            );
+
+            assert_eq!(Value::Integer(123), config["digest"]["project"]);
+            assert_eq!(
+                Value::String("456".into()),
+                config["nudger"]["ignore_label"]
+            );
+        }
+
+        #[test]
+        fn ignores_unrelated_variables() {
+            let mut config = base_config();
+
+            apply_env(
+                &mut config,
+                vars(&[
+                    ("HOME", "/root"),
+                    ("JANET_HOME", "/opt/janet"),
+                    ("JANET_SOMETHING__ELSE", "foo"),
+                ]),
+            )
+            .unwrap();
+
+            assert_eq!(base_config(), config);
+        }
+
+        #[test]
+        fn rejects_values_of_invalid_type() {
+            let mut config = base_config();
+
+            let result = apply_env(
+                &mut config,
+                vars(&[("JANET_DATABASE__MAX_CONNECTIONS", "many")]),
+            );
+
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn rejects_invalid_variables() {
+            let mut config = base_config();
+            let result = apply_env(&mut config, vars(&[("JANET_GITLAB____URL", "foo")]));
+
+            assert!(result.is_err());
+        }
+    }
+
+    mod load_secrets {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn replaces_file_options_with_files_contents() {
+            let path = env::temp_dir().join(format!("janet-secret-{}", std::process::id()));
+
+            fs::write(&path, "secret-token\n").await.unwrap();
+
+            let mut config = base_config();
+
+            apply_env(
+                &mut config,
+                vars(&[(
+                    "JANET_GITLAB__PERSONAL_ACCESS_TOKEN_FILE",
+                    &path.display().to_string(),
+                )]),
+            )
+            .unwrap();
+
+            load_secrets(&mut config).await.unwrap();
+
+            let _ = fs::remove_file(&path).await;
+
+            assert_eq!(
+                Value::String("secret-token".into()),
+                config["gitlab"]["personal_access_token"]
+            );
+
+            assert!(config["gitlab"].get("personal_access_token_file").is_none());
+        }
+
+        #[tokio::test(threaded_scheduler)]
+        async fn fails_when_file_does_not_exist() {
+            let mut config = base_config();
+
+            config["gitlab"] =
+                toml::from_str(r#"personal_access_token_file = "/nonexistent""#).unwrap();
+
+            assert!(load_secrets(&mut config).await.is_err());
+        }
     }
 }
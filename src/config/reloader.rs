@@ -0,0 +1,118 @@
+use crate::config::Config;
+use crate::log::{self, LogHandle};
+use anyhow::*;
+use arc_swap::ArcSwap;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::signal::unix::{signal, SignalKind};
+
+/// Configuration shared between components that support hot-reloading
+pub type SharedConfig = Arc<ArcSwap<Config>>;
+
+/// Reloads configuration each time Janet receives `SIGHUP`.
+///
+/// Only some options can be changed on the fly (e.g. `bot.name` or
+/// `log.filter`) - changes to the remaining ones (e.g. `database.path`) are
+/// reported and ignored until Janet gets restarted.
+pub struct ConfigReloader {
+    path: PathBuf,
+    config: SharedConfig,
+    log: Arc<LogHandle>,
+}
+
+impl ConfigReloader {
+    pub fn new(path: PathBuf, config: SharedConfig, log: Arc<LogHandle>) -> Self {
+        Self { path, config, log }
+    }
+
+    pub async fn start(self) -> Result<()> {
+        let mut hangups = signal(SignalKind::hangup()).context("Couldn't listen for SIGHUP")?;
+
+        while hangups.recv().await.is_some() {
+            tracing::info!("Received SIGHUP, reloading configuration");
+
+            if let Err(err) = self.reload().await {
+                tracing::error!(
+                    { err = ?err },
+                    "Couldn't reload configuration; keeping the previous one"
+                );
+            }
+        }
+
+        bail!("Lost connection to the `SIGHUP` stream")
+    }
+
+    async fn reload(&self) -> Result<()> {
+        let old = self.config.load();
+        let mut new = Config::load(&self.path).await?;
+
+        // Validate everything before applying anything, so that we don't end
+        // up with a half-reloaded configuration
+        log::parse_filter(&new.log.filter)?;
+
+        for option in revert_restart_required_changes(&old, &mut new) {
+            tracing::warn!(
+                "`{}` has been changed, but it requires restarting Janet to take effect",
+                option
+            );
+        }
+
+        if new.log.filter != old.log.filter {
+            self.log.set_filter(&new.log.filter)?;
+        }
+
+        self.config.store(Arc::new(new));
+
+        tracing::info!("Configuration reloaded");
+
+        Ok(())
+    }
+}
+
+/// Reverts changes that cannot be applied without restarting Janet, returning
+/// names of the options that have been reverted.
+fn revert_restart_required_changes(old: &Config, new: &mut Config) -> Vec<&'static str> {
+    let options = vec![
+        ("database", revert(&old.database, &mut new.database)),
+        ("http", revert(&old.http, &mut new.http)),
+        (
+            "gitlab.url",
+            revert(&old.gitlab.client.url, &mut new.gitlab.client.url),
+        ),
+        (
+            "gitlab.personal_access_token",
+            revert(
+                &old.gitlab.client.personal_access_token,
+                &mut new.gitlab.client.personal_access_token,
+            ),
+        ),
+        ("log.format", revert(&old.log.format, &mut new.log.format)),
+        ("log.file", revert(&old.log.file, &mut new.log.file)),
+        (
+            "log.graylog",
+            revert(&old.log.graylog, &mut new.log.graylog),
+        ),
+        ("log.otlp", revert(&old.log.otlp, &mut new.log.otlp)),
+        ("notifiers", revert(&old.notifiers, &mut new.notifiers)),
+        ("calendar", revert(&old.calendar, &mut new.calendar)),
+    ];
+
+    options
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(option, _)| option)
+        .collect()
+}
+
+/// Overwrites `new` with `old`, returning whether they were different.
+///
+/// Configuration structs don't implement `PartialEq`, so they are compared
+/// through their TOML representation.
+fn revert<T: Clone + Serialize>(old: &T, new: &mut T) -> bool {
+    let changed = toml::Value::try_from(old).ok() != toml::Value::try_from(&*new).ok();
+
+    *new = old.clone();
+
+    changed
+}
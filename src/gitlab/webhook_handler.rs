@@ -1,3 +1,5 @@
+use crate::config::SharedConfig;
+use crate::metrics;
 use anyhow::Result;
 use lib_gitlab::{
     GitLabClient,
@@ -5,10 +7,14 @@ use lib_gitlab::{
     WebhookMergeRequest,
     WebhookMergeRequestAttrs,
     WebhookNoteAttrs,
+    WebhookPipelineAttrs,
     WebhookProject,
+    WebhookUser,
 };
 use lib_interface::{
+    CommandSpec,
     Event,
+    InterfaceError,
     MergeRequestCommand,
     MergeRequestCommandContext,
     MergeRequestPtr,
@@ -18,44 +24,55 @@ use lib_system::System;
 use std::sync::Arc;
 
 pub struct GitLabWebhookHandler {
-    bot_name: String,
-    // TODO
-    webhook_secret: String,
+    config: SharedConfig,
     gitlab: Arc<GitLabClient>,
     system: Arc<System>,
 }
 
 impl GitLabWebhookHandler {
-    pub fn new(
-        bot_name: String,
-        webhook_secret: String,
-        gitlab: Arc<GitLabClient>,
-        system: Arc<System>,
-    ) -> Self {
+    pub fn new(config: SharedConfig, gitlab: Arc<GitLabClient>, system: Arc<System>) -> Self {
         Self {
-            bot_name,
-            webhook_secret,
+            config,
             gitlab,
             system,
         }
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn handle(&self, event: WebhookEvent) {
+    pub async fn handle(&self, event: WebhookEvent, correlation_id: Option<String>) {
         tracing::debug!("Handling webhook event");
 
         match event {
             WebhookEvent::MergeRequest {
                 project,
                 object_attributes,
-            } => self.handle_merge_request(project, object_attributes).await,
+            } => {
+                self.handle_merge_request(project, object_attributes, correlation_id)
+                    .await
+            }
 
             WebhookEvent::Note {
                 object_attributes,
                 project,
                 merge_request,
+                user,
+            } => {
+                self.handle_note(
+                    project,
+                    merge_request,
+                    object_attributes,
+                    user,
+                    correlation_id,
+                )
+                .await
+            }
+
+            WebhookEvent::Pipeline {
+                project,
+                merge_request,
+                object_attributes,
             } => {
-                self.handle_note(project, merge_request, object_attributes)
+                self.handle_pipeline(project, merge_request, object_attributes, correlation_id)
                     .await
             }
         }
@@ -65,6 +82,7 @@ impl GitLabWebhookHandler {
         &self,
         project: WebhookProject,
         object_attributes: WebhookMergeRequestAttrs,
+        correlation_id: Option<String>,
     ) {
         let project = project.id;
         let merge_request = object_attributes.iid;
@@ -89,7 +107,78 @@ impl GitLabWebhookHandler {
         };
 
         if let Some(evt) = evt {
-            self.system.process_event(evt).await;
+            record_webhook("merge_request", "event");
+            self.system.process_event(evt, correlation_id).await;
+        } else {
+            record_webhook("merge_request", "ignored");
+        }
+    }
+
+    async fn handle_pipeline(
+        &self,
+        project: WebhookProject,
+        merge_request: Option<WebhookMergeRequest>,
+        object_attributes: WebhookPipelineAttrs,
+        correlation_id: Option<String>,
+    ) {
+        let project = project.id;
+
+        let status = object_attributes.status.as_str();
+
+        if !matches!(status, "success" | "failed") {
+            record_webhook("pipeline", "ignored");
+            return;
+        }
+
+        // Pipelines run for merge requests point at them directly, but the
+        // ones run on branches don't - for those we have to find merge
+        // requests of the branch on our own
+        let merge_requests = if let Some(merge_request) = merge_request {
+            vec![merge_request.iid]
+        } else {
+            match (&object_attributes.ref_, object_attributes.tag) {
+                (Some(branch), false) => {
+                    match self.gitlab.branch_merge_requests(project, branch).await {
+                        Ok(merge_requests) => merge_requests
+                            .into_iter()
+                            .map(|merge_request| merge_request.iid)
+                            .collect(),
+
+                        Err(err) => {
+                            tracing::warn!(
+                                { err = ?err },
+                                "Couldn't find merge requests of pipeline's branch"
+                            );
+
+                            Vec::new()
+                        }
+                    }
+                }
+
+                _ => Vec::new(),
+            }
+        };
+
+        if merge_requests.is_empty() {
+            record_webhook("pipeline", "ignored");
+            return;
+        }
+
+        for merge_request in merge_requests {
+            let evt = if status == "success" {
+                Event::PipelineSucceeded {
+                    project,
+                    merge_request,
+                }
+            } else {
+                Event::PipelineFailed {
+                    project,
+                    merge_request,
+                }
+            };
+
+            record_webhook("pipeline", "event");
+            self.system.process_event(evt, correlation_id.clone()).await;
         }
     }
 
@@ -99,16 +188,33 @@ impl GitLabWebhookHandler {
         project: WebhookProject,
         merge_request: WebhookMergeRequest,
         object_attributes: WebhookNoteAttrs,
+        user: Option<WebhookUser>,
+        correlation_id: Option<String>,
     ) {
-        let cmd = object_attributes.description;
+        let bot_name = self.config.load().bot.name.clone();
+        let mention = format!("@{} ", bot_name);
 
-        // TODO hard-coded janet
-        if !cmd.starts_with("@janet ") {
-            return;
-        }
+        let cmd = if let Some(cmd) = object_attributes.description.strip_prefix(&mention) {
+            cmd.trim()
+        } else {
+            // Our own notes (e.g. reminders) mustn't count as activity -
+            // otherwise a reminder could postpone itself
+            if user.map_or(false, |user| user.username == bot_name) {
+                record_webhook("note", "ignored");
+                return;
+            }
 
-        // TODO hard-coded 7
-        let cmd = cmd[7..].trim();
+            let evt = Event::NoteCreated {
+                project: project.id,
+                merge_request: merge_request.iid,
+                discussion: object_attributes.discussion_id,
+                author: object_attributes.author_id,
+            };
+
+            record_webhook("note", "event");
+            self.system.process_event(evt, correlation_id).await;
+            return;
+        };
 
         let ctxt = MergeRequestCommandContext {
             user: object_attributes.author_id,
@@ -121,7 +227,8 @@ impl GitLabWebhookHandler {
 
         match MergeRequestCommand::parse(ctxt, cmd) {
             Ok(cmd) => {
-                self.system.process_command(cmd).await;
+                record_webhook("note", "command");
+                self.system.process_command(cmd, correlation_id).await;
             }
 
             Err(err) => {
@@ -131,6 +238,36 @@ impl GitLabWebhookHandler {
                     err
                 );
 
+                let reply = match &err {
+                    InterfaceError::UnknownCommand => {
+                        record_webhook("note", "unknown_command");
+
+                        match CommandSpec::suggest(cmd) {
+                            Some(spec) => format!(
+                                "sorry, I'm not sure what you mean - did you mean `{}`?\n\n{}",
+                                spec.name,
+                                spec.help()
+                            ),
+
+                            None => format!(
+                                "sorry, I'm not sure what you mean - could you please remove your comment and re-send it? (`{}help` lists all the commands I know)",
+                                mention
+                            ),
+                        }
+                    }
+
+                    InterfaceError::InvalidCommand {
+                        reason, fragment, ..
+                    } => {
+                        record_webhook("note", "invalid_command");
+
+                        format!(
+                            "sorry, there's a problem with `{}`: {} - could you please fix your comment and re-send it?",
+                            fragment, reason
+                        )
+                    }
+                };
+
                 let _: Result<()> = try {
                     let username = self
                         .gitlab
@@ -143,7 +280,7 @@ impl GitLabWebhookHandler {
                             project.id,
                             merge_request.iid,
                             &object_attributes.discussion_id,
-                            format!("@{}: sorry, I'm not sure what you mean - could you please remove your comment and re-send it?", username),
+                            format!("@{}: {}", username, reply),
                         )
                         .await?;
                 };
@@ -151,3 +288,9 @@ impl GitLabWebhookHandler {
         }
     }
 }
+
+fn record_webhook(event_type: &str, outcome: &str) {
+    metrics::WEBHOOKS_RECEIVED
+        .with_label_values(&[event_type, outcome])
+        .inc();
+}
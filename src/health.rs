@@ -0,0 +1,192 @@
+use lib_database::Database;
+use lib_gitlab::GitLabClient;
+use lib_system::System;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::timeout;
+
+/// How long we wait for a dependency (GitLab, database) to respond before we
+/// consider it unreachable
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long the reminder-tracking loop may stay silent before we consider it
+/// stuck; a single iteration may have to talk to GitLab a few times, so this
+/// is way more generous than `REMINDER_RESOLUTION`
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Number of queued commands / events above which we consider Janet to be
+/// overwhelmed
+const MAX_QUEUE_DEPTH: usize = 1000;
+
+pub struct HealthChecker {
+    db: Database,
+    gitlab: Arc<GitLabClient>,
+    system: Arc<System>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checks: BTreeMap<&'static str, HealthCheck>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthCheck {
+    pub status: HealthStatus,
+    pub details: Value,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+impl HealthChecker {
+    pub fn new(db: Database, gitlab: Arc<GitLabClient>, system: Arc<System>) -> Self {
+        Self { db, gitlab, system }
+    }
+
+    /// Checks whether Janet is alive, i.e. whether its background tasks are
+    /// still spinning; when this fails, Janet should be restarted.
+    pub async fn live(&self) -> HealthReport {
+        HealthReport::new(vec![("reminders", self.check_reminders())])
+    }
+
+    /// Checks whether Janet is ready to handle requests, i.e. whether it's
+    /// alive and all of its dependencies are reachable.
+    pub async fn ready(&self) -> HealthReport {
+        HealthReport::new(vec![
+            ("database", self.check_database().await),
+            ("gitlab", self.check_gitlab().await),
+            ("queue", self.check_queue()),
+            ("reminders", self.check_reminders()),
+        ])
+    }
+
+    async fn check_database(&self) -> HealthCheck {
+        let latest_version = Database::latest_version();
+        let (latency, version) = measure(self.db.version()).await;
+
+        match version {
+            Ok(version) if version == latest_version => HealthCheck::up(json!({
+                "latency_ms": latency.as_millis() as u64,
+                "version": version,
+            })),
+
+            Ok(version) => HealthCheck::down(json!({
+                "latency_ms": latency.as_millis() as u64,
+                "version": version,
+                "error": format!("database is at version {}, but {} was expected", version, latest_version),
+            })),
+
+            Err(err) => HealthCheck::down(json!({
+                "latency_ms": latency.as_millis() as u64,
+                "error": format!("{:?}", err),
+            })),
+        }
+    }
+
+    async fn check_gitlab(&self) -> HealthCheck {
+        let (latency, result) = measure(self.gitlab.ping()).await;
+
+        match result {
+            Ok(()) => HealthCheck::up(json!({
+                "latency_ms": latency.as_millis() as u64,
+            })),
+
+            Err(err) => HealthCheck::down(json!({
+                "latency_ms": latency.as_millis() as u64,
+                "error": format!("{:?}", err),
+            })),
+        }
+    }
+
+    fn check_queue(&self) -> HealthCheck {
+        let health = self.system.health();
+
+        let details = json!({
+            "commands": health.commands_queue_depth,
+            "events": health.events_queue_depth,
+        });
+
+        if health.commands_queue_depth > MAX_QUEUE_DEPTH
+            || health.events_queue_depth > MAX_QUEUE_DEPTH
+        {
+            HealthCheck::down(details)
+        } else {
+            HealthCheck::up(details)
+        }
+    }
+
+    fn check_reminders(&self) -> HealthCheck {
+        match self.system.health().reminders_heartbeat {
+            Some(heartbeat) if heartbeat <= HEARTBEAT_TIMEOUT => HealthCheck::up(json!({
+                "last_heartbeat_ms": heartbeat.as_millis() as u64,
+            })),
+
+            Some(heartbeat) => HealthCheck::down(json!({
+                "last_heartbeat_ms": heartbeat.as_millis() as u64,
+                "error": "reminder-tracking loop seems to be stuck",
+            })),
+
+            None => HealthCheck::down(json!({
+                "error": "reminder-tracking loop hasn't reported in yet",
+            })),
+        }
+    }
+}
+
+impl HealthReport {
+    fn new(checks: Vec<(&'static str, HealthCheck)>) -> Self {
+        let status = if checks
+            .iter()
+            .all(|(_, check)| check.status == HealthStatus::Up)
+        {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+
+        Self {
+            status,
+            checks: checks.into_iter().collect(),
+        }
+    }
+}
+
+impl HealthCheck {
+    fn up(details: Value) -> Self {
+        Self {
+            status: HealthStatus::Up,
+            details,
+        }
+    }
+
+    fn down(details: Value) -> Self {
+        Self {
+            status: HealthStatus::Down,
+            details,
+        }
+    }
+}
+
+/// Awaits given future (up to `CHECK_TIMEOUT`), returning its result together
+/// with the time it took
+async fn measure<T>(
+    future: impl Future<Output = anyhow::Result<T>>,
+) -> (Duration, anyhow::Result<T>) {
+    let started_at = Instant::now();
+
+    let result = match timeout(CHECK_TIMEOUT, future).await {
+        Ok(result) => result,
+        Err(_) => Err(anyhow::anyhow!("timed out")),
+    };
+
+    (started_at.elapsed(), result)
+}
@@ -4,15 +4,21 @@ mod config;
 mod endpoints;
 
 use crate::gitlab::GitLabWebhookHandler;
+use crate::health::HealthChecker;
 use anyhow::*;
 use std::sync::Arc;
 use warp::Filter;
 
 pub async fn init(
     config: HttpConfig,
+    health_checker: Arc<HealthChecker>,
     gitlab_webhook_handler: Arc<GitLabWebhookHandler>,
 ) -> Result<()> {
-    let router = endpoints::health().or(endpoints::gitlab_webhook(gitlab_webhook_handler));
+    let router = endpoints::health()
+        .or(endpoints::health_live(health_checker.clone()))
+        .or(endpoints::health_ready(health_checker))
+        .or(endpoints::metrics())
+        .or(endpoints::gitlab_webhook(gitlab_webhook_handler));
 
     tracing::info!("Starting server at: {}", config.addr);
 
@@ -1,4 +1,5 @@
-pub use self::{gitlab_webhook::*, health::*};
+pub use self::{gitlab_webhook::*, health::*, metrics::*};
 
 mod gitlab_webhook;
 mod health;
+mod metrics;
@@ -1,5 +1,7 @@
 use crate::gitlab::GitLabWebhookHandler;
+use crate::metrics;
 use bytes::Bytes;
+use lib_gitlab::WebhookEvent;
 use reqwest::StatusCode;
 use std::sync::Arc;
 use warp::filters::body;
@@ -10,15 +12,22 @@ pub fn gitlab_webhook(
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     warp::path!("webhooks" / "gitlab")
         .and(warp::any().map(move || handler.clone()))
+        .and(warp::header::optional::<String>("x-gitlab-event-uuid"))
         .and(body::content_length_limit(5 * 1024 * 1024))
         .and(body::bytes())
         .and_then(handle)
 }
 
-async fn handle(handler: Arc<GitLabWebhookHandler>, body: Bytes) -> Result<impl Reply, Rejection> {
-    match serde_json::from_slice(&body) {
+/// `event_uuid` identifies the webhook delivery; it's stored in the audit
+/// trail, so that GitLab's "Recent events" can be matched with Janet's logs.
+async fn handle(
+    handler: Arc<GitLabWebhookHandler>,
+    event_uuid: Option<String>,
+    body: Bytes,
+) -> Result<impl Reply, Rejection> {
+    match WebhookEvent::from_slice(&body) {
         Ok(event) => {
-            handler.handle(event).await;
+            handler.handle(event, event_uuid).await;
         }
 
         Err(error) => {
@@ -27,6 +36,10 @@ async fn handle(handler: Arc<GitLabWebhookHandler>, body: Bytes) -> Result<impl
 
             tracing::warn!("Unknown event: {}", body);
             tracing::warn!("... serde said: {}", error);
+
+            metrics::WEBHOOKS_RECEIVED
+                .with_label_values(&["unknown", "invalid"])
+                .inc();
         }
     }
 
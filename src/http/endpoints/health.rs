@@ -1,9 +1,47 @@
-use warp::{Filter, Rejection, Reply};
+use crate::health::{HealthChecker, HealthReport, HealthStatus};
+use reqwest::StatusCode;
+use std::sync::Arc;
+use warp::{reply, Filter, Rejection, Reply};
 
 pub fn health() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     warp::path!("health").and(warp::get()).map(handle)
 }
 
+pub fn health_live(
+    checker: Arc<HealthChecker>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    warp::path!("health" / "live")
+        .and(warp::get())
+        .and(warp::any().map(move || checker.clone()))
+        .and_then(handle_live)
+}
+
+pub fn health_ready(
+    checker: Arc<HealthChecker>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    warp::path!("health" / "ready")
+        .and(warp::get())
+        .and(warp::any().map(move || checker.clone()))
+        .and_then(handle_ready)
+}
+
 fn handle() -> String {
     "Oh, hi Mark!".into()
 }
+
+async fn handle_live(checker: Arc<HealthChecker>) -> Result<impl Reply, Rejection> {
+    Ok(into_reply(checker.live().await))
+}
+
+async fn handle_ready(checker: Arc<HealthChecker>) -> Result<impl Reply, Rejection> {
+    Ok(into_reply(checker.ready().await))
+}
+
+fn into_reply(report: HealthReport) -> impl Reply {
+    let status = match report.status {
+        HealthStatus::Up => StatusCode::OK,
+        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    reply::with_status(reply::json(&report), status)
+}
@@ -0,0 +1,21 @@
+use prometheus::{Encoder, TextEncoder};
+use reqwest::StatusCode;
+use warp::http::header::CONTENT_TYPE;
+use warp::{reply, Filter, Rejection, Reply};
+
+pub fn metrics() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    warp::path!("metrics").and(warp::get()).map(handle)
+}
+
+fn handle() -> reply::Response {
+    let encoder = TextEncoder::new();
+    let mut body = Vec::new();
+
+    if let Err(err) = encoder.encode(&prometheus::gather(), &mut body) {
+        tracing::error!({ err = ?err }, "Couldn't encode metrics");
+
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    }
+
+    reply::with_header(body, CONTENT_TYPE, encoder.format_type()).into_response()
+}
@@ -1,33 +1,135 @@
-pub use self::config::LogConfig;
+pub use self::config::*;
 
 mod config;
 
 use anyhow::*;
-use tracing::Level;
+use opentelemetry::sdk::{self, Resource};
+use opentelemetry::KeyValue;
+use tracing::Subscriber;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling;
 use tracing_gelf::Logger;
-use tracing_subscriber::filter::LevelFilter;
-use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
 
-pub fn init(config: LogConfig) -> Result<()> {
-    let env_filter = EnvFilter::default()
-        .add_directive("hyper=error".parse()?)
-        .add_directive("warp=error".parse()?)
-        .add_directive(LevelFilter::TRACE.into());
+/// Handle to the initialized logger.
+///
+/// Keeps the non-blocking log writers alive, so it must be held for as long as
+/// the application runs - otherwise buffered log lines might get lost.
+pub struct LogHandle {
+    filter: reload::Handle<EnvFilter, Registry>,
+    _file: Option<WorkerGuard>,
+    _otlp: Option<opentelemetry_otlp::Uninstall>,
+}
+
+impl LogHandle {
+    /// Replaces the filter passed through `LogConfig::filter`
+    pub fn set_filter(&self, filter: &str) -> Result<()> {
+        self.filter
+            .reload(parse_filter(filter)?)
+            .context("Couldn't reload log filter")
+    }
+}
 
-    let subscriber = tracing_subscriber::fmt()
-        .with_max_level(Level::TRACE)
-        .with_env_filter(env_filter)
-        .finish();
+pub fn init(config: LogConfig) -> Result<LogHandle> {
+    let (filter, filter_handle) = reload::Layer::new(parse_filter(&config.filter)?);
 
-    if let Some(graylog_address) = config.graylog {
-        let task = Logger::builder()
-            .init_tcp_with_subscriber(graylog_address, subscriber)
-            .unwrap();
+    let stdout = fmt_layer(config.format, std::io::stdout, true);
+
+    let (file, file_guard) = if let Some(file) = config.file {
+        let appender = match file.rotation {
+            LogFileRotation::Minutely => rolling::minutely(&file.dir, &file.name),
+            LogFileRotation::Hourly => rolling::hourly(&file.dir, &file.name),
+            LogFileRotation::Daily => rolling::daily(&file.dir, &file.name),
+            LogFileRotation::Never => rolling::never(&file.dir, &file.name),
+        };
+
+        let (writer, guard) = tracing_appender::non_blocking(appender);
+
+        (Some(fmt_layer(file.format, writer, false)), Some(guard))
+    } else {
+        (None, None)
+    };
+
+    let graylog = if let Some(graylog) = config.graylog {
+        let builder = Logger::builder();
+
+        let (logger, task) = match graylog.protocol {
+            GraylogProtocol::Tcp => builder.connect_tcp(graylog.addr),
+            GraylogProtocol::Udp => builder.connect_udp(graylog.addr),
+        }
+        .map_err(|err| anyhow!("{:?}", err))
+        .context("Couldn't initialize Graylog logger")?;
 
         tokio::spawn(task);
+
+        Some(logger)
     } else {
-        tracing::subscriber::set_global_default(subscriber)?;
-    }
+        None
+    };
+
+    let (otlp, otlp_guard) = if let Some(otlp) = config.otlp {
+        let trace_config = sdk::trace::config().with_resource(Resource::new(vec![KeyValue::new(
+            "service.name",
+            otlp.service_name,
+        )]));
+
+        let (tracer, guard) = opentelemetry_otlp::new_pipeline()
+            .with_endpoint(&otlp.endpoint)
+            .with_trace_config(trace_config)
+            .install()
+            .with_context(|| format!("Couldn't initialize OTLP exporter for: {}", otlp.endpoint))?;
+
+        let layer = tracing_opentelemetry::layer().with_tracer(tracer);
+
+        (Some(layer), Some(guard))
+    } else {
+        (None, None)
+    };
+
+    let subscriber = Registry::default()
+        .with(filter)
+        .with(stdout)
+        .with(file)
+        .with(graylog)
+        .with(otlp);
+
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    Ok(LogHandle {
+        filter: filter_handle,
+        _file: file_guard,
+        _otlp: otlp_guard,
+    })
+}
+
+pub fn parse_filter(filter: &str) -> Result<EnvFilter> {
+    EnvFilter::try_new(filter).with_context(|| format!("Couldn't parse log filter: {}", filter))
+}
+
+/// Creates a layer that writes formatted log lines into given writer.
+///
+/// Since `tracing-subscriber`'s formatters are distinct types, we build all of
+/// them as `Option`s and enable just the one we're interested in.
+fn fmt_layer<S, W>(format: LogFormat, writer: W, ansi: bool) -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: MakeWriter + Send + Sync + 'static,
+{
+    let layer = fmt::layer().with_writer(writer).with_ansi(ansi);
+
+    let (pretty, compact, json) = match format {
+        LogFormat::Pretty => (Some(layer.pretty()), None, None),
+        LogFormat::Compact => (None, Some(layer.compact()), None),
+
+        LogFormat::Json => (
+            None,
+            None,
+            Some(layer.json().with_current_span(true).with_span_list(true)),
+        ),
+    };
 
-    Ok(())
+    Layer::<S>::and_then(Layer::<S>::and_then(pretty, compact), json)
 }
@@ -1,8 +1,151 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
+use std::path::PathBuf;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct LogConfig {
-    pub graylog: Option<SocketAddr>,
+    /// Which spans & events should get logged; uses the same syntax as
+    /// `RUST_LOG` (e.g. `info,lib_gitlab=debug`)
+    #[serde(default = "default_filter")]
+    pub filter: String,
+
+    /// How log lines should be formatted
+    #[serde(default)]
+    pub format: LogFormat,
+
+    /// When set, logs are additionally written into files
+    pub file: Option<LogFileConfig>,
+
+    /// When set, logs are additionally sent into Graylog
+    pub graylog: Option<GraylogConfig>,
+
+    /// When set, spans are exported into an OpenTelemetry collector
+    pub otlp: Option<OtlpConfig>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    Pretty,
+    Compact,
+    Json,
+}
+
+impl Default for LogFormat {
+    fn default() -> Self {
+        LogFormat::Pretty
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogFileConfig {
+    /// Directory where log files should be put into
+    pub dir: PathBuf,
+
+    /// Log files' name; when rotation is enabled, it's suffixed with the
+    /// date, e.g. `janet.log.2020-12-24`
+    #[serde(default = "default_file_name")]
+    pub name: String,
+
+    #[serde(default)]
+    pub rotation: LogFileRotation,
+
+    /// Format of the log lines; defaults to `json`, since files are usually
+    /// consumed by machines
+    #[serde(default = "default_file_format")]
+    pub format: LogFormat,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFileRotation {
+    Minutely,
+    Hourly,
+    Daily,
+    Never,
+}
+
+impl Default for LogFileRotation {
+    fn default() -> Self {
+        LogFileRotation::Daily
+    }
+}
+
+/// Graylog's address, specified either as:
+///
+/// - `graylog = "127.0.0.1:12201"` (TCP is assumed), or
+/// - `graylog = { addr = "127.0.0.1:12201", protocol = "udp" }`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(from = "GraylogConfigRepr")]
+pub struct GraylogConfig {
+    pub addr: SocketAddr,
+    pub protocol: GraylogProtocol,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraylogProtocol {
+    Tcp,
+    Udp,
+}
+
+impl Default for GraylogProtocol {
+    fn default() -> Self {
+        GraylogProtocol::Tcp
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GraylogConfigRepr {
+    Short(SocketAddr),
+
+    #[serde(deny_unknown_fields)]
+    Full {
+        addr: SocketAddr,
+        #[serde(default)]
+        protocol: GraylogProtocol,
+    },
+}
+
+impl From<GraylogConfigRepr> for GraylogConfig {
+    fn from(repr: GraylogConfigRepr) -> Self {
+        match repr {
+            GraylogConfigRepr::Short(addr) => Self {
+                addr,
+                protocol: Default::default(),
+            },
+
+            GraylogConfigRepr::Full { addr, protocol } => Self { addr, protocol },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OtlpConfig {
+    /// Collector's gRPC endpoint, e.g. `http://localhost:4317`
+    pub endpoint: String,
+
+    /// Name under which Janet's spans are reported
+    #[serde(default = "default_otlp_service_name")]
+    pub service_name: String,
+}
+
+fn default_filter() -> String {
+    "trace,hyper=error,warp=error".into()
+}
+
+fn default_file_name() -> String {
+    "janet.log".into()
+}
+
+fn default_file_format() -> LogFormat {
+    LogFormat::Json
+}
+
+fn default_otlp_service_name() -> String {
+    "janet".into()
 }
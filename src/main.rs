@@ -1,24 +1,25 @@
 #![feature(try_blocks)]
 
+use self::args::{
+    Command,
+    DbCommand,
+    DependenciesCommand,
+    LogsCommand,
+    MigrateCommand,
+    RemindersCommand,
+};
 use anyhow::*;
-use std::sync::Arc;
 use structopt::StructOpt;
-use tokio::try_join;
 
 mod args;
+mod calendar;
+mod cmds;
 mod config;
 mod gitlab;
+mod health;
 mod http;
 mod log;
-
-const LOGO: &str = r#"
-       __                 __ 
-      / /___ _____  ___  / /_
- __  / / __ `/ __ \/ _ \/ __/
-/ /_/ / /_/ / / / /  __/ /_  
-\____/\__,_/_/ /_/\___/\__/  
-
-"#;
+mod metrics;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -33,56 +34,43 @@ async fn main() -> Result<()> {
         })?
     };
 
-    log::init(config.log).context("Couldn't initialize log")?;
-
-    for line in LOGO.lines().skip(1) {
-        tracing::info!("{}", line);
-    }
-
-    let db = {
-        tracing::info!("Initializing database (path = {})", config.database.path);
-
-        lib_database::Database::new(config.database)
-            .await
-            .context("Couldn't initialize database")?
-    };
-
-    let gitlab = {
-        tracing::info!("Initializing GitLab client");
-
-        Arc::new(
-            lib_gitlab::GitLabClient::init(config.gitlab.client)
-                .await
-                .context("Couldn't initialize GitLab client")?,
-        )
-    };
-
-    let (system, system_task) = {
-        tracing::info!("Initializing system");
-        lib_system::System::init(args.sync, db, gitlab.clone())
-    };
-
-    let gitlab_webhook_handler = Arc::new(gitlab::GitLabWebhookHandler::new(
-        config.bot.name,
-        config.gitlab.webhook_secret,
-        gitlab.clone(),
-        system.clone(),
-    ));
-
-    let http_task = {
-        tracing::info!("Initializing HTTP server");
-        http::init(config.http, gitlab_webhook_handler)
-    };
-
-    match try_join!(system_task, http_task) {
-        Ok(_) => {
-            tracing::info!("Shutting down correctly");
-            Ok(())
-        }
-
-        Err(err) => {
-            tracing::error!("Shutting down because of a system error: {:?}", err);
-            Err(err)
-        }
+    match args.cmd.unwrap_or(Command::Serve) {
+        Command::Serve => cmds::serve(args.config, config, args.sync).await,
+        Command::CheckConfig => cmds::check_config(config).await,
+
+        Command::Migrate { cmd } => match cmd.unwrap_or(MigrateCommand::Up {
+            dry_run: false,
+            to: None,
+        }) {
+            MigrateCommand::Up { dry_run, to } => cmds::migrate_up(config, dry_run, to).await,
+            MigrateCommand::Down { dry_run, to } => cmds::migrate_down(config, dry_run, to).await,
+        },
+
+        Command::Reminders { cmd } => match cmd {
+            RemindersCommand::List => cmds::list_reminders(config).await,
+            RemindersCommand::Delete { id } => cmds::delete_reminder(config, id).await,
+        },
+
+        Command::Dependencies { cmd } => match cmd {
+            DependenciesCommand::List => cmds::list_dependencies(config).await,
+        },
+
+        Command::Logs { cmd } => match cmd {
+            LogsCommand::Tail { n, follow, event } => {
+                cmds::tail_logs(config, n, follow, event).await
+            }
+        },
+
+        Command::Replay {
+            id,
+            from,
+            to,
+            dry_run,
+        } => cmds::replay(config, id, from, to, dry_run).await,
+
+        Command::Db { cmd } => match cmd {
+            DbCommand::Export { path } => cmds::export_db(config, path).await,
+            DbCommand::Import { path } => cmds::import_db(config, path).await,
+        },
     }
 }
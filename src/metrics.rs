@@ -0,0 +1,11 @@
+use once_cell::sync::Lazy;
+use prometheus::{register_int_counter_vec, IntCounterVec};
+
+pub static WEBHOOKS_RECEIVED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "janet_webhooks_received_total",
+        "Number of webhooks received, by event type and parse outcome",
+        &["event_type", "outcome"]
+    )
+    .unwrap()
+});
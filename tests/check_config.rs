@@ -0,0 +1,113 @@
+#![feature(async_closure)]
+
+use lib_e2e::*;
+
+mod given_valid_config {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn prints_it_with_secrets_redacted() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_current_user(&gl_mock::user_250()).await;
+
+            let token = ctxt.temp.path().join("token");
+
+            std::fs::write(&token, "super-secret-token\n").unwrap();
+
+            let token = token.display().to_string();
+
+            let output = Janet::run(
+                &ctxt.config,
+                &["check-config"],
+                &[
+                    ("JANET_BOT__NAME", "jane"),
+                    ("JANET_GITLAB__PERSONAL_ACCESS_TOKEN_FILE", &token),
+                ],
+            )
+            .await
+            .unwrap();
+
+            let stdout = String::from_utf8(output.stdout).unwrap();
+
+            assert!(output.status.success(), "{}", stdout);
+            assert!(stdout.contains(r#"name = "jane""#), "{}", stdout);
+            assert!(
+                stdout.contains(r#"personal_access_token = "<redacted>""#),
+                "{}",
+                stdout
+            );
+            assert!(
+                stdout.contains(r#"webhook_secret = "<redacted>""#),
+                "{}",
+                stdout
+            );
+            assert!(
+                stdout.contains(r#"webhook_url = "<redacted>""#),
+                "{}",
+                stdout
+            );
+            assert!(stdout.contains("token belongs to: @someone"), "{}", stdout);
+            assert!(stdout.contains("[ok] Calendar is valid"), "{}", stdout);
+            assert!(!stdout.contains("super-secret-token"), "{}", stdout);
+        })
+        .await;
+    }
+}
+
+mod given_invalid_database_path {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn fails() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_current_user(&gl_mock::user_250()).await;
+
+            let output = Janet::run(
+                &ctxt.config,
+                &["check-config"],
+                &[("JANET_DATABASE__PATH", "/non/existing/database.db")],
+            )
+            .await
+            .unwrap();
+
+            let stdout = String::from_utf8(output.stdout).unwrap();
+
+            assert!(!output.status.success(), "{}", stdout);
+            assert!(
+                stdout.contains("[err] Database is not accessible"),
+                "{}",
+                stdout
+            );
+        })
+        .await;
+    }
+}
+
+mod given_unknown_calendar_country {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn fails() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_current_user(&gl_mock::user_250()).await;
+
+            let output = Janet::run(
+                &ctxt.config,
+                &["check-config"],
+                &[("JANET_CALENDAR__COUNTRY", "XX")],
+            )
+            .await
+            .unwrap();
+
+            let stdout = String::from_utf8(output.stdout).unwrap();
+
+            assert!(!output.status.success(), "{}", stdout);
+            assert!(
+                stdout.contains("[err] Calendar is not valid: Unknown country `XX`"),
+                "{}",
+                stdout
+            );
+        })
+        .await;
+    }
+}
@@ -0,0 +1,214 @@
+#![feature(async_closure)]
+
+use lib_e2e::*;
+
+/// Creates a reminder through the webhook, so that the CLI has something to
+/// work on
+async fn create_reminder(ctxt: &TestContext) {
+    ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+    ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+    ctxt.gitlab
+        .expect_merge_request(&gl_mock::merge_request_100())
+        .await;
+
+    ctxt.gitlab
+        .expect_merge_request_note_created(
+            gl::ProjectId::new(10),
+            gl::MergeRequestIid::new(1),
+            &gl::DiscussionId::new("cafebabe"),
+            "@someone :+1:",
+        )
+        .await;
+
+    ctxt.janet
+        .spoof_gitlab_webhook(&json!({
+            "event_type": "note",
+            "project": {
+                "id": 10,
+                "namespace": "alpha",
+            },
+            "merge_request": {
+                "id": 100,
+                "iid": 1,
+            },
+            "object_attributes": {
+                "author_id": 250,
+                "description": "@janet remind me in 1h: check the pipeline",
+                "discussion_id": "cafebabe",
+            },
+        }))
+        .await;
+}
+
+async fn run(ctxt: &TestContext, args: &[&str], envs: &[(&str, &str)]) -> (bool, String) {
+    let output = Janet::run(&ctxt.config, args, envs).await.unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    (output.status.success(), stdout)
+}
+
+#[tokio::test(threaded_scheduler)]
+async fn migrate_reports_up_to_date_database() {
+    test(async move |ctxt| {
+        let (ok, stdout) = run(&ctxt, &["migrate", "up", "--dry-run"], &[]).await;
+
+        assert!(ok, "{}", stdout);
+        assert!(stdout.contains("already at version"), "{}", stdout);
+    })
+    .await;
+}
+
+#[tokio::test(threaded_scheduler)]
+async fn reminders_can_be_listed_and_deleted() {
+    test(async move |ctxt| {
+        create_reminder(&ctxt).await;
+
+        let (ok, stdout) = run(&ctxt, &["reminders", "list"], &[]).await;
+
+        assert!(ok, "{}", stdout);
+        assert!(stdout.contains("user#250"), "{}", stdout);
+        assert!(stdout.contains("project#10!1"), "{}", stdout);
+        assert!(stdout.contains("check the pipeline"), "{}", stdout);
+
+        let id = stdout.split(" | ").next().unwrap().trim();
+
+        let (ok, stdout) = run(&ctxt, &["reminders", "delete", id], &[]).await;
+
+        assert!(ok, "{}", stdout);
+
+        let (ok, stdout) = run(&ctxt, &["reminders", "list"], &[]).await;
+
+        assert!(ok, "{}", stdout);
+        assert!(
+            stdout.contains("There are no pending reminders"),
+            "{}",
+            stdout
+        );
+    })
+    .await;
+}
+
+#[tokio::test(threaded_scheduler)]
+async fn logs_contain_outcome_of_commands() {
+    test(async move |ctxt| {
+        create_reminder(&ctxt).await;
+
+        let (ok, stdout) = run(&ctxt, &["logs", "tail", "--event", "command"], &[]).await;
+
+        assert!(ok, "{}", stdout);
+        assert!(stdout.contains(SPOOFED_EVENT_UUID), "{}", stdout);
+        assert!(stdout.contains("| ok in "), "{}", stdout);
+        assert!(stdout.contains("check the pipeline"), "{}", stdout);
+        assert!(
+            stdout.contains("(create_merge_request_note): 200"),
+            "{}",
+            stdout
+        );
+    })
+    .await;
+}
+
+#[tokio::test(threaded_scheduler)]
+async fn commands_can_be_replayed_in_dry_run_mode() {
+    test(async move |ctxt| {
+        create_reminder(&ctxt).await;
+
+        let (ok, stdout) = run(
+            &ctxt,
+            &["replay", "--from", "2000-01-01T00:00:00Z", "--dry-run"],
+            &[],
+        )
+        .await;
+
+        assert!(ok, "{}", stdout);
+        assert!(stdout.contains("Replaying command #1"), "{}", stdout);
+        assert!(stdout.contains("replay:1"), "{}", stdout);
+        assert!(stdout.contains("would post note to"), "{}", stdout);
+        assert!(stdout.contains("@someone :+1:"), "{}", stdout);
+
+        // Dry-run works on a copy of the database, so no reminder should've
+        // been created
+        let (ok, stdout) = run(&ctxt, &["reminders", "list"], &[]).await;
+
+        assert!(ok, "{}", stdout);
+        assert_eq!(
+            1,
+            stdout.matches("check the pipeline").count(),
+            "{}",
+            stdout
+        );
+    })
+    .await;
+}
+
+#[tokio::test(threaded_scheduler)]
+async fn database_can_be_exported_and_imported() {
+    test(async move |ctxt| {
+        create_reminder(&ctxt).await;
+
+        let dump = ctxt.temp.path().join("dump.json");
+        let dump = dump.to_str().unwrap();
+
+        let (ok, stdout) = run(&ctxt, &["db", "export", dump], &[]).await;
+
+        assert!(ok, "{}", stdout);
+
+        let other_db = ctxt.temp.path().join("other.db");
+
+        std::fs::write(&other_db, "").unwrap();
+
+        let envs = &[("JANET_DATABASE__PATH", other_db.to_str().unwrap())];
+
+        let (ok, stdout) = run(&ctxt, &["db", "import", dump], envs).await;
+
+        assert!(!ok, "{}", stdout);
+
+        let (ok, stdout) = run(&ctxt, &["migrate"], envs).await;
+
+        assert!(ok, "{}", stdout);
+
+        let (ok, stdout) = run(&ctxt, &["db", "import", dump], envs).await;
+
+        assert!(ok, "{}", stdout);
+
+        let (ok, stdout) = run(&ctxt, &["reminders", "list"], envs).await;
+
+        assert!(ok, "{}", stdout);
+        assert!(stdout.contains("check the pipeline"), "{}", stdout);
+    })
+    .await;
+}
+
+#[tokio::test(threaded_scheduler)]
+async fn database_can_be_migrated_down_and_up() {
+    test(async move |ctxt| {
+        let other_db = ctxt.temp.path().join("other.db");
+
+        std::fs::write(&other_db, "").unwrap();
+
+        let envs = &[("JANET_DATABASE__PATH", other_db.to_str().unwrap())];
+
+        let (ok, stdout) = run(&ctxt, &["migrate"], envs).await;
+
+        assert!(ok, "{}", stdout);
+
+        let (ok, stdout) = run(&ctxt, &["migrate", "down", "--to", "1"], envs).await;
+
+        assert!(ok, "{}", stdout);
+        assert!(stdout.contains("rolled back"), "{}", stdout);
+
+        let (ok, stdout) = run(&ctxt, &["reminders", "list"], envs).await;
+
+        assert!(!ok, "{}", stdout);
+
+        let (ok, stdout) = run(&ctxt, &["migrate", "up"], envs).await;
+
+        assert!(ok, "{}", stdout);
+
+        let (ok, stdout) = run(&ctxt, &["reminders", "list"], envs).await;
+
+        assert!(ok, "{}", stdout);
+    })
+    .await;
+}
@@ -0,0 +1,50 @@
+#![feature(async_closure)]
+
+use lib_e2e::*;
+
+mod when_bot_name_is_changed {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn responds_to_new_name_after_reload() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "Hi, @someone!",
+                )
+                .await;
+
+            let config = std::fs::read_to_string(&ctxt.config).unwrap();
+            let config = config.replace(r#"name = "janet""#, r#"name = "jane""#);
+
+            std::fs::write(&ctxt.config, config).unwrap();
+
+            ctxt.janet.reload_config().await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&json!({
+                    "event_type": "note",
+                    "project": {
+                        "id": 10,
+                        "namespace": "alpha",
+                    },
+                    "merge_request": {
+                        "id": 100,
+                        "iid": 1,
+                    },
+                    "object_attributes": {
+                        "author_id": 250,
+                        "description": "@jane hi!!!",
+                        "discussion_id": "cafebabe",
+                    },
+                }))
+                .await;
+        })
+        .await;
+    }
+}
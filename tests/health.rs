@@ -0,0 +1,37 @@
+#![feature(async_closure)]
+
+use lib_e2e::*;
+
+mod when_everything_is_fine {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn reports_janet_as_alive() {
+        test(async move |ctxt| {
+            let (status, body) = ctxt.janet.health("live").await;
+
+            assert_eq!(200, status.as_u16());
+            assert_eq!("up", body["status"]);
+            assert_eq!("up", body["checks"]["reminders"]["status"]);
+        })
+        .await;
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn reports_janet_as_ready() {
+        test(async move |ctxt| {
+            let (status, body) = ctxt.janet.health("ready").await;
+
+            assert_eq!(200, status.as_u16(), "{}", body);
+            assert_eq!("up", body["status"]);
+
+            for check in &["database", "gitlab", "queue", "reminders"] {
+                assert_eq!("up", body["checks"][check]["status"], "{}", body);
+            }
+
+            assert_eq!(0, body["checks"]["queue"]["details"]["commands"]);
+            assert_eq!(0, body["checks"]["queue"]["details"]["events"]);
+        })
+        .await;
+    }
+}
@@ -3,8 +3,17 @@
 #[path = "merge_requests/dependencies.rs"]
 mod dependencies;
 
+#[path = "merge_requests/digests.rs"]
+mod digests;
+
+#[path = "merge_requests/help.rs"]
+mod help;
+
 #[path = "merge_requests/hi.rs"]
 mod hi;
 
+#[path = "merge_requests/notifications.rs"]
+mod notifications;
+
 #[path = "merge_requests/reminders.rs"]
 mod reminders;
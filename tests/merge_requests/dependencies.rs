@@ -52,6 +52,65 @@ mod when_user_adds_comment {
         }
     }
 
+    mod that_refers_to_existing_merge_request_by_url {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn responds_with_acknowledgement() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_project_by_path("alpha/project", &gl_mock::project_10())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_101())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                let url = ctxt
+                    .gitlab
+                    .url()
+                    .join("alpha/project/-/merge_requests/2")
+                    .unwrap();
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": format!("@janet depends on {}", url),
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+            })
+            .await;
+        }
+    }
+
     mod that_refers_to_missing_merge_request {
         use super::*;
 
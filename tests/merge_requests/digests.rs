@@ -0,0 +1,77 @@
+use lib_e2e::*;
+
+fn note(description: &str) -> serde_json::Value {
+    json!({
+        "event_type": "note",
+        "project": {
+            "id": 10,
+            "namespace": "alpha",
+        },
+        "merge_request": {
+            "id": 100,
+            "iid": 1,
+        },
+        "object_attributes": {
+            "author_id": 250,
+            "description": description,
+            "discussion_id": "cafebabe",
+        },
+    })
+}
+
+mod when_user_subscribes_to_digest {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn responds_with_acknowledgment() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+            ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+            ctxt.gitlab
+                .expect_merge_request(&gl_mock::merge_request_100())
+                .await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone :+1:",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&note(
+                    "@janet digest me weekly on monday at 8am Europe/Warsaw",
+                ))
+                .await;
+        })
+        .await;
+    }
+}
+
+mod when_user_gives_invalid_timezone {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn explains_whats_wrong() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone: sorry, there's a problem with `Europe/Atlantis`: there's no such timezone - could you please fix your comment and re-send it?",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&note("@janet digest me daily Europe/Atlantis"))
+                .await;
+        })
+        .await;
+    }
+}
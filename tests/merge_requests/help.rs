@@ -0,0 +1,70 @@
+use lib_e2e::*;
+
+fn note(description: &str) -> serde_json::Value {
+    json!({
+        "event_type": "note",
+        "project": {
+            "id": 10,
+            "namespace": "alpha",
+        },
+        "merge_request": {
+            "id": 100,
+            "iid": 1,
+        },
+        "object_attributes": {
+            "author_id": 250,
+            "description": description,
+            "discussion_id": "cafebabe",
+        },
+    })
+}
+
+mod when_user_asks_for_help {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn responds_with_commands_usage() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone `notify me via <gitlab/slack/matrix/webhook>` - changes where reminders & dependency updates are sent to; e.g.:\n\n- `notify me via slack`\n- `notify me via gitlab`",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&note("@janet help notify"))
+                .await;
+        })
+        .await;
+    }
+}
+
+mod when_user_makes_a_typo {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn suggests_the_closest_command() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone: sorry, I'm not sure what you mean - did you mean `notify`?\n\n`notify me via <gitlab/slack/matrix/webhook>` - changes where reminders & dependency updates are sent to; e.g.:\n\n- `notify me via slack`\n- `notify me via gitlab`",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&note("@janet notfy me via slack"))
+                .await;
+        })
+        .await;
+    }
+}
@@ -0,0 +1,140 @@
+use lib_e2e::*;
+use lib_system::REMINDER_RESOLUTION;
+use tokio::time::delay_for;
+
+/// Makes user #250 choose given notification channel (in a separate
+/// discussion, so that Janet's replies don't get mixed up) and then add a
+/// reminder that expires immediately
+async fn choose_channel_and_add_reminder(ctxt: &TestContext, channel: &str) {
+    ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+    ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+    ctxt.gitlab
+        .expect_merge_request(&gl_mock::merge_request_100())
+        .await;
+
+    for discussion in &["deadbeef", "cafebabe"] {
+        ctxt.gitlab
+            .expect_merge_request_note_created(
+                gl::ProjectId::new(10),
+                gl::MergeRequestIid::new(1),
+                &gl::DiscussionId::new(*discussion),
+                "@someone :+1:",
+            )
+            .await;
+    }
+
+    for (discussion, cmd) in &[
+        ("deadbeef", format!("@janet notify me via {}", channel)),
+        ("cafebabe", "@janet remind me in 0s: works!".to_string()),
+    ] {
+        ctxt.janet
+            .spoof_gitlab_webhook(&json!({
+                "event_type": "note",
+                "project": {
+                    "id": 10,
+                    "namespace": "alpha",
+                },
+                "merge_request": {
+                    "id": 100,
+                    "iid": 1,
+                },
+                "object_attributes": {
+                    "author_id": 250,
+                    "description": cmd,
+                    "discussion_id": discussion,
+                },
+            }))
+            .await;
+    }
+}
+
+mod when_user_chooses_webhook {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn reminders_are_posted_to_webhook() {
+        test(async move |ctxt| {
+            ctxt.notifiers
+                .expect_webhook(json!({
+                    "user": gl_mock::user_250(),
+                    "merge_request": gl_mock::merge_request_100(),
+                    "message": "reminding: works!",
+                }))
+                .await;
+
+            choose_channel_and_add_reminder(&ctxt, "webhook").await;
+
+            delay_for(REMINDER_RESOLUTION).await;
+        })
+        .await;
+    }
+}
+
+mod when_user_chooses_slack {
+    use super::*;
+
+    // User #250 has their Slack's member id configured, so they get pinged
+    #[tokio::test(threaded_scheduler)]
+    async fn reminders_are_posted_to_slack() {
+        test(async move |ctxt| {
+            ctxt.notifiers
+                .expect_slack_message(
+                    "<@U250>: reminding: works! (<http://gitlab.com/merge_requests/100|!1>)",
+                )
+                .await;
+
+            choose_channel_and_add_reminder(&ctxt, "slack").await;
+
+            delay_for(REMINDER_RESOLUTION).await;
+        })
+        .await;
+    }
+}
+
+mod when_user_chooses_matrix {
+    use super::*;
+
+    // User #250 has no Matrix id configured, so they get mentioned by their
+    // GitLab username
+    #[tokio::test(threaded_scheduler)]
+    async fn reminders_are_posted_to_matrix() {
+        test(async move |ctxt| {
+            ctxt.notifiers
+                .expect_matrix_message(
+                    "@someone: reminding: works! (http://gitlab.com/merge_requests/100)",
+                )
+                .await;
+
+            choose_channel_and_add_reminder(&ctxt, "matrix").await;
+
+            delay_for(REMINDER_RESOLUTION).await;
+        })
+        .await;
+    }
+}
+
+mod when_chosen_channel_fails {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn reminders_fall_back_to_gitlab_notes() {
+        test(async move |ctxt| {
+            // Notifiers' stand-in doesn't expect anything, so it will reject
+            // the notification
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone reminding: works!",
+                )
+                .await;
+
+            choose_channel_and_add_reminder(&ctxt, "slack").await;
+
+            delay_for(REMINDER_RESOLUTION).await;
+        })
+        .await;
+    }
+}
@@ -61,3 +61,836 @@ mod when_user_adds_new_reminder {
         }
     }
 }
+
+mod when_user_adds_reminder_with_invalid_time {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn explains_whats_wrong() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone: sorry, there's a problem with `25`: hour must be 0-23 - could you please fix your comment and re-send it?",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&json!({
+                    "event_type": "note",
+                    "project": {
+                        "id": 10,
+                        "namespace": "alpha",
+                    },
+                    "merge_request": {
+                        "id": 100,
+                        "iid": 1,
+                    },
+                    "object_attributes": {
+                        "author_id": 250,
+                        "description": "@janet remind me at 25",
+                        "discussion_id": "cafebabe",
+                    },
+                }))
+                .await;
+        })
+        .await;
+    }
+}
+
+mod when_user_adds_reminder_too_far_in_the_future {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn explains_whats_wrong() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone: sorry, there's a problem with `99999999d`: that's too far in the future - could you please fix your comment and re-send it?",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&json!({
+                    "event_type": "note",
+                    "project": {
+                        "id": 10,
+                        "namespace": "alpha",
+                    },
+                    "merge_request": {
+                        "id": 100,
+                        "iid": 1,
+                    },
+                    "object_attributes": {
+                        "author_id": 250,
+                        "description": "@janet remind me in 99999999d",
+                        "discussion_id": "cafebabe",
+                    },
+                }))
+                .await;
+        })
+        .await;
+    }
+}
+
+mod when_user_reminds_someone_else {
+    use super::*;
+
+    mod and_that_time_passes {
+        use super::*;
+        use lib_system::REMINDER_RESOLUTION;
+        use tokio::time::delay_for;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn reminds_them() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_user(&gl_mock::user_251()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_project_members(
+                        gl::ProjectId::new(10),
+                        &[gl_mock::user_250(), gl_mock::user_251()],
+                    )
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone-else reminding: please review",
+                    )
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind @someone-else in 0s: please review",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+
+                delay_for(REMINDER_RESOLUTION).await;
+            })
+            .await;
+        }
+    }
+
+    mod together_with_themselves {
+        use super::*;
+        use lib_system::REMINDER_RESOLUTION;
+        use tokio::time::delay_for;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn reminds_everyone_in_a_single_note() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_user(&gl_mock::user_251()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_project_members(
+                        gl::ProjectId::new(10),
+                        &[gl_mock::user_250(), gl_mock::user_251()],
+                    )
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone @someone-else reminding: let's merge it",
+                    )
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind me and @someone-else in 0s: let's merge it",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+
+                delay_for(REMINDER_RESOLUTION).await;
+            })
+            .await;
+        }
+    }
+
+    mod who_is_not_a_project_member {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn refuses() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_user(&gl_mock::user_251()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_project_members(gl::ProjectId::new(10), &[gl_mock::user_250()])
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone sorry, @someone-else isn't a member of this project, so I can't remind them about this merge request",
+                    )
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind @someone-else tomorrow",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+            })
+            .await;
+        }
+    }
+}
+
+mod when_user_reminds_reviewers_of_merge_request_without_any {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn explains_whats_wrong() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+            ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+            ctxt.gitlab
+                .expect_merge_request(&gl_mock::merge_request_100())
+                .await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone sorry, this merge request doesn't have any reviewers - could you please assign some and re-create your comment?",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&json!({
+                    "event_type": "note",
+                    "project": {
+                        "id": 10,
+                        "namespace": "alpha",
+                    },
+                    "merge_request": {
+                        "id": 100,
+                        "iid": 1,
+                    },
+                    "object_attributes": {
+                        "author_id": 250,
+                        "description": "@janet remind reviewers tomorrow",
+                        "discussion_id": "cafebabe",
+                    },
+                }))
+                .await;
+        })
+        .await;
+    }
+}
+
+mod when_user_adds_reminder_for_pipeline {
+    use super::*;
+
+    mod and_pipeline_succeeds {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn reminds() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone reminding (the pipeline has succeeded): merge it!",
+                    )
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind me when pipeline succeeds: merge it!",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+
+                // Pipeline hooks don't have `event_type`, only `object_kind`
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "object_kind": "pipeline",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "id": 1000,
+                            "status": "success",
+                        },
+                    }))
+                    .await;
+            })
+            .await;
+        }
+    }
+
+    mod and_pipeline_on_its_branch_succeeds {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn reminds() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_branch_merge_requests(
+                        gl::ProjectId::new(10),
+                        "feature",
+                        &[gl_mock::merge_request_100()],
+                    )
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone reminding (the pipeline has succeeded): merge it!",
+                    )
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind me when pipeline succeeds: merge it!",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+
+                // Pipelines run on branches don't point at any merge request
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "object_kind": "pipeline",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": null,
+                        "object_attributes": {
+                            "id": 1000,
+                            "status": "success",
+                            "ref": "feature",
+                            "tag": false,
+                        },
+                    }))
+                    .await;
+            })
+            .await;
+        }
+    }
+
+    mod and_pipeline_fails {
+        use super::*;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn does_nothing() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind me when pipeline succeeds",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "object_kind": "pipeline",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "id": 1000,
+                            "status": "failed",
+                        },
+                    }))
+                    .await;
+            })
+            .await;
+        }
+    }
+}
+
+mod when_user_adds_reminder_for_merge_request_that_is_already_merged {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn explains_whats_wrong() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+            ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+            ctxt.gitlab
+                .expect_merge_request(&gl_mock::merge_request_100())
+                .await;
+
+            ctxt.gitlab
+                .expect_merge_request(&gl::MergeRequest {
+                    state: "merged".to_string(),
+                    ..gl_mock::merge_request_101()
+                })
+                .await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "@someone sorry, this merge request has already been merged, so this reminder would never fire",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&json!({
+                    "event_type": "note",
+                    "project": {
+                        "id": 10,
+                        "namespace": "alpha",
+                    },
+                    "merge_request": {
+                        "id": 100,
+                        "iid": 1,
+                    },
+                    "object_attributes": {
+                        "author_id": 250,
+                        "description": "@janet remind me when !2 is merged",
+                        "discussion_id": "cafebabe",
+                    },
+                }))
+                .await;
+        })
+        .await;
+    }
+}
+
+mod when_user_adds_reminder_unless_someone_replies {
+    use super::*;
+
+    mod and_someone_replies {
+        use super::*;
+        use lib_system::REMINDER_RESOLUTION;
+        use tokio::time::delay_for;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn does_nothing() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind me in 2s unless someone replies",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 251,
+                            "description": "sure, looking at it now",
+                            "discussion_id": "cafebabe",
+                        },
+                        "user": {
+                            "username": "someone-else",
+                        },
+                    }))
+                    .await;
+
+                delay_for(REMINDER_RESOLUTION).await;
+            })
+            .await;
+        }
+    }
+
+    mod and_its_author_replies {
+        use super::*;
+        use lib_system::REMINDER_RESOLUTION;
+        use std::time::Duration;
+        use tokio::time::delay_for;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn reminds() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone ping, ping!",
+                    )
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind me in 2s unless someone replies",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "cc @someone-else",
+                            "discussion_id": "cafebabe",
+                        },
+                        "user": {
+                            "username": "someone",
+                        },
+                    }))
+                    .await;
+
+                delay_for(Duration::from_secs(2) + REMINDER_RESOLUTION).await;
+            })
+            .await;
+        }
+    }
+}
+
+mod when_user_adds_reminder_if_no_activity {
+    use super::*;
+
+    mod and_someone_writes_a_note {
+        use super::*;
+        use lib_system::REMINDER_RESOLUTION;
+        use std::time::Duration;
+        use tokio::time::delay_for;
+
+        #[tokio::test(threaded_scheduler)]
+        async fn postpones_it() {
+            test(async move |ctxt| {
+                ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+                ctxt.gitlab.expect_project(&gl_mock::project_10()).await;
+
+                ctxt.gitlab
+                    .expect_merge_request(&gl_mock::merge_request_100())
+                    .await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone :+1:",
+                    )
+                    .await;
+
+                // t = 0s; reminder is due at t = 8s
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 250,
+                            "description": "@janet remind me if no activity in 8s: take a look",
+                            "discussion_id": "cafebabe",
+                        },
+                    }))
+                    .await;
+
+                delay_for(Duration::from_secs(5)).await;
+
+                // t = 5s; reminder gets pushed back to t = 13s
+                ctxt.janet
+                    .spoof_gitlab_webhook(&json!({
+                        "event_type": "note",
+                        "project": {
+                            "id": 10,
+                            "namespace": "alpha",
+                        },
+                        "merge_request": {
+                            "id": 100,
+                            "iid": 1,
+                        },
+                        "object_attributes": {
+                            "author_id": 251,
+                            "description": "still working on it",
+                            "discussion_id": "deadbeef",
+                        },
+                        "user": {
+                            "username": "someone-else",
+                        },
+                    }))
+                    .await;
+
+                // t = 12s; had the reminder not been postponed, it would've
+                // been already sent - and since there's no mock for it yet,
+                // Janet would've failed to send it (and complained in logs)
+                delay_for(Duration::from_secs(7)).await;
+
+                ctxt.gitlab
+                    .expect_merge_request_note_created(
+                        gl::ProjectId::new(10),
+                        gl::MergeRequestIid::new(1),
+                        &gl::DiscussionId::new("cafebabe"),
+                        "@someone reminding: take a look",
+                    )
+                    .await;
+
+                delay_for(Duration::from_secs(1) + REMINDER_RESOLUTION).await;
+            })
+            .await;
+        }
+    }
+}
@@ -0,0 +1,60 @@
+#![feature(async_closure)]
+
+use lib_e2e::*;
+
+mod when_user_adds_comment {
+    use super::*;
+
+    #[tokio::test(threaded_scheduler)]
+    async fn updates_metrics() {
+        test(async move |ctxt| {
+            ctxt.gitlab.expect_user(&gl_mock::user_250()).await;
+
+            ctxt.gitlab
+                .expect_merge_request_note_created(
+                    gl::ProjectId::new(10),
+                    gl::MergeRequestIid::new(1),
+                    &gl::DiscussionId::new("cafebabe"),
+                    "Hi, @someone!",
+                )
+                .await;
+
+            ctxt.janet
+                .spoof_gitlab_webhook(&json!({
+                    "event_type": "note",
+                    "project": {
+                        "id": 10,
+                        "namespace": "alpha",
+                    },
+                    "merge_request": {
+                        "id": 100,
+                        "iid": 1,
+                    },
+                    "object_attributes": {
+                        "author_id": 250,
+                        "description": "@janet hi!!!",
+                        "discussion_id": "cafebabe",
+                    },
+                }))
+                .await;
+
+            let metrics = ctxt.janet.metrics().await;
+
+            for expected in &[
+                r#"janet_webhooks_received_total{event_type="note",outcome="command"} 1"#,
+                r#"janet_commands_handled_total{command="merge_request.hi",result="ok"} 1"#,
+                r#"janet_gitlab_requests_total{endpoint="create_merge_request_note",status="200"} 1"#,
+                r#"janet_queue_depth{queue="commands"} 0"#,
+                r#"janet_database_statement_duration_seconds_count{kind="command",name="CreateLogEntry"} 1"#,
+            ] {
+                assert!(
+                    metrics.contains(expected),
+                    "Metrics don't contain `{}`:\n{}",
+                    expected,
+                    metrics
+                );
+            }
+        })
+        .await;
+    }
+}